@@ -86,9 +86,9 @@ fn test_array() {
         println!("row[{}]: [{}, {}, {}, {}]", i, arr[i][0], arr[i][1], arr[i][2], arr[i][3]);
     }
 
-    use mathematics::linalg::Matrix2;
-    use mathematics::linalg::Matrix3;
-    use mathematics::linalg::Matrix4;
+    use mathematics::linalg::Matrix2f as Matrix2;
+    use mathematics::linalg::Matrix3f as Matrix3;
+    use mathematics::linalg::Matrix4f as Matrix4;
     let mut mat = Matrix4::new(
         1.0, 2.0, 3.0, 4.0, 
         5.0, 6.0, 7.0, 8.0,
@@ -435,9 +435,9 @@ fn test_rendering() {
             0.0, 0.0, 0.0, 1.0
         );
         // let model: Matrix4 = Matrix4::identity();
-        let view = Matrix4::translation(Vector3::new(0.0, 0.0, -5.0));
+        let view = Matrix4::look_at_rh(Vector3::new(0.0, 0.0, 5.0), Vector3::zero(), Vector3::new(0.0, 1.0, 0.0));
         // let projection = Matrix4::identity();
-        let projection = Matrix4::perspective(45.0, (open_gl::scr_width as f32) / (open_gl::scr_height as f32), 0.1, 100.0);
+        let projection = Matrix4::perspective(Deg(45.0), (open_gl::scr_width as f32) / (open_gl::scr_height as f32), 0.1, 100.0);
         // let projection = Matrix4::perspective(60.0, 1.0, 1.0, 100.0);
         // let projection = Matrix4::new(
         //     0.2, 0.0, 0.0, 0.0,
@@ -484,9 +484,9 @@ fn test_rendering() {
             // gl::DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, 0 as *const GLvoid);
 
             for (i, position) in cubePositions.iter().enumerate() {
-                let mut model = Matrix4::translation(*position);
                 let angle = 20.0 * i as f32;
-                model = model * Matrix4::scale_uniform(1.0) * Matrix4::rotation(angle, Vector3::new(1.0, 0.3, 0.5).normalization());
+                let rotation = Quaternion::rotation(Deg(angle), Vector3::new(1.0, 0.3, 0.5).normalization());
+                let model = Transform::new(*position, rotation, Vector3::one()).to_matrix4();
                 gl::UniformMatrix4fv(model_loc, 1, gl::FALSE, model.as_ptr());
 
                 gl::DrawArrays(gl::TRIANGLES, 0, 36);
@@ -514,7 +514,7 @@ fn test_rendering() {
 }
 
 fn test_math_profiling() {
-    pub use mathematics::linalg::Vector2;
+    pub use mathematics::linalg::Vector2f as Vector2;
 
     let a = Vector2::new(3.0, 4.0);
     let b = Vector2::new(1.0, 5.0);
@@ -549,9 +549,10 @@ fn test_math_profiling() {
 }
 
 fn test_vector2() {
-    use mathematics::linalg::Vector2;
-    use mathematics::linalg::Matrix2;
+    use mathematics::linalg::Vector2f as Vector2;
+    use mathematics::linalg::Matrix2f as Matrix2;
     use mathematics::num::constants as num;
+    use mathematics::num::Rad;
 
     // Construction
     println!("===== CONSTRUCTION =====");
@@ -561,8 +562,8 @@ fn test_vector2() {
     println!("b = Vector2::new(5.0, 1.0) -> {}", b.to_string());
     let mut c = Vector2::from_vector2(&a);
     println!("c = Vector2::from_vector(&a) -> {}", c.to_string());
-    let mut d = Vector2::from_polar(num::PIOVER3, 1.0);
-    println!("d = Vector2::from_polar(num::PIOVER3, 1.0) -> {}", d.to_string());
+    let mut d = Vector2::from_polar(Rad(num::PIOVER3), 1.0);
+    println!("d = Vector2::from_polar(Rad(num::PIOVER3), 1.0) -> {}", d.to_string());
     
     // Prefabrication
     println!("===== PREFABRICATION =====");
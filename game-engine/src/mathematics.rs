@@ -10,8 +10,6 @@ use std::f32::{ self, EPSILON, NAN };
     - Check if angle_signed works for both unit and non-unit vectors. Make new functions if needed. 
     - Check if angle_signed is counter-clockwise (like it should be). 
     - Consider making (Vector2) / (Vector2) into a (Vector3), or whether to keep as (f32). 
-    - Make MatrixN class? Needs to be on the heap. 
-    - Clear up confusion on column-majority, display style, getting elements, and matrix multiplication. 
 */
 
 pub mod num {
@@ -75,26 +73,264 @@ pub mod num {
         pub const IOR_humanLens     : f32 = 1.406;              // 1.386 - 1.406
         pub const IOR_humanCornea   : f32 = 1.38466666667;      // 1.373 / 1.380 / 1.401
     }
+
+    // Scalar types the linalg structs are generic over (f32 for GPU upload, f64 for large worlds)
+    //      Function names mirror the ones on f32/f64, so T::sqrt(x) reads like f32::sqrt(x).
+    use std::fmt::{ Debug, Display };
+    use std::ops::{ Add, AddAssign, Sub, SubAssign, Neg, Mul, MulAssign, Div, DivAssign };
+    pub trait Scalar: 
+        Copy + Debug + Display + PartialEq + PartialOrd + 
+        Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self> + 
+        AddAssign + SubAssign + MulAssign + DivAssign {
+        const ZERO      : Self;
+        const ONE       : Self;
+        const TWO       : Self;
+        const HALF      : Self;
+        const EPSILON   : Self;
+        const NAN       : Self;
+        const INFINITY  : Self;
+        // Default epsilon for approximate comparisons (looser than EPSILON, to absorb accumulated rounding)
+        const TOLERANCE : Self;
+
+        // Precision conversion (from_f64 and to_f32 may be lossy)
+        fn from_f32(x: f32) -> Self;
+        fn from_f64(x: f64) -> Self;
+        fn to_f32(self) -> f32;
+        fn to_f64(self) -> f64;
+
+        fn sqrt(self) -> Self;
+        fn sin(self) -> Self;
+        fn cos(self) -> Self;
+        fn tan(self) -> Self;
+        fn asin(self) -> Self;
+        fn acos(self) -> Self;
+        fn atan(self) -> Self;
+        fn atan2(self, x: Self) -> Self;
+        fn exp(self) -> Self;
+        fn ln(self) -> Self;
+        fn powf(self, n: Self) -> Self;
+        fn abs(self) -> Self;
+        fn signum(self) -> Self;
+        fn floor(self) -> Self;
+        fn min(self, x: Self) -> Self;
+        fn max(self, x: Self) -> Self;
+    }
+    macro_rules! impl_scalar { ($($t:ident: $tolerance:expr),*) => { $(
+        impl Scalar for $t {
+            const ZERO      : Self = 0.0;
+            const ONE       : Self = 1.0;
+            const TWO       : Self = 2.0;
+            const HALF      : Self = 0.5;
+            const EPSILON   : Self = $t::EPSILON;
+            const NAN       : Self = $t::NAN;
+            const INFINITY  : Self = $t::INFINITY;
+            const TOLERANCE : Self = $tolerance;
+
+            fn from_f32(x: f32) -> Self { return x as $t; }
+            fn from_f64(x: f64) -> Self { return x as $t; }
+            fn to_f32(self) -> f32      { return self as f32; }
+            fn to_f64(self) -> f64      { return self as f64; }
+
+            fn sqrt(self) -> Self               { return $t::sqrt(self); }
+            fn sin(self) -> Self                { return $t::sin(self); }
+            fn cos(self) -> Self                { return $t::cos(self); }
+            fn tan(self) -> Self                { return $t::tan(self); }
+            fn asin(self) -> Self               { return $t::asin(self); }
+            fn acos(self) -> Self               { return $t::acos(self); }
+            fn atan(self) -> Self               { return $t::atan(self); }
+            fn atan2(self, x: Self) -> Self     { return $t::atan2(self, x); }
+            fn exp(self) -> Self                { return $t::exp(self); }
+            fn ln(self) -> Self                 { return $t::ln(self); }
+            fn powf(self, n: Self) -> Self      { return $t::powf(self, n); }
+            fn abs(self) -> Self                { return $t::abs(self); }
+            fn signum(self) -> Self             { return $t::signum(self); }
+            fn floor(self) -> Self              { return $t::floor(self); }
+            fn min(self, x: Self) -> Self       { return $t::min(self, x); }
+            fn max(self, x: Self) -> Self       { return $t::max(self, x); }
+        }
+    )* } }
+    impl_scalar!(f32: 1.0e-5, f64: 1.0e-12);
+
+    // Angles
+    //      Angle-taking functions accept anything that converts into Rad (so both Rad and Deg),
+    //      but not bare floats, so a missing unit is a compile error.
+    //      Conversions use the f64 value of pi, so f64 angles keep their precision.
+    #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)] pub struct Rad<T: Scalar = f32>(pub T);
+    #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)] pub struct Deg<T: Scalar = f32>(pub T);
+    //          Conversion
+    impl<T: Scalar> From<Deg<T>> for Rad<T> { fn from(d: Deg<T>) -> Self { return Rad(d.0 * T::from_f64(std::f64::consts::PI / 180.0)); } }
+    impl<T: Scalar> From<Rad<T>> for Deg<T> { fn from(r: Rad<T>) -> Self { return Deg(r.0 * T::from_f64(180.0 / std::f64::consts::PI)); } }
+    impl<T: Scalar> Rad<T> { pub fn to_deg(&self)              -> Deg<T> { return Deg::from(*self); } }
+    impl<T: Scalar> Deg<T> { pub fn to_rad(&self)              -> Rad<T> { return Rad::from(*self); } }
+    impl<T: Scalar> Rad<T> { pub fn to_string(&self)           -> String { return format!("{} rad", self.0); } }
+    impl<T: Scalar> Deg<T> { pub fn to_string(&self)           -> String { return format!("{}°", self.0); } }
+    //          Prefabrication
+    impl<T: Scalar> Rad<T> { pub fn zero()                     -> Self { return Rad(T::ZERO); } }
+    impl<T: Scalar> Deg<T> { pub fn zero()                     -> Self { return Deg(T::ZERO); } }
+    impl<T: Scalar> Rad<T> { pub fn full_turn()                -> Self { return Rad(T::from_f64(std::f64::consts::PI * 2.0)); } }
+    impl<T: Scalar> Deg<T> { pub fn full_turn()                -> Self { return Deg(T::from_f32(360.0)); } }
+    impl<T: Scalar> Rad<T> { pub fn half_turn()                -> Self { return Rad(T::from_f64(std::f64::consts::PI)); } }
+    impl<T: Scalar> Deg<T> { pub fn half_turn()                -> Self { return Deg(T::from_f32(180.0)); } }
+    //          Trigonometry
+    impl<T: Scalar> Rad<T> { pub fn sin(&self)                 -> T { return T::sin(self.0); } }
+    impl<T: Scalar> Rad<T> { pub fn cos(&self)                 -> T { return T::cos(self.0); } }
+    impl<T: Scalar> Rad<T> { pub fn tan(&self)                 -> T { return T::tan(self.0); } }
+    impl<T: Scalar> Rad<T> { pub fn sin_cos(&self)             -> (T, T) { return (T::sin(self.0), T::cos(self.0)); } }
+    impl<T: Scalar> Deg<T> { pub fn sin(&self)                 -> T { return self.to_rad().sin(); } }
+    impl<T: Scalar> Deg<T> { pub fn cos(&self)                 -> T { return self.to_rad().cos(); } }
+    impl<T: Scalar> Deg<T> { pub fn tan(&self)                 -> T { return self.to_rad().tan(); } }
+    impl<T: Scalar> Deg<T> { pub fn sin_cos(&self)             -> (T, T) { return self.to_rad().sin_cos(); } }
+    impl<T: Scalar> Rad<T> { pub fn asin(a: T)                 -> Self { return Rad(T::asin(a)); } }
+    impl<T: Scalar> Rad<T> { pub fn acos(a: T)                 -> Self { return Rad(T::acos(a)); } }
+    impl<T: Scalar> Rad<T> { pub fn atan(a: T)                 -> Self { return Rad(T::atan(a)); } }
+    impl<T: Scalar> Rad<T> { pub fn atan2(y: T, x: T)          -> Self { return Rad(T::atan2(y, x)); } }
+    //          Wrapping into [0, full turn) and (-half turn, half turn]
+    impl<T: Scalar> Rad<T> { pub fn normalization(&self)       -> Self {
+        let turn = Self::full_turn().0;
+        return Rad(self.0 - turn * T::floor(self.0 / turn)); } }
+    impl<T: Scalar> Deg<T> { pub fn normalization(&self)       -> Self {
+        let turn = Self::full_turn().0;
+        return Deg(self.0 - turn * T::floor(self.0 / turn)); } }
+    impl<T: Scalar> Rad<T> { pub fn normalization_signed(&self) -> Self {
+        let a = self.normalization();
+        return if a > Self::half_turn() { a - Self::full_turn() } else { a }; } }
+    impl<T: Scalar> Deg<T> { pub fn normalization_signed(&self) -> Self {
+        let a = self.normalization();
+        return if a > Self::half_turn() { a - Self::full_turn() } else { a }; } }
+    //          Arithmetic (unary [-] is plain negation here)
+    macro_rules! impl_angle_arithmetic { ($($A:ident),*) => { $(
+        impl<T: Scalar> Add<$A<T>> for $A<T>    { type Output = Self; fn add(self, a: Self) -> Self { return $A(self.0 + a.0); } }
+        impl<T: Scalar> Sub<$A<T>> for $A<T>    { type Output = Self; fn sub(self, a: Self) -> Self { return $A(self.0 - a.0); } }
+        impl<T: Scalar> Neg for $A<T>           { type Output = Self; fn neg(self) -> Self { return $A(-self.0); } }
+        impl<T: Scalar> Mul<T> for $A<T>        { type Output = Self; fn mul(self, s: T) -> Self { return $A(self.0 * s); } }
+        impl<T: Scalar> Div<T> for $A<T>        { type Output = Self; fn div(self, s: T) -> Self { return $A(self.0 / s); } }
+        impl<T: Scalar> Div<$A<T>> for $A<T>    { type Output = T; fn div(self, a: Self) -> T { return self.0 / a.0; } }
+        impl<T: Scalar> AddAssign<$A<T>> for $A<T> { fn add_assign(&mut self, a: Self) { self.0 += a.0; } }
+        impl<T: Scalar> SubAssign<$A<T>> for $A<T> { fn sub_assign(&mut self, a: Self) { self.0 -= a.0; } }
+        impl<T: Scalar> MulAssign<T> for $A<T>  { fn mul_assign(&mut self, s: T) { self.0 *= s; } }
+        impl<T: Scalar> DivAssign<T> for $A<T>  { fn div_assign(&mut self, s: T) { self.0 /= s; } }
+        impl Mul<$A<f32>> for f32               { type Output = $A<f32>; fn mul(self, a: $A<f32>) -> $A<f32> { return $A(self * a.0); } }
+        impl Mul<$A<f64>> for f64               { type Output = $A<f64>; fn mul(self, a: $A<f64>) -> $A<f64> { return $A(self * a.0); } }
+    )* } }
+    impl_angle_arithmetic!(Rad, Deg);
+
+    // Optics
+    //      The refractive index table as a type, so materials can store one and a renderer can list them.
+    //      Custom covers anything not in the table.
+    use self::constants::*;
+    #[derive(Debug, Copy, Clone, PartialEq)] pub enum Ior {
+        Vacuum, Air, Water, OliveOil, Silicon, Ice,
+        GlassLight, GlassWindow, GlassFlint, GlassHeavy,
+        Opal, Obsidian, Moonstone, Sunstone, Amethyst, Citrine, Amber, Emerald, Pearl, Topaz,
+        Turquoise, Jet, Jade, Sapphire, Ruby, Malachite, Azurite, Diamond,
+        HumanLens, HumanCornea,
+        Custom(f32)
+    }
+    impl Ior { pub fn to_f32(&self)                            -> f32 {
+        return match self {
+            Ior::Vacuum => IOR_vacuum,          Ior::Air => IOR_air,                Ior::Water => IOR_water,
+            Ior::OliveOil => IOR_oliveOil,      Ior::Silicon => IOR_silicon,        Ior::Ice => IOR_ice,
+            Ior::GlassLight => IOR_glassLight,  Ior::GlassWindow => IOR_glassWindow,
+            Ior::GlassFlint => IOR_glassFlint,  Ior::GlassHeavy => IOR_glassHeavy,
+            Ior::Opal => IOR_opal,              Ior::Obsidian => IOR_obsidian,      Ior::Moonstone => IOR_moonstone,
+            Ior::Sunstone => IOR_sunstone,      Ior::Amethyst => IOR_amethyst,      Ior::Citrine => IOR_citrine,
+            Ior::Amber => IOR_amber,            Ior::Emerald => IOR_emerald,        Ior::Pearl => IOR_pearl,
+            Ior::Topaz => IOR_topaz,            Ior::Turquoise => IOR_turquoise,    Ior::Jet => IOR_jet,
+            Ior::Jade => IOR_jade,              Ior::Sapphire => IOR_sapphire,      Ior::Ruby => IOR_ruby,
+            Ior::Malachite => IOR_malachite,    Ior::Azurite => IOR_azurite,        Ior::Diamond => IOR_diamond,
+            Ior::HumanLens => IOR_humanLens,    Ior::HumanCornea => IOR_humanCornea,
+            Ior::Custom(n) => *n,
+        }; } }
+    impl Ior { pub fn value<T: Scalar>(&self)                   -> T { return T::from_f32(self.to_f32()); } }
+    //          Relative index n1 / n2 for light going from self into the other medium
+    impl Ior { pub fn ratio<T: Scalar>(&self, into: Ior)        -> T { return self.value::<T>() / into.value::<T>(); } }
+    //          Every tabulated material (Custom excluded), for material pickers
+    impl Ior { pub fn all()                                     -> [Ior; 30] {
+        return [
+            Ior::Vacuum, Ior::Air, Ior::Water, Ior::OliveOil, Ior::Silicon, Ior::Ice,
+            Ior::GlassLight, Ior::GlassWindow, Ior::GlassFlint, Ior::GlassHeavy,
+            Ior::Opal, Ior::Obsidian, Ior::Moonstone, Ior::Sunstone, Ior::Amethyst, Ior::Citrine, Ior::Amber, Ior::Emerald, Ior::Pearl, Ior::Topaz,
+            Ior::Turquoise, Ior::Jet, Ior::Jade, Ior::Sapphire, Ior::Ruby, Ior::Malachite, Ior::Azurite, Ior::Diamond,
+            Ior::HumanLens, Ior::HumanCornea]; } }
+    impl Ior { pub fn to_string(&self)                          -> String { return format!("{:?} ({})", self, self.to_f32()); } }
+    impl From<Ior> for f32 { fn from(n: Ior) -> f32 { return n.to_f32(); } }
+    impl From<Ior> for f64 { fn from(n: Ior) -> f64 { return n.to_f32() as f64; } }
+    //      Fresnel reflectance of a dielectric boundary (the fraction of light reflected, the rest is refracted).
+    //      cos_i is the cosine of the angle between the incident ray and the normal, n1 the index on the incident side.
+    //      Both return 1 under total internal reflection.
+    //          Schlick's approximation: R0 + (1 - R0)(1 - cos)^5, using the refracted angle when going into a lower index
+    //          https://en.wikipedia.org/wiki/Schlick%27s_approximation
+    pub fn fresnel_schlick<T: Scalar>(cos_i: T, n1: T, n2: T) -> T {
+        let r0 = ((n1 - n2) / (n1 + n2)) * ((n1 - n2) / (n1 + n2));
+        let mut cos = cos_i.abs();
+        if n1 > n2 {
+            let sin_t_sqr = (n1 / n2) * (n1 / n2) * (T::ONE - cos * cos);
+            if sin_t_sqr > T::ONE { return T::ONE; }
+            cos = T::sqrt(T::ONE - sin_t_sqr);
+        }
+        let x = T::ONE - cos;
+        return r0 + (T::ONE - r0) * x * x * x * x * x; }
+    //          Exact, for unpolarized light (the mean of the s- and p-polarized reflectances)
+    //          https://en.wikipedia.org/wiki/Fresnel_equations#Power_(intensity)_reflection_and_transmission_coefficients
+    pub fn fresnel_dielectric<T: Scalar>(cos_i: T, n1: T, n2: T) -> T {
+        let cos_i = T::min(cos_i.abs(), T::ONE);
+        let sin_t_sqr = (n1 / n2) * (n1 / n2) * (T::ONE - cos_i * cos_i);
+        if sin_t_sqr >= T::ONE { return T::ONE; }
+        let cos_t = T::sqrt(T::ONE - sin_t_sqr);
+        let rs = (n1 * cos_i - n2 * cos_t) / (n1 * cos_i + n2 * cos_t);
+        let rp = (n2 * cos_i - n1 * cos_t) / (n2 * cos_i + n1 * cos_t);
+        return (rs * rs + rp * rp) * T::HALF; }
+
+    // Easing curves, tweening and spring smoothing
+    pub mod easing;
 }
 
+// Procedural noise (gradient, simplex, value and cellular noise, fractal sums and domain warping)
+pub mod noise;
+
+// Seedable random sampling (shapes, stratified and low-discrepancy sequences, Poisson-disk sampling)
+pub mod sampling;
+
 pub mod linalg {
     pub use super::num::constants::*;
-    pub use std::ops::{ Add, AddAssign, Sub, SubAssign, Neg, Mul, MulAssign, Div, DivAssign, BitXor, Not };
+    pub use super::num::{ Scalar, Rad, Deg, Ior };
+    pub use std::ops::{ Add, AddAssign, Sub, SubAssign, Neg, Mul, MulAssign, Div, DivAssign, BitXor, Not, Index, IndexMut };
     pub use std::f32;
 
+    // Geometric algebra (bivectors, rotors, and the wedge product "^")
+    pub mod ga;
+    pub use self::ga::{ Bivector2, Bivector3, Bivector4, Trivector3, Rotor3 };
+    pub use self::ga::{ Bivector2f, Bivector2d, Bivector3f, Bivector3d, Bivector4f, Bivector4d, Trivector3f, Trivector3d, Rotor3f, Rotor3d };
+
+    // Euler angles in any of the 12 axis orders (yaw/pitch/roll, conversion to and from matrices and quaternions)
+    pub mod euler;
+    pub use self::euler::{ EulerAngles, EulerOrder, EulerAnglesf, EulerAnglesd };
+
+    // Translation, rotation and scale kept apart (TRS), for scene hierarchies and animation
+    pub mod transform;
+    pub use self::transform::{ Transform, Transformf, Transformd };
+
+    // Decompositions (LU, QR, Cholesky, symmetric eigen, SVD) and the solvers built on them
+    pub mod decomposition;
+    pub use self::decomposition::{ Lu2, Lu3, Lu4, Lu2f, Lu2d, Lu3f, Lu3d, Lu4f, Lu4d };
+
+    // Heap-allocated vectors and matrices of any size
+    pub mod dynamic;
+    pub use self::dynamic::{ VectorN, MatrixN, VectorNf, VectorNd, MatrixNf, MatrixNd };
+
     // Struct Definitions
-    #[derive(Debug, Copy, Clone)] pub struct Vector2    { x: f32, y: f32 }
+    #[derive(Debug, Copy, Clone, PartialEq)] #[repr(C)] pub struct Vector2<T: Scalar = f32>    { x: T, y: T }
     //      Complex number form:         c = a + bi
     //      Complex numbers base law:    i^2 = (-1)
-    #[derive(Debug, Copy, Clone)] pub struct Complex    { r: f32, i: f32 }
+    #[derive(Debug, Copy, Clone, PartialEq)] pub struct Complex<T: Scalar = f32>    { r: T, i: T }
     //      Guidance: http://www.dtecta.com/files/GDC13_vandenBergen_Gino_Math_Tut.pdf
     //      Paper on Automatic Differentiation (AD): https://www.duo.uio.no/bitstream/handle/10852/41535/Kjelseth-Master.pdf?sequence=9 
     //      Introduction to AD: https://alexey.radul.name/ideas/2013/introduction-to-automatic-differentiation/
     //      Dual number form:            d = a + bε
     //      Dual numbers base law:       ε^2 = 0
-    #[derive(Debug, Copy, Clone)] pub struct Dual       { r: f32, e: f32 }
-    #[derive(Debug, Copy, Clone)] pub struct Vector3    { x: f32, y: f32, z: f32 }
-    #[derive(Debug, Copy, Clone)] pub struct Vector4    { x: f32, y: f32, z: f32, w: f32 }
+    #[derive(Debug, Copy, Clone, PartialEq)] pub struct Dual<T: Scalar = f32>       { r: T, e: T }
+    #[derive(Debug, Copy, Clone, PartialEq)] #[repr(C)] pub struct Vector3<T: Scalar = f32>    { x: T, y: T, z: T }
+    #[derive(Debug, Copy, Clone, PartialEq)] #[repr(C)] pub struct Vector4<T: Scalar = f32>    { x: T, y: T, z: T, w: T }
     //      https://www.3dgep.com/understanding-quaternions/
     //      Quaternion number form:      q = s + xi + yj + zk -> { s, x, y, z } ∈ R
     //      Quaternion base law:         i^2 + j^2 + k^2 = ijk = (-1)
@@ -102,121 +338,234 @@ pub mod linalg {
             ij = k      jk = i      ki = j
             ji = -k     kj = -i     ik = -j
     */
-    #[derive(Debug, Copy, Clone)] pub struct Quaternion { s: f32, i: f32, j: f32, k: f32 }
-    // #[derive(Debug, Copy, Clone)] pub struct QuaternionDual { /* ? */ }
+    #[derive(Debug, Copy, Clone, PartialEq)] pub struct Quaternion<T: Scalar = f32> { s: T, i: T, j: T, k: T }
+    //      https://cs.gmu.edu/~jmlien/teaching/cs451/uploads/Main/dual-quaternion.pdf
+    //      Dual quaternion form:        dq = r + eε -> { r, e } ∈ H
+    //      Rigid transforms:            r = rotation,   e = (1/2) * t * r   (t = pure translation quaternion)
+    #[derive(Debug, Copy, Clone, PartialEq)] pub struct QuaternionDual<T: Scalar = f32> { r: Quaternion<T>, e: Quaternion<T> }
 
     /*
         Matrix conventions: 
-        - Column-major, store as 1 row of column vectors
-        - Transformations written like this: M * v
-        - Data definition: [[type; col]; row]
+        - Column-major, store as 1 row of column vectors: e[column][row]
+        - new() takes its arguments row by row, as the matrix is written on paper (it transposes into storage)
+        - to_string() prints row by row, the same way new() reads
+        - Transformations written like this: M * v (column vectors), so translation lives in the last column
+        - Data definition: [[type; row]; col]
+        - as_ptr() points at e[0][0] and the structs are #[repr(C)], so the memory is the column-major float array
+          OpenGL expects. Upload with transpose = gl::FALSE.
     */
-    #[derive(Debug, Copy, Clone)] pub struct Matrix2    { e: [[f32; 2]; 2] }
-    #[derive(Debug, Copy, Clone)] pub struct Matrix3    { e: [[f32; 3]; 3] }
-    #[derive(Debug, Copy, Clone)] pub struct Matrix4    { e: [[f32; 4]; 4] }
+    #[derive(Debug, Copy, Clone, PartialEq)] #[repr(C)] pub struct Matrix2<T: Scalar = f32>    { e: [[T; 2]; 2] }
+    #[derive(Debug, Copy, Clone, PartialEq)] #[repr(C)] pub struct Matrix3<T: Scalar = f32>    { e: [[T; 3]; 3] }
+    #[derive(Debug, Copy, Clone, PartialEq)] #[repr(C)] pub struct Matrix4<T: Scalar = f32>    { e: [[T; 4]; 4] }
+
+    // Precision aliases (the bare names default to f32)
+    pub type Vector2f           = Vector2<f32>;         pub type Vector2d           = Vector2<f64>;
+    pub type Vector3f           = Vector3<f32>;         pub type Vector3d           = Vector3<f64>;
+    pub type Vector4f           = Vector4<f32>;         pub type Vector4d           = Vector4<f64>;
+    pub type Complexf           = Complex<f32>;         pub type Complexd           = Complex<f64>;
+    pub type Dualf              = Dual<f32>;            pub type Duald              = Dual<f64>;
+    pub type Quaternionf        = Quaternion<f32>;      pub type Quaterniond        = Quaternion<f64>;
+    pub type QuaternionDualf    = QuaternionDual<f32>;  pub type QuaternionDuald    = QuaternionDual<f64>;
+    pub type Matrix2f           = Matrix2<f32>;         pub type Matrix2d           = Matrix2<f64>;
+    pub type Matrix3f           = Matrix3<f32>;         pub type Matrix3d           = Matrix3<f64>;
+    pub type Matrix4f           = Matrix4<f32>;         pub type Matrix4d           = Matrix4<f64>;
 
     // Field Interpretation
-    // (do vector [] syntax)
-    impl Matrix2 { pub fn row(&self, n: usize)          -> Vector2 { return Vector2::new(self.e[n][0], self.e[n][1]); } }
-    impl Matrix3 { pub fn row(&self, n: usize)          -> Vector3 { return Vector3::new(self.e[n][0], self.e[n][1], self.e[n][2]); } }
-    impl Matrix4 { pub fn row(&self, n: usize)          -> Vector4 { return Vector4::new(self.e[n][0], self.e[n][1], self.e[n][2], self.e[n][3]); } }
-    impl Matrix2 { pub fn column(&self, n: usize)       -> Vector2 { return Vector2::new(self.e[0][n], self.e[1][n]); } }
-    impl Matrix3 { pub fn column(&self, n: usize)       -> Vector3 { return Vector3::new(self.e[0][n], self.e[1][n], self.e[2][n]); } }
-    impl Matrix4 { pub fn column(&self, n: usize)       -> Vector4 { return Vector4::new(self.e[0][n], self.e[1][n], self.e[2][n], self.e[3][n]); } }
-    impl Matrix2 { pub fn diagonal(&self)               -> Vector2 { return Vector2::new(self.e[0][0], self.e[1][1]); } }
-    impl Matrix3 { pub fn diagonal(&self)               -> Vector3 { return Vector3::new(self.e[0][0], self.e[1][1], self.e[2][2]); } }
-    impl Matrix4 { pub fn diagonal(&self)               -> Vector4 { return Vector4::new(self.e[0][0], self.e[1][1], self.e[2][2], self.e[3][3]); } }
+    //      Vectors index as [x, y, z, w]. Matrices index as m[column][row] (like GLSL, and the storage order),
+    //      or as m[(row, column)] in the usual mathematical order.
+    impl<T: Scalar> Vector2<T> { pub fn x(&self)                      -> T { return self.x; } }
+    impl<T: Scalar> Vector2<T> { pub fn y(&self)                      -> T { return self.y; } }
+    impl<T: Scalar> Vector3<T> { pub fn x(&self)                      -> T { return self.x; } }
+    impl<T: Scalar> Vector3<T> { pub fn y(&self)                      -> T { return self.y; } }
+    impl<T: Scalar> Vector3<T> { pub fn z(&self)                      -> T { return self.z; } }
+    impl<T: Scalar> Vector4<T> { pub fn x(&self)                      -> T { return self.x; } }
+    impl<T: Scalar> Vector4<T> { pub fn y(&self)                      -> T { return self.y; } }
+    impl<T: Scalar> Vector4<T> { pub fn z(&self)                      -> T { return self.z; } }
+    impl<T: Scalar> Vector4<T> { pub fn w(&self)                      -> T { return self.w; } }
+    impl<T: Scalar> Vector2<T> { pub fn set_x(&mut self, a: T)                  { self.x = a; } }
+    impl<T: Scalar> Vector2<T> { pub fn set_y(&mut self, a: T)                  { self.y = a; } }
+    impl<T: Scalar> Vector3<T> { pub fn set_x(&mut self, a: T)                  { self.x = a; } }
+    impl<T: Scalar> Vector3<T> { pub fn set_y(&mut self, a: T)                  { self.y = a; } }
+    impl<T: Scalar> Vector3<T> { pub fn set_z(&mut self, a: T)                  { self.z = a; } }
+    impl<T: Scalar> Vector4<T> { pub fn set_x(&mut self, a: T)                  { self.x = a; } }
+    impl<T: Scalar> Vector4<T> { pub fn set_y(&mut self, a: T)                  { self.y = a; } }
+    impl<T: Scalar> Vector4<T> { pub fn set_z(&mut self, a: T)                  { self.z = a; } }
+    impl<T: Scalar> Vector4<T> { pub fn set_w(&mut self, a: T)                  { self.w = a; } }
+    impl<T: Scalar> Matrix2<T> { pub fn element(&self, r: usize, c: usize)   -> T { return self.e[c][r]; } }
+    impl<T: Scalar> Matrix3<T> { pub fn element(&self, r: usize, c: usize)   -> T { return self.e[c][r]; } }
+    impl<T: Scalar> Matrix4<T> { pub fn element(&self, r: usize, c: usize)   -> T { return self.e[c][r]; } }
+    impl<T: Scalar> Matrix2<T> { pub fn row(&self, n: usize)          -> Vector2<T> { return Vector2::new(self.e[0][n], self.e[1][n]); } }
+    impl<T: Scalar> Matrix3<T> { pub fn row(&self, n: usize)          -> Vector3<T> { return Vector3::new(self.e[0][n], self.e[1][n], self.e[2][n]); } }
+    impl<T: Scalar> Matrix4<T> { pub fn row(&self, n: usize)          -> Vector4<T> { return Vector4::new(self.e[0][n], self.e[1][n], self.e[2][n], self.e[3][n]); } }
+    impl<T: Scalar> Matrix2<T> { pub fn column(&self, n: usize)       -> Vector2<T> { return Vector2::new(self.e[n][0], self.e[n][1]); } }
+    impl<T: Scalar> Matrix3<T> { pub fn column(&self, n: usize)       -> Vector3<T> { return Vector3::new(self.e[n][0], self.e[n][1], self.e[n][2]); } }
+    impl<T: Scalar> Matrix4<T> { pub fn column(&self, n: usize)       -> Vector4<T> { return Vector4::new(self.e[n][0], self.e[n][1], self.e[n][2], self.e[n][3]); } }
+    impl<T: Scalar> Matrix2<T> { pub fn diagonal(&self)               -> Vector2<T> { return Vector2::new(self.e[0][0], self.e[1][1]); } }
+    impl<T: Scalar> Matrix3<T> { pub fn diagonal(&self)               -> Vector3<T> { return Vector3::new(self.e[0][0], self.e[1][1], self.e[2][2]); } }
+    impl<T: Scalar> Matrix4<T> { pub fn diagonal(&self)               -> Vector4<T> { return Vector4::new(self.e[0][0], self.e[1][1], self.e[2][2], self.e[3][3]); } }
+    impl<T: Scalar> Matrix2<T> { pub fn set_element(&mut self, r: usize, c: usize, a: T) { self.e[c][r] = a; } }
+    impl<T: Scalar> Matrix3<T> { pub fn set_element(&mut self, r: usize, c: usize, a: T) { self.e[c][r] = a; } }
+    impl<T: Scalar> Matrix4<T> { pub fn set_element(&mut self, r: usize, c: usize, a: T) { self.e[c][r] = a; } }
+    impl<T: Scalar> Matrix2<T> { pub fn set_row(&mut self, n: usize, v: Vector2<T>) {
+        self.e[0][n] = v.x;     self.e[1][n] = v.y; } }
+    impl<T: Scalar> Matrix3<T> { pub fn set_row(&mut self, n: usize, v: Vector3<T>) {
+        self.e[0][n] = v.x;     self.e[1][n] = v.y;     self.e[2][n] = v.z; } }
+    impl<T: Scalar> Matrix4<T> { pub fn set_row(&mut self, n: usize, v: Vector4<T>) {
+        self.e[0][n] = v.x;     self.e[1][n] = v.y;     self.e[2][n] = v.z;     self.e[3][n] = v.w; } }
+    impl<T: Scalar> Matrix2<T> { pub fn set_column(&mut self, n: usize, v: Vector2<T>)  { self.e[n] = [v.x, v.y]; } }
+    impl<T: Scalar> Matrix3<T> { pub fn set_column(&mut self, n: usize, v: Vector3<T>)  { self.e[n] = [v.x, v.y, v.z]; } }
+    impl<T: Scalar> Matrix4<T> { pub fn set_column(&mut self, n: usize, v: Vector4<T>)  { self.e[n] = [v.x, v.y, v.z, v.w]; } }
+    //          [] syntax
+    impl<T: Scalar> Index<usize> for Vector2<T> { type Output = T; fn index(&self, n: usize) -> &T {
+        match n { 0 => &self.x, 1 => &self.y, _ => panic!("Vector2 index out of range: {}", n) } } }
+    impl<T: Scalar> Index<usize> for Vector3<T> { type Output = T; fn index(&self, n: usize) -> &T {
+        match n { 0 => &self.x, 1 => &self.y, 2 => &self.z, _ => panic!("Vector3 index out of range: {}", n) } } }
+    impl<T: Scalar> Index<usize> for Vector4<T> { type Output = T; fn index(&self, n: usize) -> &T {
+        match n { 0 => &self.x, 1 => &self.y, 2 => &self.z, 3 => &self.w, _ => panic!("Vector4 index out of range: {}", n) } } }
+    impl<T: Scalar> IndexMut<usize> for Vector2<T> { fn index_mut(&mut self, n: usize) -> &mut T {
+        match n { 0 => &mut self.x, 1 => &mut self.y, _ => panic!("Vector2 index out of range: {}", n) } } }
+    impl<T: Scalar> IndexMut<usize> for Vector3<T> { fn index_mut(&mut self, n: usize) -> &mut T {
+        match n { 0 => &mut self.x, 1 => &mut self.y, 2 => &mut self.z, _ => panic!("Vector3 index out of range: {}", n) } } }
+    impl<T: Scalar> IndexMut<usize> for Vector4<T> { fn index_mut(&mut self, n: usize) -> &mut T {
+        match n { 0 => &mut self.x, 1 => &mut self.y, 2 => &mut self.z, 3 => &mut self.w, _ => panic!("Vector4 index out of range: {}", n) } } }
+    impl<T: Scalar> Index<usize> for Matrix2<T>                 { type Output = [T; 2]; fn index(&self, c: usize) -> &[T; 2] { return &self.e[c]; } }
+    impl<T: Scalar> Index<usize> for Matrix3<T>                 { type Output = [T; 3]; fn index(&self, c: usize) -> &[T; 3] { return &self.e[c]; } }
+    impl<T: Scalar> Index<usize> for Matrix4<T>                 { type Output = [T; 4]; fn index(&self, c: usize) -> &[T; 4] { return &self.e[c]; } }
+    impl<T: Scalar> IndexMut<usize> for Matrix2<T>              { fn index_mut(&mut self, c: usize) -> &mut [T; 2] { return &mut self.e[c]; } }
+    impl<T: Scalar> IndexMut<usize> for Matrix3<T>              { fn index_mut(&mut self, c: usize) -> &mut [T; 3] { return &mut self.e[c]; } }
+    impl<T: Scalar> IndexMut<usize> for Matrix4<T>              { fn index_mut(&mut self, c: usize) -> &mut [T; 4] { return &mut self.e[c]; } }
+    impl<T: Scalar> Index<(usize, usize)> for Matrix2<T>        { type Output = T; fn index(&self, (r, c): (usize, usize)) -> &T { return &self.e[c][r]; } }
+    impl<T: Scalar> Index<(usize, usize)> for Matrix3<T>        { type Output = T; fn index(&self, (r, c): (usize, usize)) -> &T { return &self.e[c][r]; } }
+    impl<T: Scalar> Index<(usize, usize)> for Matrix4<T>        { type Output = T; fn index(&self, (r, c): (usize, usize)) -> &T { return &self.e[c][r]; } }
+    impl<T: Scalar> IndexMut<(usize, usize)> for Matrix2<T>     { fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T { return &mut self.e[c][r]; } }
+    impl<T: Scalar> IndexMut<(usize, usize)> for Matrix3<T>     { fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T { return &mut self.e[c][r]; } }
+    impl<T: Scalar> IndexMut<(usize, usize)> for Matrix4<T>     { fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T { return &mut self.e[c][r]; } }
+    //          Iteration (vectors in [x, y, z, w] order, matrices in storage order: column by column)
+    //          The structs are #[repr(C)], so the fields are laid out contiguously like an array.
+    impl<T: Scalar> Vector2<T> { pub fn as_slice(&self)               -> &[T] { return unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 2) }; } }
+    impl<T: Scalar> Vector3<T> { pub fn as_slice(&self)               -> &[T] { return unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 3) }; } }
+    impl<T: Scalar> Vector4<T> { pub fn as_slice(&self)               -> &[T] { return unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 4) }; } }
+    impl<T: Scalar> Matrix2<T> { pub fn as_slice(&self)               -> &[T] { return unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 4) }; } }
+    impl<T: Scalar> Matrix3<T> { pub fn as_slice(&self)               -> &[T] { return unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 9) }; } }
+    impl<T: Scalar> Matrix4<T> { pub fn as_slice(&self)               -> &[T] { return unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 16) }; } }
+    impl<T: Scalar> Vector2<T> { pub fn as_mut_slice(&mut self)       -> &mut [T] { return unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 2) }; } }
+    impl<T: Scalar> Vector3<T> { pub fn as_mut_slice(&mut self)       -> &mut [T] { return unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 3) }; } }
+    impl<T: Scalar> Vector4<T> { pub fn as_mut_slice(&mut self)       -> &mut [T] { return unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 4) }; } }
+    impl<T: Scalar> Matrix2<T> { pub fn as_mut_slice(&mut self)       -> &mut [T] { return unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 4) }; } }
+    impl<T: Scalar> Matrix3<T> { pub fn as_mut_slice(&mut self)       -> &mut [T] { return unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 9) }; } }
+    impl<T: Scalar> Matrix4<T> { pub fn as_mut_slice(&mut self)       -> &mut [T] { return unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 16) }; } }
+    impl<T: Scalar> Vector2<T> { pub fn iter(&self)                   -> std::slice::Iter<T> { return self.as_slice().iter(); } }
+    impl<T: Scalar> Vector3<T> { pub fn iter(&self)                   -> std::slice::Iter<T> { return self.as_slice().iter(); } }
+    impl<T: Scalar> Vector4<T> { pub fn iter(&self)                   -> std::slice::Iter<T> { return self.as_slice().iter(); } }
+    impl<T: Scalar> Matrix2<T> { pub fn iter(&self)                   -> std::slice::Iter<T> { return self.as_slice().iter(); } }
+    impl<T: Scalar> Matrix3<T> { pub fn iter(&self)                   -> std::slice::Iter<T> { return self.as_slice().iter(); } }
+    impl<T: Scalar> Matrix4<T> { pub fn iter(&self)                   -> std::slice::Iter<T> { return self.as_slice().iter(); } }
+    impl<T: Scalar> Vector2<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<T> { return self.as_mut_slice().iter_mut(); } }
+    impl<T: Scalar> Vector3<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<T> { return self.as_mut_slice().iter_mut(); } }
+    impl<T: Scalar> Vector4<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<T> { return self.as_mut_slice().iter_mut(); } }
+    impl<T: Scalar> Matrix2<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<T> { return self.as_mut_slice().iter_mut(); } }
+    impl<T: Scalar> Matrix3<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<T> { return self.as_mut_slice().iter_mut(); } }
+    impl<T: Scalar> Matrix4<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<T> { return self.as_mut_slice().iter_mut(); } }
     
     // Construction
-    impl Vector2    { pub fn new(a: f32, b: f32)                        -> Self { return Self { x: a, y: b }; } }
-    impl Complex    { pub fn new(a: f32, b: f32)                        -> Self { return Self { r: a, i: b}; } }
-    impl Dual       { pub fn new(a: f32, b: f32)                        -> Self { return Self { r: a, e: b}; } }
-    impl Vector3    { pub fn new(a: f32, b: f32, c: f32)                -> Self { return Self { x: a, y: b, z: c }; } }
-    impl Vector4    { pub fn new(a: f32, b: f32, c: f32, d: f32)        -> Self { return Self { x: a, y: b, z: c, w: d }; } }
-    impl Quaternion { pub fn new(a: f32, b: f32, c: f32, d: f32)        -> Self { return Self { s: a, i: b, j: c, k: d }; } }
-    impl Vector2    { pub fn from_polar(angle: f32, radius: f32)        -> Self { return Self::new(f32::cos(angle), f32::sin(angle)) * radius; } }
-    impl Complex    { pub fn from_polar(angle: f32, radius: f32)        -> Self { return Self::new(f32::cos(angle), f32::sin(angle)) * radius; } }
-    impl Dual       { pub fn from_polar(angle: f32, radius: f32)        -> Self { return Self::new(f32::cos(angle), f32::sin(angle)) * radius; } }
-    impl Vector3    { pub fn from_spherical(r: f32, t: f32, p: f32)     -> Self { return Self::new(f32::sin(p) * f32::cos(t), f32::sin(p) * f32::sin(t), f32::cos(p)) * r; } }
-    impl Matrix2    { pub fn new(   a: f32, b: f32, 
-                                    c: f32, d: f32) -> Self {
+    impl<T: Scalar> Vector2<T>    { pub fn new(a: T, b: T)                        -> Self { return Self { x: a, y: b }; } }
+    impl<T: Scalar> Complex<T>    { pub fn new(a: T, b: T)                        -> Self { return Self { r: a, i: b}; } }
+    impl<T: Scalar> Dual<T>       { pub fn new(a: T, b: T)                        -> Self { return Self { r: a, e: b}; } }
+    impl<T: Scalar> Vector3<T>    { pub fn new(a: T, b: T, c: T)                -> Self { return Self { x: a, y: b, z: c }; } }
+    impl<T: Scalar> Vector4<T>    { pub fn new(a: T, b: T, c: T, d: T)        -> Self { return Self { x: a, y: b, z: c, w: d }; } }
+    impl<T: Scalar> Quaternion<T> { pub fn new(a: T, b: T, c: T, d: T)        -> Self { return Self { s: a, i: b, j: c, k: d }; } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn new(a: Quaternion<T>, b: Quaternion<T>)     -> Self { return Self { r: a, e: b }; } }
+    impl<T: Scalar> Vector2<T>    { pub fn from_polar<A: Into<Rad<T>>>(angle: A, radius: T) -> Self { let (sin, cos) = angle.into().sin_cos(); return Self::new(cos, sin) * radius; } }
+    impl<T: Scalar> Complex<T>    { pub fn from_polar<A: Into<Rad<T>>>(angle: A, radius: T) -> Self { let (sin, cos) = angle.into().sin_cos(); return Self::new(cos, sin) * radius; } }
+    impl<T: Scalar> Dual<T>       { pub fn from_polar<A: Into<Rad<T>>>(angle: A, radius: T) -> Self { let (sin, cos) = angle.into().sin_cos(); return Self::new(cos, sin) * radius; } }
+    //      Azimuth t around z from the x-axis, polar angle p down from the z-axis
+    impl<T: Scalar> Vector3<T>    { pub fn from_spherical<A: Into<Rad<T>>, B: Into<Rad<T>>>(r: T, t: A, p: B) -> Self {
+        let (t, p) = (t.into(), p.into());
+        return Self::new(p.sin() * t.cos(), p.sin() * t.sin(), p.cos()) * r; } }
+    impl<T: Scalar> Matrix2<T>    { pub fn new(   a: T, b: T, 
+                                    c: T, d: T) -> Self {
         return Self { e:           [[a, c],
                                     [b, d]] } } }
-    impl Matrix2    { pub fn from_vector2(a: Vector2, b: Vector2) -> Self {
+    //      from_vectorN() takes the rows, like new()
+    impl<T: Scalar> Matrix2<T>    { pub fn from_vector2(a: Vector2<T>, b: Vector2<T>) -> Self {
         return Self::new(a.x, a.y, b.x, b.y); } }
-    impl Matrix3    { pub fn new(   a: f32, b: f32, c: f32, 
-                                    d: f32, e: f32, f: f32,
-                                    g: f32, h: f32, i: f32) -> Self {
+    impl<T: Scalar> Matrix3<T>    { pub fn new(   a: T, b: T, c: T, 
+                                    d: T, e: T, f: T,
+                                    g: T, h: T, i: T) -> Self {
         return Self { e:           [[a, d, g],
                                     [b, e, h],
                                     [c, f, i]] } } }
-    impl Matrix3    { pub fn from_vector3(a: Vector3, b: Vector3, c: Vector3) -> Self {
+    impl<T: Scalar> Matrix3<T>    { pub fn from_vector3(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
         return Self::new(a.x, a.y, a.z, b.x, b.y, b.z, c.x, c.y, c.z); } }
-    impl Matrix4    { pub fn new(   a: f32, b: f32, c: f32, d: f32,
-                                    e: f32, f: f32, g: f32, h: f32,
-                                    i: f32, j: f32, k: f32, l: f32,
-                                    m: f32, n: f32, o: f32, p: f32) -> Self {
+    impl<T: Scalar> Matrix4<T>    { pub fn new(   a: T, b: T, c: T, d: T,
+                                    e: T, f: T, g: T, h: T,
+                                    i: T, j: T, k: T, l: T,
+                                    m: T, n: T, o: T, p: T) -> Self {
         return Self { e:           [[a, e, i, m],
                                     [b, f, j, n],
                                     [c, g, k, o],
                                     [d, h, l, p]] } } }
-    impl Matrix4    { pub fn from_vector4(a: Vector4, b: Vector4, c: Vector4, d: Vector4) -> Self {
+    impl<T: Scalar> Matrix4<T>    { pub fn from_vector4(a: Vector4<T>, b: Vector4<T>, c: Vector4<T>, d: Vector4<T>) -> Self {
         return Self::new(a.x, a.y, a.z, a.w, b.x, b.y, b.z, b.w, c.x, c.y, c.z, c.w, d.x, d.y, d.z, d.w); } }
 
     // Read functions
-    impl Vector2    { pub fn as_ptr(&self)  -> *const f32 { return &self.x; } }
-    impl Vector3    { pub fn as_ptr(&self)  -> *const f32 { return &self.x; } }
-    impl Vector4    { pub fn as_ptr(&self)  -> *const f32 { return &self.x; } }
-    impl Matrix2    { pub fn as_ptr(&self)  -> *const f32 { return &self.e[0][0]; } }
-    impl Matrix3    { pub fn as_ptr(&self)  -> *const f32 { return &self.e[0][0]; } }
-    impl Matrix4    { pub fn as_ptr(&self)  -> *const f32 { return &self.e[0][0]; } }
-    
-    // impl Quaternion {
-    //     // Unfinished
-    // }
-    // impl QuaternionDual {
-    //     // Unfinished
-    // }
-    
+    //      Pointers cover the whole struct (column-major for matrices), ready for glUniform*fv with transpose = gl::FALSE
+    impl<T: Scalar> Vector2<T>    { pub fn as_ptr(&self)  -> *const T { return self as *const Self as *const T; } }
+    impl<T: Scalar> Vector3<T>    { pub fn as_ptr(&self)  -> *const T { return self as *const Self as *const T; } }
+    impl<T: Scalar> Vector4<T>    { pub fn as_ptr(&self)  -> *const T { return self as *const Self as *const T; } }
+    impl<T: Scalar> Matrix2<T>    { pub fn as_ptr(&self)  -> *const T { return self as *const Self as *const T; } }
+    impl<T: Scalar> Matrix3<T>    { pub fn as_ptr(&self)  -> *const T { return self as *const Self as *const T; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn as_ptr(&self)  -> *const T { return self as *const Self as *const T; } }
+    //      Flat arrays in either order (cols = storage order = OpenGL order, rows = reading order = new() order)
+    impl<T: Scalar> Matrix2<T>    { pub fn to_cols_array(&self)               -> [T; 4] { let mut a = [T::ZERO; 4]; a.copy_from_slice(self.as_slice()); return a; } }
+    impl<T: Scalar> Matrix3<T>    { pub fn to_cols_array(&self)               -> [T; 9] { let mut a = [T::ZERO; 9]; a.copy_from_slice(self.as_slice()); return a; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn to_cols_array(&self)               -> [T; 16] { let mut a = [T::ZERO; 16]; a.copy_from_slice(self.as_slice()); return a; } }
+    impl<T: Scalar> Matrix2<T>    { pub fn to_rows_array(&self)               -> [T; 4] { return self.transpose().to_cols_array(); } }
+    impl<T: Scalar> Matrix3<T>    { pub fn to_rows_array(&self)               -> [T; 9] { return self.transpose().to_cols_array(); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn to_rows_array(&self)               -> [T; 16] { return self.transpose().to_cols_array(); } }
+    impl<T: Scalar> Matrix2<T>    { pub fn from_cols_array(a: [T; 4])        -> Self { let mut m = Self::zero(); m.as_mut_slice().copy_from_slice(&a); return m; } }
+    impl<T: Scalar> Matrix3<T>    { pub fn from_cols_array(a: [T; 9])        -> Self { let mut m = Self::zero(); m.as_mut_slice().copy_from_slice(&a); return m; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn from_cols_array(a: [T; 16])        -> Self { let mut m = Self::zero(); m.as_mut_slice().copy_from_slice(&a); return m; } }
+    impl<T: Scalar> Matrix2<T>    { pub fn from_rows_array(a: [T; 4])        -> Self { return Self::from_cols_array(a).transpose(); } }
+    impl<T: Scalar> Matrix3<T>    { pub fn from_rows_array(a: [T; 9])        -> Self { return Self::from_cols_array(a).transpose(); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn from_rows_array(a: [T; 16])        -> Self { return Self::from_cols_array(a).transpose(); } }
+
     //      Transformation Constructors
     //          Translation
-    impl Matrix4    { pub fn translation(v: Vector3) -> Self {
+    impl<T: Scalar> Matrix4<T>    { pub fn translation(v: Vector3<T>) -> Self {
         return Matrix4::new(
-            1.0, 0.0, 0.0, v.x,
-            0.0, 1.0, 0.0, v.y,
-            0.0, 0.0, 1.0, v.z,
-            0.0, 0.0, 0.0, 1.0); } }
+            T::ONE, T::ZERO, T::ZERO, v.x,
+            T::ZERO, T::ONE, T::ZERO, v.y,
+            T::ZERO, T::ZERO, T::ONE, v.z,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn translation(v: Vector3<T>) -> Self {
+        return Self::new(Quaternion::identity(), Quaternion::new(T::ZERO, v.x, v.y, v.z) * T::HALF); } }
     //          Rotation
-    impl Complex    { pub fn from_rotor(angle: f32)                     -> Self { return Self::new(f32::cos(angle), f32::sin(angle)); } }
-    impl Matrix4    { pub fn rotation_x(t: f32) -> Self { 
-        let cos = f32::cos(t); 
-        let sin = f32::sin(t);
+    impl<T: Scalar> Complex<T>    { pub fn from_rotor<A: Into<Rad<T>>>(angle: A)    -> Self { let (sin, cos) = angle.into().sin_cos(); return Self::new(cos, sin); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn rotation_x<A: Into<Rad<T>>>(t: A) -> Self { 
+        let (sin, cos) = t.into().sin_cos();
         return Self::new(
-            1.0,    0.0,    0.0,    0.0,
-            0.0,    cos,    -sin,   0.0,
-            0.0,    sin,    cos,    0.0,
-            0.0,    0.0,    0.0,    1.0); } }
-    impl Matrix4    { pub fn rotation_y(t: f32) -> Self { 
-        let cos = f32::cos(t); 
-        let sin = f32::sin(t);
+            T::ONE,    T::ZERO,    T::ZERO,    T::ZERO,
+            T::ZERO,    cos,    -sin,   T::ZERO,
+            T::ZERO,    sin,    cos,    T::ZERO,
+            T::ZERO,    T::ZERO,    T::ZERO,    T::ONE); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn rotation_y<A: Into<Rad<T>>>(t: A) -> Self { 
+        let (sin, cos) = t.into().sin_cos();
         return Self::new(
-            cos,    0.0,    sin,    0.0,
-            0.0,    1.0,    0.0,    0.0,
-            -sin,   0.0,    cos,    0.0,
-            0.0,    0.0,    0.0,    1.0); } }
-    impl Matrix4    { pub fn rotation_z(t: f32) -> Self { 
-        let cos = f32::cos(t); 
-        let sin = f32::sin(t);
+            cos,    T::ZERO,    sin,    T::ZERO,
+            T::ZERO,    T::ONE,    T::ZERO,    T::ZERO,
+            -sin,   T::ZERO,    cos,    T::ZERO,
+            T::ZERO,    T::ZERO,    T::ZERO,    T::ONE); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn rotation_z<A: Into<Rad<T>>>(t: A) -> Self { 
+        let (sin, cos) = t.into().sin_cos();
         return Self::new(
-            cos,    -sin,   0.0,    0.0,
-            sin,    cos,    0.0,    0.0,
-            0.0,    0.0,    0.0,    0.0,
-            0.0,    0.0,    0.0,    1.0); } }
-    impl Matrix4    { pub fn rotation(t: f32, v: Vector3) -> Self {
-        // Add cosine-sine double calculation here at a later time
-        let cos = f32::cos(t);
-        let sin = f32::sin(t);
-        let d = 1.0 - cos;
+            cos,    -sin,   T::ZERO,    T::ZERO,
+            sin,    cos,    T::ZERO,    T::ZERO,
+            T::ZERO,    T::ZERO,    T::ONE,    T::ZERO,
+            T::ZERO,    T::ZERO,    T::ZERO,    T::ONE); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn rotation<A: Into<Rad<T>>>(t: A, v: Vector3<T>) -> Self {
+        let (sin, cos) = t.into().sin_cos();
+        let d = T::ONE - cos;
 
         let x = v.x * d;
         let y = v.y * d;
@@ -226,83 +575,345 @@ pub mod linalg {
         let vyvz = y * v.z;
 
         return Self::new(
-            cos + x * v.x,      vxvy - sin * v.z,   vxvz + sin * v.y,   0.0,
-            vxvy + sin * v.z,   cos + y * v.y,      vyvz - sin * v.x,   0.0,
-            vxvz - sin * v.y,   vyvz + sin * v.x,   cos + z * v.z,      0.0,
-            0.0,                0.0,                0.0,                1.0); } }
+            cos + x * v.x,      vxvy - sin * v.z,   vxvz + sin * v.y,   T::ZERO,
+            vxvy + sin * v.z,   cos + y * v.y,      vyvz - sin * v.x,   T::ZERO,
+            vxvz - sin * v.y,   vyvz + sin * v.x,   cos + z * v.z,      T::ZERO,
+            T::ZERO,                T::ZERO,                T::ZERO,                T::ONE); } }
+    //      https://www.3dgep.com/understanding-quaternions/#Rotations
+    //      Rotates by angle t around the unit vector v (same convention as Matrix4::rotation)
+    impl<T: Scalar> Quaternion<T> { pub fn rotation<A: Into<Rad<T>>>(t: A, v: Vector3<T>) -> Self {
+        let (sin, cos) = (t.into() * T::HALF).sin_cos();
+        return Self::new(cos, v.x * sin, v.y * sin, v.z * sin); } }
+    impl<T: Scalar> Quaternion<T> { pub fn rotation_x<A: Into<Rad<T>>>(t: A) -> Self { return Self::rotation(t, Vector3::new(T::ONE, T::ZERO, T::ZERO)); } }
+    impl<T: Scalar> Quaternion<T> { pub fn rotation_y<A: Into<Rad<T>>>(t: A) -> Self { return Self::rotation(t, Vector3::new(T::ZERO, T::ONE, T::ZERO)); } }
+    impl<T: Scalar> Quaternion<T> { pub fn rotation_z<A: Into<Rad<T>>>(t: A) -> Self { return Self::rotation(t, Vector3::new(T::ZERO, T::ZERO, T::ONE)); } }
+    //      Euler angles, applied in the order x, then y, then z (q = qz * qy * qx, same as EulerOrder::XYZ)
+    //      See linalg::euler for the other orders and for decomposition.
+    impl<T: Scalar> Quaternion<T> { pub fn from_euler<A: Into<Rad<T>>>(x: A, y: A, z: A) -> Self {
+        return Self::rotation_z(z) * Self::rotation_y(y) * Self::rotation_x(x); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn rotation<A: Into<Rad<T>>>(t: A, v: Vector3<T>) -> Self {
+        return Self::new(Quaternion::rotation(t, v), Quaternion::zero()); } }
+    //          Rigid (rotate by q, then translate by v)
+    impl<T: Scalar> QuaternionDual<T> { pub fn from_rotation_translation(q: &Quaternion<T>, v: &Vector3<T>) -> Self {
+        return Self::new(*q, Quaternion::new(T::ZERO, v.x, v.y, v.z) * (*q) * T::HALF); } }
+    impl<T: Scalar> Quaternion<T> { pub fn to_axis_angle(&self) -> (Rad<T>, Vector3<T>) {
+        let q = self.normalization();
+        let s = T::max(-T::ONE, T::min(T::ONE, q.s));
+        let sin = T::sqrt(T::ONE - s * s);
+        if sin <= T::EPSILON {
+            // No rotation, so any axis will do
+            return (Rad::zero(), Vector3::right());
+        } else {
+            return (Rad::acos(s) * T::TWO, Vector3::new(q.i, q.j, q.k) / sin);
+        }
+    } }
 
     //          Scale
-    impl Matrix4    { pub fn scale_uniform(t: f32) -> Self {
+    impl<T: Scalar> Matrix4<T>    { pub fn scale_uniform(t: T) -> Self {
         return Self::new(
-            t, 0.0, 0.0, 0.0,
-            0.0, t, 0.0, 0.0,
-            0.0, 0.0, t, 0.0,
-            0.0, 0.0, 0.0, 1.0); } }
-    impl Matrix4    { pub fn scale_vector(v: Vector3) -> Self {
+            t, T::ZERO, T::ZERO, T::ZERO,
+            T::ZERO, t, T::ZERO, T::ZERO,
+            T::ZERO, T::ZERO, t, T::ZERO,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn scale_vector(v: Vector3<T>) -> Self {
         return Self::new(
-            v.x, 0.0, 0.0, 0.0,
-            0.0, v.y, 0.0, 0.0,
-            0.0, 0.0, v.z, 0.0,
-            0.0, 0.0, 0.0, 1.0); } }
+            v.x, T::ZERO, T::ZERO, T::ZERO,
+            T::ZERO, v.y, T::ZERO, T::ZERO,
+            T::ZERO, T::ZERO, v.z, T::ZERO,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE); } }
     //          Other
-    impl Matrix4    { pub fn perspective(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Self {
-        let scl_y = 1.0 / f32::tan(DEG2RAD * fov / 2.0);
+    impl<T: Scalar> Matrix4<T>    { pub fn perspective<A: Into<Rad<T>>>(fov: A, aspect_ratio: T, near: T, far: T) -> Self {
+        let scl_y = T::ONE / (fov.into() * T::HALF).tan();
         let scl_x = scl_y / aspect_ratio;
         let near_m_far = near - far;
 
         return Self::new(
-            scl_x,  0.0,    0.0,                            0.0,
-            0.0,    scl_y,  0.0,                            0.0,
-            0.0,    0.0,    (near + far) / near_m_far,      2.0 * near * far / near_m_far,
-            0.0,    0.0,    -1.0,                           0.0); } }
-    
+            scl_x,  T::ZERO,    T::ZERO,                            T::ZERO,
+            T::ZERO,    scl_y,  T::ZERO,                            T::ZERO,
+            T::ZERO,    T::ZERO,    (near + far) / near_m_far,      T::TWO * near * far / near_m_far,
+            T::ZERO,    T::ZERO,    -T::ONE,                           T::ZERO); } }
+    //          Camera (view) matrices. Right-handed looks down -z like OpenGL, left-handed looks down +z like Direct3D.
+    //          https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluLookAt.xml
+    impl<T: Scalar> Matrix4<T>    { pub fn look_at_rh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        let f = (target - eye).normalization();
+        let s = (f / up).normalization();
+        let u = s / f;
+
+        return Self::new(
+            s.x,        s.y,        s.z,        -(s * eye),
+            u.x,        u.y,        u.z,        -(u * eye),
+            -f.x,       -f.y,       -f.z,       f * eye,
+            T::ZERO,    T::ZERO,    T::ZERO,    T::ONE); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn look_at_lh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        let f = (target - eye).normalization();
+        let s = (up / f).normalization();
+        let u = f / s;
+
+        return Self::new(
+            s.x,        s.y,        s.z,        -(s * eye),
+            u.x,        u.y,        u.z,        -(u * eye),
+            f.x,        f.y,        f.z,        -(f * eye),
+            T::ZERO,    T::ZERO,    T::ZERO,    T::ONE); } }
+    //          Projection matrices map the view volume to OpenGL clip space (depth -1 at near, 1 at far)
+    //          https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/glOrtho.xml
+    impl<T: Scalar> Matrix4<T>    { pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let w = right - left;
+        let h = top - bottom;
+        let d = far - near;
+
+        return Self::new(
+            T::TWO / w, T::ZERO,    T::ZERO,        -(right + left) / w,
+            T::ZERO,    T::TWO / h, T::ZERO,        -(top + bottom) / h,
+            T::ZERO,    T::ZERO,    -T::TWO / d,    -(far + near) / d,
+            T::ZERO,    T::ZERO,    T::ZERO,        T::ONE); } }
+    //          Off-centre perspective (the bounds are on the near plane)
+    //          https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/glFrustum.xml
+    impl<T: Scalar> Matrix4<T>    { pub fn frustum(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let w = right - left;
+        let h = top - bottom;
+        let d = far - near;
+
+        return Self::new(
+            T::TWO * near / w,  T::ZERO,            (right + left) / w,     T::ZERO,
+            T::ZERO,            T::TWO * near / h,  (top + bottom) / h,     T::ZERO,
+            T::ZERO,            T::ZERO,            -(far + near) / d,      -T::TWO * far * near / d,
+            T::ZERO,            T::ZERO,            -T::ONE,                T::ZERO); } }
+    //          Far plane at infinity (the limit of perspective() as far -> infinity)
+    impl<T: Scalar> Matrix4<T>    { pub fn perspective_infinite<A: Into<Rad<T>>>(fov: A, aspect_ratio: T, near: T) -> Self {
+        let scl_y = T::ONE / (fov.into() * T::HALF).tan();
+        let scl_x = scl_y / aspect_ratio;
+
+        return Self::new(
+            scl_x,      T::ZERO,    T::ZERO,    T::ZERO,
+            T::ZERO,    scl_y,      T::ZERO,    T::ZERO,
+            T::ZERO,    T::ZERO,    -T::ONE,    -T::TWO * near,
+            T::ZERO,    T::ZERO,    -T::ONE,    T::ZERO); } }
+    //          Reversed-Z maps near to depth 1 and far to depth 0, which spreads float precision evenly over the range.
+    //          Expects a [0, 1] depth range (glClipControl(GL_LOWER_LEFT, GL_ZERO_TO_ONE)), depth cleared to 0,
+    //          and a GL_GREATER depth test.
+    //          https://developer.nvidia.com/content/depth-precision-visualized
+    impl<T: Scalar> Matrix4<T>    { pub fn perspective_reversed_z<A: Into<Rad<T>>>(fov: A, aspect_ratio: T, near: T, far: T) -> Self {
+        let scl_y = T::ONE / (fov.into() * T::HALF).tan();
+        let scl_x = scl_y / aspect_ratio;
+        let d = far - near;
+
+        return Self::new(
+            scl_x,      T::ZERO,    T::ZERO,        T::ZERO,
+            T::ZERO,    scl_y,      T::ZERO,        T::ZERO,
+            T::ZERO,    T::ZERO,    near / d,       far * near / d,
+            T::ZERO,    T::ZERO,    -T::ONE,        T::ZERO); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn perspective_infinite_reversed_z<A: Into<Rad<T>>>(fov: A, aspect_ratio: T, near: T) -> Self {
+        let scl_y = T::ONE / (fov.into() * T::HALF).tan();
+        let scl_x = scl_y / aspect_ratio;
+
+        return Self::new(
+            scl_x,      T::ZERO,    T::ZERO,    T::ZERO,
+            T::ZERO,    scl_y,      T::ZERO,    T::ZERO,
+            T::ZERO,    T::ZERO,    T::ZERO,    near,
+            T::ZERO,    T::ZERO,    -T::ONE,    T::ZERO); } }
+    //          Screen space <-> world space (view_projection = projection * view)
+    //          Window coordinates follow glViewport: origin at the bottom left, viewport = (x, y, width, height),
+    //          and depth in [0, 1]. Flip mouse coordinates with (height - y) first.
+    //          The depth range is the one the projection maps to: NegativeOneToOne for perspective, orthographic,
+    //          frustum and perspective_infinite, ZeroToOne for the reversed-Z variants (where near is at depth 1).
+    //          Unprojecting needs the inverse, so it returns None for a singular view_projection.
+    //          https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluUnProject.xml
+    #[derive(Debug, Copy, Clone, PartialEq)] pub enum DepthRange { NegativeOneToOne, ZeroToOne }
+    impl DepthRange { fn window_to_ndc<T: Scalar>(self, z: T) -> T {
+        return match self { DepthRange::NegativeOneToOne => T::TWO * z - T::ONE, DepthRange::ZeroToOne => z }; } }
+    impl DepthRange { fn ndc_to_window<T: Scalar>(self, z: T) -> T {
+        return match self { DepthRange::NegativeOneToOne => (z + T::ONE) * T::HALF, DepthRange::ZeroToOne => z }; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn project(p: &Vector3<T>, view_projection: &Self, viewport: &Vector4<T>, depth: DepthRange) -> Vector3<T> {
+        let ndc = view_projection.transform_point(p);
+        return Vector3::new(
+            viewport.x + viewport.z * (ndc.x + T::ONE) * T::HALF,
+            viewport.y + viewport.w * (ndc.y + T::ONE) * T::HALF,
+            depth.ndc_to_window(ndc.z)); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn unproject(p: &Vector3<T>, view_projection: &Self, viewport: &Vector4<T>, depth: DepthRange) -> Option<Vector3<T>> {
+        let ndc = Vector3::new(
+            T::TWO * (p.x - viewport.x) / viewport.z - T::ONE,
+            T::TWO * (p.y - viewport.y) / viewport.w - T::ONE,
+            depth.window_to_ndc(p.z));
+        return view_projection.try_inverse().map(|inverse| inverse.transform_point(&ndc)); } }
+    //          World-space ray (origin on the near plane, unit direction) through a window position, for picking.
+    //          Aims through two depths inside the range (an infinite far plane has no point at its end), ordered by
+    //          clip-space w, which grows away from a perspective camera. Reversed-Z is detected that way.
+    impl<T: Scalar> Matrix4<T>    { pub fn unproject_ray(x: T, y: T, view_projection: &Self, viewport: &Vector4<T>, depth: DepthRange) -> Option<(Vector3<T>, Vector3<T>)> {
+        let a = Self::unproject(&Vector3::new(x, y, T::HALF * T::HALF), view_projection, viewport, depth)?;
+        let b = Self::unproject(&Vector3::new(x, y, T::ONE - T::HALF * T::HALF), view_projection, viewport, depth)?;
+        let w = |p: &Vector3<T>| -> T { return view_projection.e[0][3] * p.x + view_projection.e[1][3] * p.y + view_projection.e[2][3] * p.z + view_projection.e[3][3]; };
+        let reversed = w(&b) < w(&a);
+        let near = Self::unproject(&Vector3::new(x, y, if reversed { T::ONE } else { T::ZERO }), view_projection, viewport, depth)?;
+        return Some((near, (if reversed { a - b } else { b - a }).normalization())); } }
+
     // Conversion Methods
-    impl Vector2    { pub fn from_vector2(v: &Vector2)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Complex    { pub fn from_vector2(v: &Vector2)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Dual       { pub fn from_vector2(v: &Vector2)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Vector3    { pub fn from_vector2(v: &Vector2, c: f32)          -> Self { return Self::new(v.x, v.y, c); } }
-    impl Vector4    { pub fn from_vector2(v: &Vector2, c: f32, d: f32)  -> Self { return Self::new(v.x, v.y, c, d); } }
-    impl Vector2    { pub fn from_complex(c: &Complex)                  -> Self { return Self::new(c.r, c.i); } }
-    impl Complex    { pub fn from_complex(c: &Complex)                  -> Self { return Self::new(c.r, c.i); } }
-    impl Dual       { pub fn from_complex(c: &Complex)                  -> Self { return Self::new(c.r, c.i); } }
-    impl Vector3    { pub fn from_complex(c: &Complex, c2: f32)         -> Self { return Self::new(c.r, c.i, c2); } }
-    impl Vector4    { pub fn from_complex(c: &Complex, c2: f32, d: f32) -> Self { return Self::new(c.r, c.i, c2, d); } }
-    impl Vector2    { pub fn from_dual(c: &Dual)                        -> Self { return Self::new(c.r, c.e); } }
-    impl Complex    { pub fn from_dual(d: &Dual)                        -> Self { return Self::new(d.r, d.e); } }
-    impl Dual       { pub fn from_dual(d: &Dual)                        -> Self { return Self::new(d.r, d.e); } }
-    impl Vector3    { pub fn from_dual(d: &Dual, c: f32)                -> Self { return Self::new(d.r, d.e, c); } }
-    impl Vector4    { pub fn from_dual(d: &Dual, c: f32, d2: f32)       -> Self { return Self::new(d.r, d.e, c, d2); } }
-    impl Vector2    { pub fn from_vector3(v: &Vector3)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Complex    { pub fn from_vector3(v: &Vector3)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Dual       { pub fn from_vector3(v: &Vector3)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Vector3    { pub fn from_vector3(v: &Vector3)                  -> Self { return Self::new(v.x, v.y, v.z); } }
-    impl Vector4    { pub fn from_vector3(v: &Vector3, d: f32)          -> Self { return Self::new(v.x, v.y, v.z, d); } }
-    impl Vector2    { pub fn from_vector4(v: &Vector4)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Complex    { pub fn from_vector4(v: &Vector4)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Dual       { pub fn from_vector4(v: &Vector4)                  -> Self { return Self::new(v.x, v.y); } }
-    impl Vector3    { pub fn from_vector4(v: &Vector4)                  -> Self { return Self::new(v.x, v.y, v.z); } }
-    impl Vector4    { pub fn from_vector4(v: &Vector4)                  -> Self { return Self::new(v.x, v.y, v.z, v.w); } }
+    impl<T: Scalar> Vector2<T>    { pub fn from_vector2(v: &Vector2<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Complex<T>    { pub fn from_vector2(v: &Vector2<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Dual<T>       { pub fn from_vector2(v: &Vector2<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Vector3<T>    { pub fn from_vector2(v: &Vector2<T>, c: T)          -> Self { return Self::new(v.x, v.y, c); } }
+    impl<T: Scalar> Vector4<T>    { pub fn from_vector2(v: &Vector2<T>, c: T, d: T)  -> Self { return Self::new(v.x, v.y, c, d); } }
+    impl<T: Scalar> Vector2<T>    { pub fn from_complex(c: &Complex<T>)                  -> Self { return Self::new(c.r, c.i); } }
+    impl<T: Scalar> Complex<T>    { pub fn from_complex(c: &Complex<T>)                  -> Self { return Self::new(c.r, c.i); } }
+    impl<T: Scalar> Dual<T>       { pub fn from_complex(c: &Complex<T>)                  -> Self { return Self::new(c.r, c.i); } }
+    impl<T: Scalar> Vector3<T>    { pub fn from_complex(c: &Complex<T>, c2: T)         -> Self { return Self::new(c.r, c.i, c2); } }
+    impl<T: Scalar> Vector4<T>    { pub fn from_complex(c: &Complex<T>, c2: T, d: T) -> Self { return Self::new(c.r, c.i, c2, d); } }
+    impl<T: Scalar> Vector2<T>    { pub fn from_dual(c: &Dual<T>)                        -> Self { return Self::new(c.r, c.e); } }
+    impl<T: Scalar> Complex<T>    { pub fn from_dual(d: &Dual<T>)                        -> Self { return Self::new(d.r, d.e); } }
+    impl<T: Scalar> Dual<T>       { pub fn from_dual(d: &Dual<T>)                        -> Self { return Self::new(d.r, d.e); } }
+    impl<T: Scalar> Vector3<T>    { pub fn from_dual(d: &Dual<T>, c: T)                -> Self { return Self::new(d.r, d.e, c); } }
+    impl<T: Scalar> Vector4<T>    { pub fn from_dual(d: &Dual<T>, c: T, d2: T)       -> Self { return Self::new(d.r, d.e, c, d2); } }
+    impl<T: Scalar> Vector2<T>    { pub fn from_vector3(v: &Vector3<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Complex<T>    { pub fn from_vector3(v: &Vector3<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Dual<T>       { pub fn from_vector3(v: &Vector3<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Vector3<T>    { pub fn from_vector3(v: &Vector3<T>)                  -> Self { return Self::new(v.x, v.y, v.z); } }
+    impl<T: Scalar> Vector4<T>    { pub fn from_vector3(v: &Vector3<T>, d: T)          -> Self { return Self::new(v.x, v.y, v.z, d); } }
+    impl<T: Scalar> Vector2<T>    { pub fn from_vector4(v: &Vector4<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Complex<T>    { pub fn from_vector4(v: &Vector4<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Dual<T>       { pub fn from_vector4(v: &Vector4<T>)                  -> Self { return Self::new(v.x, v.y); } }
+    impl<T: Scalar> Vector3<T>    { pub fn from_vector4(v: &Vector4<T>)                  -> Self { return Self::new(v.x, v.y, v.z); } }
+    impl<T: Scalar> Vector4<T>    { pub fn from_vector4(v: &Vector4<T>)                  -> Self { return Self::new(v.x, v.y, v.z, v.w); } }
+    impl<T: Scalar> Quaternion<T> { pub fn from_vector4(v: &Vector4<T>)                  -> Self { return Self::new(v.w, v.x, v.y, v.z); } }
+    impl<T: Scalar> Vector4<T>    { pub fn from_quaternion(q: &Quaternion<T>)            -> Self { return Self::new(q.i, q.j, q.k, q.s); } }
+    //      Upper left 3x3 (the linear part of a transform) and back
+    impl<T: Scalar> Matrix3<T>    { pub fn from_matrix4(m: &Matrix4<T>)                  -> Self {
+        return Self { e: [
+            [m.e[0][0], m.e[0][1], m.e[0][2]],
+            [m.e[1][0], m.e[1][1], m.e[1][2]],
+            [m.e[2][0], m.e[2][1], m.e[2][2]]] }; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn from_matrix3(m: &Matrix3<T>)                  -> Self {
+        return Self { e: [
+            [m.e[0][0], m.e[0][1], m.e[0][2], T::ZERO],
+            [m.e[1][0], m.e[1][1], m.e[1][2], T::ZERO],
+            [m.e[2][0], m.e[2][1], m.e[2][2], T::ZERO],
+            [T::ZERO,   T::ZERO,   T::ZERO,   T::ONE]] }; } }
+    //      Rotation matrices (the quaternion is normalized first)
+    //      http://www.euclideanspace.com/maths/geometry/rotations/conversions/quaternionToMatrix/index.htm
+    impl<T: Scalar> Matrix3<T>    { pub fn from_quaternion(q: &Quaternion<T>)            -> Self {
+        let q = q.normalization();
+        let ii = q.i * q.i;     let jj = q.j * q.j;     let kk = q.k * q.k;
+        let ij = q.i * q.j;     let ik = q.i * q.k;     let jk = q.j * q.k;
+        let si = q.s * q.i;     let sj = q.s * q.j;     let sk = q.s * q.k;
+
+        return Self::new(
+            T::ONE - T::TWO * (jj + kk),  T::TWO * (ij - sk),        T::TWO * (ik + sj),
+            T::TWO * (ij + sk),        T::ONE - T::TWO * (ii + kk),  T::TWO * (jk - si),
+            T::TWO * (ik - sj),        T::TWO * (jk + si),        T::ONE - T::TWO * (ii + jj)); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn from_quaternion(q: &Quaternion<T>)            -> Self {
+        let m = Matrix3::from_quaternion(q);
+        return Self::new(
+            m.e[0][0],  m.e[1][0],  m.e[2][0],  T::ZERO,
+            m.e[0][1],  m.e[1][1],  m.e[2][1],  T::ZERO,
+            m.e[0][2],  m.e[1][2],  m.e[2][2],  T::ZERO,
+            T::ZERO,        T::ZERO,        T::ZERO,        T::ONE); } }
+    //      Expects a pure rotation matrix. Elements are read as e[column][row].
+    //      http://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/index.htm
+    impl<T: Scalar> Quaternion<T> { pub fn from_matrix3(m: &Matrix3<T>)                  -> Self {
+        let m00 = m.e[0][0];    let m01 = m.e[1][0];    let m02 = m.e[2][0];
+        let m10 = m.e[0][1];    let m11 = m.e[1][1];    let m12 = m.e[2][1];
+        let m20 = m.e[0][2];    let m21 = m.e[1][2];    let m22 = m.e[2][2];
+        let trace = m00 + m11 + m22;
+
+        let q = if trace > T::ZERO {
+            let s = T::sqrt(trace + T::ONE) * T::TWO;
+            Self::new(T::from_f32(0.25) * s, (m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s)
+        } else if m00 > m11 && m00 > m22 {
+            let s = T::sqrt(T::ONE + m00 - m11 - m22) * T::TWO;
+            Self::new((m21 - m12) / s, T::from_f32(0.25) * s, (m01 + m10) / s, (m02 + m20) / s)
+        } else if m11 > m22 {
+            let s = T::sqrt(T::ONE + m11 - m00 - m22) * T::TWO;
+            Self::new((m02 - m20) / s, (m01 + m10) / s, T::from_f32(0.25) * s, (m12 + m21) / s)
+        } else {
+            let s = T::sqrt(T::ONE + m22 - m00 - m11) * T::TWO;
+            Self::new((m10 - m01) / s, (m02 + m20) / s, (m12 + m21) / s, T::from_f32(0.25) * s)
+        };
+        return q.normalization(); } }
+    impl<T: Scalar> Quaternion<T> { pub fn from_matrix4(m: &Matrix4<T>)                  -> Self {
+        return Self::from_matrix3(&Matrix3::from_matrix4(m)); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn from_quaternion_dual(dq: &QuaternionDual<T>)  -> Self {
+        let m = Matrix3::from_quaternion(&dq.r);
+        let t = dq.to_translation();
+        return Self::new(
+            m.e[0][0],  m.e[1][0],  m.e[2][0],  t.x,
+            m.e[0][1],  m.e[1][1],  m.e[2][1],  t.y,
+            m.e[0][2],  m.e[1][2],  m.e[2][2],  t.z,
+            T::ZERO,        T::ZERO,        T::ZERO,        T::ONE); } }
+    //      Expects a rigid transform (rotation and translation only)
+    impl<T: Scalar> QuaternionDual<T> { pub fn from_matrix4(m: &Matrix4<T>)              -> Self {
+        return Self::from_rotation_translation(&Quaternion::from_matrix4(m), &Vector3::new(m.e[3][0], m.e[3][1], m.e[3][2])); } }
+    //      Decomposition
+    impl<T: Scalar> QuaternionDual<T> { pub fn to_rotation(&self)                     -> Quaternion<T> { return self.r.normalization(); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn to_translation(&self)                  -> Vector3<T> {
+        let n = self.r.magnitude_sqr();
+        let t = self.e * self.r.conjugate() * (T::TWO / n);
+        return Vector3::new(t.i, t.j, t.k); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn to_rotation_translation(&self)         -> (Quaternion<T>, Vector3<T>) {
+        return (self.to_rotation(), self.to_translation()); } }
+    //      Precision conversion
+    //          cast() goes between any two precisions (lossy when narrowing, e.g. f64 positions to f32 for GPU upload)
+    impl<T: Scalar> Vector2<T>    { pub fn cast<U: Scalar>(&self)                        -> Vector2<U> { return Vector2::new(U::from_f64(self.x.to_f64()), U::from_f64(self.y.to_f64())); } }
+    impl<T: Scalar> Vector3<T>    { pub fn cast<U: Scalar>(&self)                        -> Vector3<U> { return Vector3::new(U::from_f64(self.x.to_f64()), U::from_f64(self.y.to_f64()), U::from_f64(self.z.to_f64())); } }
+    impl<T: Scalar> Vector4<T>    { pub fn cast<U: Scalar>(&self)                        -> Vector4<U> { return Vector4::new(U::from_f64(self.x.to_f64()), U::from_f64(self.y.to_f64()), U::from_f64(self.z.to_f64()), U::from_f64(self.w.to_f64())); } }
+    impl<T: Scalar> Complex<T>    { pub fn cast<U: Scalar>(&self)                        -> Complex<U> { return Complex::new(U::from_f64(self.r.to_f64()), U::from_f64(self.i.to_f64())); } }
+    impl<T: Scalar> Dual<T>       { pub fn cast<U: Scalar>(&self)                        -> Dual<U> { return Dual::new(U::from_f64(self.r.to_f64()), U::from_f64(self.e.to_f64())); } }
+    impl<T: Scalar> Quaternion<T> { pub fn cast<U: Scalar>(&self)                        -> Quaternion<U> { return Quaternion::new(U::from_f64(self.s.to_f64()), U::from_f64(self.i.to_f64()), U::from_f64(self.j.to_f64()), U::from_f64(self.k.to_f64())); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn cast<U: Scalar>(&self)                    -> QuaternionDual<U> { return QuaternionDual::new(self.r.cast(), self.e.cast()); } }
+    impl<T: Scalar> Matrix2<T>    { pub fn cast<U: Scalar>(&self)                        -> Matrix2<U> {
+        let mut m = Matrix2::zero();
+        for c in 0..2 { for r in 0..2 { m.e[c][r] = U::from_f64(self.e[c][r].to_f64()); } }
+        return m; } }
+    impl<T: Scalar> Matrix3<T>    { pub fn cast<U: Scalar>(&self)                        -> Matrix3<U> {
+        let mut m = Matrix3::zero();
+        for c in 0..3 { for r in 0..3 { m.e[c][r] = U::from_f64(self.e[c][r].to_f64()); } }
+        return m; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn cast<U: Scalar>(&self)                        -> Matrix4<U> {
+        let mut m = Matrix4::zero();
+        for c in 0..4 { for r in 0..4 { m.e[c][r] = U::from_f64(self.e[c][r].to_f64()); } }
+        return m; } }
+    //          Widening f32 -> f64 is lossless, so it is also available through From/Into
+    impl From<Vector2<f32>>         for Vector2<f64>        { fn from(v: Vector2<f32>)          -> Self { return v.cast(); } }
+    impl From<Vector3<f32>>         for Vector3<f64>        { fn from(v: Vector3<f32>)          -> Self { return v.cast(); } }
+    impl From<Vector4<f32>>         for Vector4<f64>        { fn from(v: Vector4<f32>)          -> Self { return v.cast(); } }
+    impl From<Complex<f32>>         for Complex<f64>        { fn from(c: Complex<f32>)          -> Self { return c.cast(); } }
+    impl From<Dual<f32>>            for Dual<f64>           { fn from(d: Dual<f32>)             -> Self { return d.cast(); } }
+    impl From<Quaternion<f32>>      for Quaternion<f64>     { fn from(q: Quaternion<f32>)       -> Self { return q.cast(); } }
+    impl From<QuaternionDual<f32>>  for QuaternionDual<f64> { fn from(dq: QuaternionDual<f32>)  -> Self { return dq.cast(); } }
+    impl From<Matrix2<f32>>         for Matrix2<f64>        { fn from(m: Matrix2<f32>)          -> Self { return m.cast(); } }
+    impl From<Matrix3<f32>>         for Matrix3<f64>        { fn from(m: Matrix3<f32>)          -> Self { return m.cast(); } }
+    impl From<Matrix4<f32>>         for Matrix4<f64>        { fn from(m: Matrix4<f32>)          -> Self { return m.cast(); } }
+    //      Arrays (matrix arrays are arrays of columns, [[T; N]; N] = [column][row], same as the storage order)
+    impl<T: Scalar> From<[T; 2]>        for Vector2<T>      { fn from(a: [T; 2])            -> Self { return Self::new(a[0], a[1]); } }
+    impl<T: Scalar> From<[T; 3]>        for Vector3<T>      { fn from(a: [T; 3])            -> Self { return Self::new(a[0], a[1], a[2]); } }
+    impl<T: Scalar> From<[T; 4]>        for Vector4<T>      { fn from(a: [T; 4])            -> Self { return Self::new(a[0], a[1], a[2], a[3]); } }
+    impl<T: Scalar> From<[[T; 2]; 2]>   for Matrix2<T>      { fn from(a: [[T; 2]; 2])       -> Self { return Self { e: a }; } }
+    impl<T: Scalar> From<[[T; 3]; 3]>   for Matrix3<T>      { fn from(a: [[T; 3]; 3])       -> Self { return Self { e: a }; } }
+    impl<T: Scalar> From<[[T; 4]; 4]>   for Matrix4<T>      { fn from(a: [[T; 4]; 4])       -> Self { return Self { e: a }; } }
+    impl<T: Scalar> From<Vector2<T>>    for [T; 2]          { fn from(v: Vector2<T>)        -> Self { return [v.x, v.y]; } }
+    impl<T: Scalar> From<Vector3<T>>    for [T; 3]          { fn from(v: Vector3<T>)        -> Self { return [v.x, v.y, v.z]; } }
+    impl<T: Scalar> From<Vector4<T>>    for [T; 4]          { fn from(v: Vector4<T>)        -> Self { return [v.x, v.y, v.z, v.w]; } }
+    impl<T: Scalar> From<Matrix2<T>>    for [[T; 2]; 2]     { fn from(m: Matrix2<T>)        -> Self { return m.e; } }
+    impl<T: Scalar> From<Matrix3<T>>    for [[T; 3]; 3]     { fn from(m: Matrix3<T>)        -> Self { return m.e; } }
+    impl<T: Scalar> From<Matrix4<T>>    for [[T; 4]; 4]     { fn from(m: Matrix4<T>)        -> Self { return m.e; } }
     //      https://stackoverflow.com/questions/36138768/finding-minor-matrices-of-3x3-matrix-c
     
     // Transpose (also implemented for unary operator [-])
-    impl Matrix2    { pub fn transpose(&self)                           -> Self {
+    impl<T: Scalar> Matrix2<T>    { pub fn transpose(&self)                           -> Self {
         return Self::new(
             self.e[0][0],   self.e[0][1],
             self.e[1][0],   self.e[1][1]); } }
-    impl Matrix3    { pub fn transpose(&self)                           -> Self {
+    impl<T: Scalar> Matrix3<T>    { pub fn transpose(&self)                           -> Self {
         return Self::new(
             self.e[0][0],   self.e[0][1],   self.e[0][2],
             self.e[1][0],   self.e[1][1],   self.e[1][2],
             self.e[2][0],   self.e[2][1],   self.e[2][2]); } }
-    impl Matrix4    { pub fn transpose(&self)                           -> Self {
+    impl<T: Scalar> Matrix4<T>    { pub fn transpose(&self)                           -> Self {
         return Self::new(
             self.e[0][0],   self.e[0][1],   self.e[0][2],   self.e[0][3],
             self.e[1][0],   self.e[1][1],   self.e[1][2],   self.e[1][3],
             self.e[2][0],   self.e[2][1],   self.e[2][2],   self.e[2][3],
             self.e[3][0],   self.e[3][1],   self.e[3][2],   self.e[3][3]); } }
-    // Matrix minor
-    impl Matrix2    { pub fn minor(&self, i: usize, j: usize)           -> f32 { return self.e[1 - i][1 - j]; } }
-    impl Matrix3    { pub fn minor(&self, i: usize, j: usize)           -> Matrix2 {
+    // Matrix minor (row i and column j removed)
+    impl<T: Scalar> Matrix2<T>    { pub fn minor(&self, i: usize, j: usize)           -> T { return self.e[1 - j][1 - i]; } }
+    impl<T: Scalar> Matrix3<T>    { pub fn minor(&self, i: usize, j: usize)           -> Matrix2<T> {
         let mut M = Matrix2::zero();
         let mut row = 0;
         let mut col = 0;
@@ -318,12 +929,12 @@ pub mod linalg {
                     col = col - 1;
                 }
                 if a != i && b != j {
-                    M.e[row][col] = self.e[a][b];
+                    M.e[col][row] = self.e[b][a];
                 }
             }
         }
         return M; } }
-    impl Matrix4    { pub fn minor(&self, i: usize, j: usize)           -> Matrix3 {
+    impl<T: Scalar> Matrix4<T>    { pub fn minor(&self, i: usize, j: usize)           -> Matrix3<T> {
         let mut M = Matrix3::zero();
         let mut row = 0;
         let mut col = 0;
@@ -339,7 +950,7 @@ pub mod linalg {
                     col = col - 1;
                 }
                 if a != i && b != j {
-                    M.e[row][col] = self.e[a][b];
+                    M.e[col][row] = self.e[b][a];
                 }
             }
         }
@@ -347,40 +958,40 @@ pub mod linalg {
 
     
     // Cofactors
-    impl Matrix2    { pub fn cofactor(&self, i: usize, j: usize)        -> f32 {
-        return self.minor(i, j) * f32::signum((((i % 2) as f32) - 0.5) * (((j % 2) as f32) - 0.5)); } }
-    impl Matrix3    { pub fn cofactor(&self, i: usize, j: usize)        -> f32 {
-        return self.minor(i, j).determinant() * f32::signum((((i % 2) as f32) - 0.5) * (((j % 2) as f32) - 0.5)); } }
-    impl Matrix4    { pub fn cofactor(&self, i: usize, j: usize)        -> f32 {
-        return self.minor(i, j).determinant() * f32::signum((((i % 2) as f32) - 0.5) * (((j % 2) as f32) - 0.5)); } }
+    impl<T: Scalar> Matrix2<T>    { pub fn cofactor(&self, i: usize, j: usize)        -> T {
+        return self.minor(i, j) * (if (i + j) % 2 == 0 { T::ONE } else { -T::ONE }); } }
+    impl<T: Scalar> Matrix3<T>    { pub fn cofactor(&self, i: usize, j: usize)        -> T {
+        return self.minor(i, j).determinant() * (if (i + j) % 2 == 0 { T::ONE } else { -T::ONE }); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn cofactor(&self, i: usize, j: usize)        -> T {
+        return self.minor(i, j).determinant() * (if (i + j) % 2 == 0 { T::ONE } else { -T::ONE }); } }
 
     // Cofactor matrix
-    impl Matrix2    { pub fn cofactor_matrix(&self)                     -> Self {
+    impl<T: Scalar> Matrix2<T>    { pub fn cofactor_matrix(&self)                     -> Self {
         let mut c = Vec::new();
         for i in 0..2 {
             for j in 0..2 {
-                c.push(self.cofactor(j, i));
+                c.push(self.cofactor(i, j));
             }
         }
         return Matrix2::new(
             c[0],   c[1],
             c[2],   c[3]); } }
-    impl Matrix3    { pub fn cofactor_matrix(&self)                     -> Self {
+    impl<T: Scalar> Matrix3<T>    { pub fn cofactor_matrix(&self)                     -> Self {
         let mut c = Vec::new();
         for i in 0..3 {
             for j in 0..3 {
-                c.push(self.cofactor(j, i));
+                c.push(self.cofactor(i, j));
             }
         }
         return Matrix3::new(
             c[0],   c[1],   c[2],
             c[3],   c[4],   c[5],
             c[6],   c[7],   c[8]); } }
-    impl Matrix4    { pub fn cofactor_matrix(&self)                     -> Self {
+    impl<T: Scalar> Matrix4<T>    { pub fn cofactor_matrix(&self)                     -> Self {
         let mut c = Vec::new();
         for i in 0..4 {
             for j in 0..4 {
-                c.push(self.cofactor(j, i));
+                c.push(self.cofactor(i, j));
             }
         }
         return Matrix4::new(
@@ -390,49 +1001,100 @@ pub mod linalg {
             c[12],  c[13],  c[14],  c[15]); } }
 
     // Matrix adjugate
-    impl Matrix2    { pub fn adjugate(&self)                            -> Self {
+    impl<T: Scalar> Matrix2<T>    { pub fn adjugate(&self)                            -> Self {
         return self.cofactor_matrix().transpose(); } }
-    impl Matrix3    { pub fn adjugate(&self)                            -> Self {
+    impl<T: Scalar> Matrix3<T>    { pub fn adjugate(&self)                            -> Self {
         return self.cofactor_matrix().transpose(); } }
-    impl Matrix4    { pub fn adjugate(&self)                            -> Self {
+    impl<T: Scalar> Matrix4<T>    { pub fn adjugate(&self)                            -> Self {
         return self.cofactor_matrix().transpose(); } }
     // Inverse (also implemented for unary operator [!])
-    impl Matrix2    { pub fn inverse(&self)                             -> Self {
-        if self.determinant() == 0.0 {
+    //      Returns the zero matrix for an exactly singular matrix. Use try_inverse() to catch near-singular ones,
+    //      and inverse_lu() (linalg::decomposition) for better precision on badly scaled matrices.
+    impl<T: Scalar> Matrix2<T>    { pub fn inverse(&self)                             -> Self {
+        if self.determinant() == T::ZERO {
             return Self::zero();
         } else {
             return self.adjugate() / self.determinant(); } } }
-    impl Matrix3    { pub fn inverse(&self)                             -> Self {
-        if self.determinant() == 0.0 {
+    impl<T: Scalar> Matrix3<T>    { pub fn inverse(&self)                             -> Self {
+        if self.determinant() == T::ZERO {
             return Self::zero();
         } else {
             return self.adjugate() / self.determinant(); } } }
-    impl Matrix4    { pub fn inverse(&self)                             -> Self {
-        if self.determinant() == 0.0 {
+    impl<T: Scalar> Matrix4<T>    { pub fn inverse(&self)                             -> Self {
+        if self.determinant() == T::ZERO {
             return Self::zero();
         } else {
             return self.adjugate() / self.determinant(); } } }
+    //      Fallible inverse. The determinant is compared relative to the product of the column lengths, its largest
+    //      possible value (Hadamard's inequality: |det| <= |c0| |c1| ... |cn|), so scaling a matrix does not change
+    //      whether it counts as invertible, and a large translation column only weighs in once.
+    //      The inverse itself comes from the pivoted LU (linalg::decomposition) rather than the adjugate.
+    fn column_lengths<T: Scalar>(a: &[T], n: usize) -> T {
+        return a.chunks(n).fold(T::ONE, |p, c| p * T::sqrt(c.iter().fold(T::ZERO, |s, &x| s + x * x))); }
+    impl<T: Scalar> Matrix2<T>    { pub fn is_invertible(&self)                       -> bool { return self.is_invertible_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Matrix3<T>    { pub fn is_invertible(&self)                       -> bool { return self.is_invertible_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn is_invertible(&self)                       -> bool { return self.is_invertible_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Matrix2<T>    { pub fn is_invertible_eps(&self, epsilon: T)       -> bool {
+        let h = column_lengths(self.as_slice(), 2);
+        return h > T::ZERO && self.determinant().abs() > epsilon * h; } }
+    impl<T: Scalar> Matrix3<T>    { pub fn is_invertible_eps(&self, epsilon: T)       -> bool {
+        let h = column_lengths(self.as_slice(), 3);
+        return h > T::ZERO && self.determinant().abs() > epsilon * h; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn is_invertible_eps(&self, epsilon: T)       -> bool {
+        let h = column_lengths(self.as_slice(), 4);
+        return h > T::ZERO && self.determinant().abs() > epsilon * h; } }
+    impl<T: Scalar> Matrix2<T>    { pub fn try_inverse(&self)                         -> Option<Self> { return self.try_inverse_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Matrix3<T>    { pub fn try_inverse(&self)                         -> Option<Self> { return self.try_inverse_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn try_inverse(&self)                         -> Option<Self> { return self.try_inverse_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Matrix2<T>    { pub fn try_inverse_eps(&self, epsilon: T)         -> Option<Self> {
+        return if self.is_invertible_eps(epsilon) { self.inverse_lu() } else { None }; } }
+    impl<T: Scalar> Matrix3<T>    { pub fn try_inverse_eps(&self, epsilon: T)         -> Option<Self> {
+        return if self.is_invertible_eps(epsilon) { self.inverse_lu() } else { None }; } }
+    impl<T: Scalar> Matrix4<T>    { pub fn try_inverse_eps(&self, epsilon: T)         -> Option<Self> {
+        return if self.is_invertible_eps(epsilon) { self.inverse_lu() } else { None }; } }
+    //      Fast paths for transforms with [0, 0, 0, 1] as the bottom row
+    //          Affine (model matrices with scale or shear): inverts the 3x3 part only, t' = -A^-1 * t.
+    //          A singular 3x3 part (a scale of zero) gives the zero 3x3 part, like inverse(); try_inverse_affine() catches it.
+    impl<T: Scalar> Matrix4<T>    { pub fn inverse_affine(&self)                      -> Self { return self.with_affine_inverse(&Matrix3::from_matrix4(self).inverse()); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn try_inverse_affine(&self)                  -> Option<Self> {
+        return Matrix3::from_matrix4(self).try_inverse().map(|a| self.with_affine_inverse(&a)); } }
+    impl<T: Scalar> Matrix4<T>    { fn with_affine_inverse(&self, a: &Matrix3<T>)     -> Self {
+        let t = (*a) * Vector3::new(-self.e[3][0], -self.e[3][1], -self.e[3][2]);
+        return Self::new(
+            a.e[0][0],  a.e[1][0],  a.e[2][0],  t.x,
+            a.e[0][1],  a.e[1][1],  a.e[2][1],  t.y,
+            a.e[0][2],  a.e[1][2],  a.e[2][2],  t.z,
+            T::ZERO,    T::ZERO,    T::ZERO,    T::ONE); } }
+    //          Rigid (view matrices, rotation and translation only): R^-1 = R^T, t' = -R^T * t
+    impl<T: Scalar> Matrix4<T>    { pub fn inverse_orthonormal(&self)                 -> Self {
+        let r = Matrix3::from_matrix4(self).transpose();
+        let t = r * Vector3::new(-self.e[3][0], -self.e[3][1], -self.e[3][2]);
+        return Self::new(
+            r.e[0][0],  r.e[1][0],  r.e[2][0],  t.x,
+            r.e[0][1],  r.e[1][1],  r.e[2][1],  t.y,
+            r.e[0][2],  r.e[1][2],  r.e[2][2],  t.z,
+            T::ZERO,    T::ZERO,    T::ZERO,    T::ONE); } }
 
     // Matrix triangulation
-    impl Matrix2    { pub fn triangular_lower(&self)                    -> Self {
-        let col0 = self.column(0);
-        let col1 = self.column(1);
-
-        return Matrix2::from_vector2(col0, col1 - (col1.x / col0.x) * col0); } }
-    impl Matrix3    { pub fn triangular_lower(&self)                    -> Self {
-        let col0 = self.column(0);
-        let col1 = self.column(1);
-        let col2 = self.column(2);
+    impl<T: Scalar> Matrix2<T>    { pub fn triangular_lower(&self)                    -> Self {
+        let col0 = self.row(0);
+        let col1 = self.row(1);
+
+        return Matrix2::from_vector2(col0, col1 - col0 * (col1.x / col0.x)); } }
+    impl<T: Scalar> Matrix3<T>    { pub fn triangular_lower(&self)                    -> Self {
+        let col0 = self.row(0);
+        let col1 = self.row(1);
+        let col2 = self.row(2);
         let col1_a = col1 - col0 * (col1.x / col0.x);
         let col2_a = col2 - col0 * (col2.x / col0.x);
         let col2_b = col2_a - col1_a * (col2_a.y / col1_a.y);
 
         return Matrix3::from_vector3(col0, col1_a, col2_b); } }
-    impl Matrix4    { pub fn triangular_lower(&self)                    -> Self {
-        let col0 = self.column(0);
-        let col1 = self.column(1);
-        let col2 = self.column(2);
-        let col3 = self.column(3);
+    impl<T: Scalar> Matrix4<T>    { pub fn triangular_lower(&self)                    -> Self {
+        let col0 = self.row(0);
+        let col1 = self.row(1);
+        let col2 = self.row(2);
+        let col3 = self.row(3);
         let col1_a = col1 - col0 * (col1.x / col0.x);
         let col2_a = col2 - col0 * (col2.x / col0.x);
         let col3_a = col3 - col0 * (col3.x / col0.x);
@@ -441,25 +1103,25 @@ pub mod linalg {
         let col3_c = col3_b - col2_b * (col3_b.z / col2_b.z);
 
         return Matrix4::from_vector4(col0, col1_a, col2_b, col3_c); } }
-    impl Matrix2    { pub fn triangular_upper(&self)                    -> Self {
-        let col0 = self.column(0);
-        let col1 = self.column(1);
+    impl<T: Scalar> Matrix2<T>    { pub fn triangular_upper(&self)                    -> Self {
+        let col0 = self.row(0);
+        let col1 = self.row(1);
 
         return Matrix2::from_vector2(col0 - col1 * (col0.y / col1.y), col1); } }
-    impl Matrix3    { pub fn triangular_upper(&self)                    -> Self {
-        let col0 = self.column(0);
-        let col1 = self.column(1);
-        let col2 = self.column(2);
+    impl<T: Scalar> Matrix3<T>    { pub fn triangular_upper(&self)                    -> Self {
+        let col0 = self.row(0);
+        let col1 = self.row(1);
+        let col2 = self.row(2);
         let col0_a = col0 - col2 * (col0.z / col2.z);
         let col1_a = col1 - col2 * (col1.z / col2.z);
         let col0_b = col0_a - col1_a * (col0_a.y / col1_a.y);
 
         return Matrix3::from_vector3(col0_b, col1_a, col2); } }
-    impl Matrix4    { pub fn triangular_upper(&self)                    -> Self {
-        let col0 = self.column(0);
-        let col1 = self.column(1);
-        let col2 = self.column(2);
-        let col3 = self.column(3);
+    impl<T: Scalar> Matrix4<T>    { pub fn triangular_upper(&self)                    -> Self {
+        let col0 = self.row(0);
+        let col1 = self.row(1);
+        let col2 = self.row(2);
+        let col3 = self.row(3);
         let col0_a = col0 - col3 * (col0.w / col3.w); 
         let col1_a = col1 - col3 * (col1.w / col3.w); 
         let col2_a = col2 - col3 * (col2.w / col3.w); 
@@ -470,71 +1132,77 @@ pub mod linalg {
         return Matrix4::from_vector4(col0_c, col1_b, col2_a, col3); } }
 
     // Prefabrication
-    impl Vector2 { pub fn one()         -> Self { return Self::new(1.0, 1.0); } }
-    impl Vector3 { pub fn one()         -> Self { return Self::new(1.0, 1.0, 1.0); } }
-    impl Vector4 { pub fn one()         -> Self { return Self::new(1.0, 1.0, 1.0, 1.0); } }
-    impl Matrix2 { pub fn one()         -> Self { return Self::new(1.0, 1.0, 1.0, 1.0); } }
-    impl Matrix3 { pub fn one()         -> Self { return Self::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0); } }
-    impl Matrix4 { pub fn one()         -> Self { return Self::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0); } }
-    impl Vector2 { pub fn zero()        -> Self { return Self::new(0.0, 0.0); } }
-    impl Vector3 { pub fn zero()        -> Self { return Self::new(0.0, 0.0, 0.0); } }
-    impl Vector4 { pub fn zero()        -> Self { return Self::new(0.0, 0.0, 0.0, 0.0); } }
-    impl Matrix2 { pub fn zero()        -> Self { return Self::new(0.0, 0.0, 0.0, 0.0); } }
-    impl Matrix3 { pub fn zero()        -> Self { return Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0); } }
-    impl Matrix4 { pub fn zero()        -> Self { return Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0); } }
-    impl Matrix2 { pub fn identity()    -> Self { return Self::new(1.0, 0.0, 0.0, 1.0); } }
-    impl Matrix3 { pub fn identity()    -> Self { return Self::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0); } }
-    impl Matrix4 { pub fn identity()    -> Self { return Self::new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0); } }
-
-    impl Vector2 { pub fn right()       -> Self { return Self::new(1.0, 0.0); } }
-    impl Vector3 { pub fn right()       -> Self { return Self::new(1.0, 0.0, 0.0); } }
-    impl Vector2 { pub fn left()        -> Self { return Self::new(-1.0, 0.0); } }
-    impl Vector3 { pub fn left()        -> Self { return Self::new(-1.0, 0.0, 0.0); } }
-    impl Vector2 { pub fn forth()       -> Self { return Self::new(0.0, 1.0); } }
-    impl Vector3 { pub fn forth()       -> Self { return Self::new(0.0, 1.0, 0.0); } }
-    impl Vector2 { pub fn back()        -> Self { return Self::new(0.0, -1.0); } }
-    impl Vector3 { pub fn back()        -> Self { return Self::new(0.0, -1.0, 0.0); } }
-    impl Vector3 { pub fn up()          -> Self { return Self::new(0.0, 0.0, 1.0); } }
-    impl Vector3 { pub fn down()        -> Self { return Self::new(0.0, 0.0, -1.0); } }
-
-    impl Vector2 { pub fn Q1()          -> Self { return Self::new(1.0, 1.0); } }
-    impl Vector2 { pub fn Q1n()         -> Self { return Self::new(SQRT2OVER2, SQRT2OVER2); } }
-    impl Vector3 { pub fn Q1()          -> Self { return Self::new(1.0, 1.0, 1.0); } }
-    impl Vector3 { pub fn Q1n()         -> Self { return Self::new(SQRT3OVER3, SQRT3OVER3, SQRT3OVER3); } }
-    impl Vector2 { pub fn Q2()          -> Self { return Self::new(-1.0, 1.0); } }
-    impl Vector2 { pub fn Q2n()         -> Self { return Self::new(-SQRT2OVER2, SQRT2OVER2); } }
-    impl Vector3 { pub fn Q2()          -> Self { return Self::new(-1.0, 1.0, 1.0); } }
-    impl Vector3 { pub fn Q2n()         -> Self { return Self::new(-SQRT3OVER3, SQRT3OVER3, SQRT3OVER3); } }
-    impl Vector2 { pub fn Q3()          -> Self { return Self::new(-1.0, -1.0); } }
-    impl Vector2 { pub fn Q3n()         -> Self { return Self::new(-SQRT2OVER2, -SQRT2OVER2); } }
-    impl Vector3 { pub fn Q3()          -> Self { return Self::new(-1.0, -1.0, 1.0); } }
-    impl Vector3 { pub fn Q3n()         -> Self { return Self::new(-SQRT3OVER3, -SQRT3OVER3, SQRT3OVER3); } }
-    impl Vector2 { pub fn Q4()          -> Self { return Self::new(1.0, 1.0); } }
-    impl Vector2 { pub fn Q4n()         -> Self { return Self::new(SQRT2OVER2, SQRT2OVER2); } }
-    impl Vector3 { pub fn Q4()          -> Self { return Self::new(1.0, 1.0, 1.0); } }
-    impl Vector3 { pub fn Q4n()         -> Self { return Self::new(SQRT3OVER3, SQRT3OVER3, SQRT3OVER3); } }
-    impl Vector3 { pub fn Q5()          -> Self { return Self::new(1.0, 1.0, -1.0); } }
-    impl Vector3 { pub fn Q5n()         -> Self { return Self::new(SQRT3OVER3, SQRT3OVER3, -SQRT3OVER3); } }
-    impl Vector3 { pub fn Q6()          -> Self { return Self::new(-1.0, 1.0, -1.0); } }
-    impl Vector3 { pub fn Q6n()         -> Self { return Self::new(-SQRT3OVER3, SQRT3OVER3, -SQRT3OVER3); } }
-    impl Vector3 { pub fn Q7()          -> Self { return Self::new(-1.0, -1.0, -1.0); } }
-    impl Vector3 { pub fn Q7n()         -> Self { return Self::new(-SQRT3OVER3, -SQRT3OVER3, -SQRT3OVER3); } }
-    impl Vector3 { pub fn Q8()          -> Self { return Self::new(1.0, -1.0, -1.0); } }
-    impl Vector3 { pub fn Q8n()         -> Self { return Self::new(SQRT3OVER3, -SQRT3OVER3, -SQRT3OVER3); } }
+    impl<T: Scalar> Vector2<T> { pub fn one()         -> Self { return Self::new(T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn one()         -> Self { return Self::new(T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector4<T> { pub fn one()         -> Self { return Self::new(T::ONE, T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Matrix2<T> { pub fn one()         -> Self { return Self::new(T::ONE, T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Matrix3<T> { pub fn one()         -> Self { return Self::new(T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Matrix4<T> { pub fn one()         -> Self { return Self::new(T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector2<T> { pub fn zero()        -> Self { return Self::new(T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Vector3<T> { pub fn zero()        -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Vector4<T> { pub fn zero()        -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Matrix2<T> { pub fn zero()        -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Matrix3<T> { pub fn zero()        -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Matrix4<T> { pub fn zero()        -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Matrix2<T> { pub fn identity()    -> Self { return Self::new(T::ONE, T::ZERO, T::ZERO, T::ONE); } }
+    impl<T: Scalar> Matrix3<T> { pub fn identity()    -> Self { return Self::new(T::ONE, T::ZERO, T::ZERO, T::ZERO, T::ONE, T::ZERO, T::ZERO, T::ZERO, T::ONE); } }
+    impl<T: Scalar> Matrix4<T> { pub fn identity()    -> Self { return Self::new(T::ONE, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ONE, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ONE, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ONE); } }
+    impl<T: Scalar> Quaternion<T> { pub fn zero()     -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Quaternion<T> { pub fn identity() -> Self { return Self::new(T::ONE, T::ZERO, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn zero()     -> Self { return Self::new(Quaternion::zero(), Quaternion::zero()); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn identity() -> Self { return Self::new(Quaternion::identity(), Quaternion::zero()); } }
+
+    impl<T: Scalar> Vector2<T> { pub fn right()       -> Self { return Self::new(T::ONE, T::ZERO); } }
+    impl<T: Scalar> Vector3<T> { pub fn right()       -> Self { return Self::new(T::ONE, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Vector2<T> { pub fn left()        -> Self { return Self::new(-T::ONE, T::ZERO); } }
+    impl<T: Scalar> Vector3<T> { pub fn left()        -> Self { return Self::new(-T::ONE, T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Vector2<T> { pub fn forth()       -> Self { return Self::new(T::ZERO, T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn forth()       -> Self { return Self::new(T::ZERO, T::ONE, T::ZERO); } }
+    impl<T: Scalar> Vector2<T> { pub fn back()        -> Self { return Self::new(T::ZERO, -T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn back()        -> Self { return Self::new(T::ZERO, -T::ONE, T::ZERO); } }
+    impl<T: Scalar> Vector3<T> { pub fn up()          -> Self { return Self::new(T::ZERO, T::ZERO, T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn down()        -> Self { return Self::new(T::ZERO, T::ZERO, -T::ONE); } }
+
+    impl<T: Scalar> Vector2<T> { pub fn Q1()          -> Self { return Self::new(T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector2<T> { pub fn Q1n()         -> Self { return Self::new(T::from_f32(SQRT2OVER2), T::from_f32(SQRT2OVER2)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q1()          -> Self { return Self::new(T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q1n()         -> Self { return Self::new(T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3)); } }
+    impl<T: Scalar> Vector2<T> { pub fn Q2()          -> Self { return Self::new(-T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector2<T> { pub fn Q2n()         -> Self { return Self::new(-T::from_f32(SQRT2OVER2), T::from_f32(SQRT2OVER2)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q2()          -> Self { return Self::new(-T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q2n()         -> Self { return Self::new(-T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3)); } }
+    impl<T: Scalar> Vector2<T> { pub fn Q3()          -> Self { return Self::new(-T::ONE, -T::ONE); } }
+    impl<T: Scalar> Vector2<T> { pub fn Q3n()         -> Self { return Self::new(-T::from_f32(SQRT2OVER2), -T::from_f32(SQRT2OVER2)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q3()          -> Self { return Self::new(-T::ONE, -T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q3n()         -> Self { return Self::new(-T::from_f32(SQRT3OVER3), -T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3)); } }
+    impl<T: Scalar> Vector2<T> { pub fn Q4()          -> Self { return Self::new(T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector2<T> { pub fn Q4n()         -> Self { return Self::new(T::from_f32(SQRT2OVER2), T::from_f32(SQRT2OVER2)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q4()          -> Self { return Self::new(T::ONE, T::ONE, T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q4n()         -> Self { return Self::new(T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q5()          -> Self { return Self::new(T::ONE, T::ONE, -T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q5n()         -> Self { return Self::new(T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3), -T::from_f32(SQRT3OVER3)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q6()          -> Self { return Self::new(-T::ONE, T::ONE, -T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q6n()         -> Self { return Self::new(-T::from_f32(SQRT3OVER3), T::from_f32(SQRT3OVER3), -T::from_f32(SQRT3OVER3)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q7()          -> Self { return Self::new(-T::ONE, -T::ONE, -T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q7n()         -> Self { return Self::new(-T::from_f32(SQRT3OVER3), -T::from_f32(SQRT3OVER3), -T::from_f32(SQRT3OVER3)); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q8()          -> Self { return Self::new(T::ONE, -T::ONE, -T::ONE); } }
+    impl<T: Scalar> Vector3<T> { pub fn Q8n()         -> Self { return Self::new(T::from_f32(SQRT3OVER3), -T::from_f32(SQRT3OVER3), -T::from_f32(SQRT3OVER3)); } }
     
-    // impl Complex { /* Unfinished */ }
-    // impl Dual { /* Unfinished */ }
-    // impl Quaternion { /* Unfinished */ }
-    // impl QuaternionDual { /* Unfinished */ }
+    impl<T: Scalar> Complex<T> { pub fn zero()        -> Self { return Self::new(T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Complex<T> { pub fn one()         -> Self { return Self::new(T::ONE, T::ZERO); } }
+    impl<T: Scalar> Complex<T> { pub fn identity()    -> Self { return Self::one(); } }
+    impl<T: Scalar> Complex<T> { pub fn i()           -> Self { return Self::new(T::ZERO, T::ONE); } }
+    impl<T: Scalar> Dual<T> { pub fn zero()           -> Self { return Self::new(T::ZERO, T::ZERO); } }
+    impl<T: Scalar> Dual<T> { pub fn one()            -> Self { return Self::new(T::ONE, T::ZERO); } }
     
     // Swizzling
-    impl Vector2 {
+    impl<T: Scalar> Vector2<T> {
         pub fn xx(&self)    -> Self { return Vector2::new(self.x, self.x); }
         pub fn xy(&self)    -> Self { return Vector2::new(self.x, self.y); }
         pub fn yx(&self)    -> Self { return Vector2::new(self.y, self.x); }
         pub fn yy(&self)    -> Self { return Vector2::new(self.y, self.y); }
     }
-    impl Vector3 {
+    impl<T: Scalar> Vector3<T> {
         pub fn xxx(&self)   -> Self { return Vector3::new(self.x, self.x, self.x); }
         pub fn xxy(&self)   -> Self { return Vector3::new(self.x, self.x, self.y); }
         pub fn xxz(&self)   -> Self { return Vector3::new(self.x, self.x, self.z); }
@@ -573,7 +1241,7 @@ pub mod linalg {
         pub fn zzy(&self)   -> Self { return Vector3::new(self.z, self.z, self.y); }
         pub fn zzz(&self)   -> Self { return Vector3::new(self.z, self.z, self.z); }
     }
-    impl Vector4 {
+    impl<T: Scalar> Vector4<T> {
         pub fn xxxx(&self)  -> Self { return Vector4::new(self.x, self.x, self.x, self.x); }
         pub fn xxxy(&self)  -> Self { return Vector4::new(self.x, self.x, self.x, self.y); }
         pub fn xxxz(&self)  -> Self { return Vector4::new(self.x, self.x, self.x, self.z); }
@@ -915,33 +1583,35 @@ pub mod linalg {
 
     // Utilities
     //      (Also implement to_latex)
-    impl Vector2    { pub fn to_string(&self)   -> String {
+    impl<T: Scalar> Vector2<T>    { pub fn to_string(&self)   -> String {
         return format!("[{}, {}]", self.x, self.y); } }
-    impl Complex    { pub fn to_string(&self)   -> String {
+    impl<T: Scalar> Complex<T>    { pub fn to_string(&self)   -> String {
         return format!("[{} + {}i]", self.r, self.i); } }
-    impl Dual       { pub fn to_string(&self)   -> String {
+    impl<T: Scalar> Dual<T>       { pub fn to_string(&self)   -> String {
         return format!("[{} + {}ε]", self.r, self.e); } }
-    impl Vector3    { pub fn to_string(&self)   -> String {
+    impl<T: Scalar> Vector3<T>    { pub fn to_string(&self)   -> String {
         return format!("[{}, {}, {}]", self.x, self.y, self.z); } }
-    impl Vector4    { pub fn to_string(&self)   -> String {
+    impl<T: Scalar> Vector4<T>    { pub fn to_string(&self)   -> String {
         return format!("[{}, {}, {}, {}]", self.x, self.y, self.z, self.w); } }
-    // impl Quaternion { /* Unfinished */ }
-    // impl QuaternionDual { /* Unfinished */ }
-    impl Matrix2    { pub fn to_string(&self)   -> String {
+    impl<T: Scalar> Quaternion<T> { pub fn to_string(&self)   -> String {
+        return format!("[{} + {}i + {}j + {}k]", self.s, self.i, self.j, self.k); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn to_string(&self) -> String {
+        return format!("[{} + {}ε]", self.r.to_string(), self.e.to_string()); } }
+    impl<T: Scalar> Matrix2<T>    { pub fn to_string(&self)   -> String {
         return format!("[[{}, {}], [{}, {}]]", 
-            self.e[0][0], self.e[0][1], 
-            self.e[1][0], self.e[1][1]); } }
-    impl Matrix3    { pub fn to_string(&self)   -> String {
+            self.e[0][0], self.e[1][0],
+            self.e[0][1], self.e[1][1]); } }
+    impl<T: Scalar> Matrix3<T>    { pub fn to_string(&self)   -> String {
         return format!("[[{}, {}, {}], [{}, {}, {}], [{}, {}, {}]]", 
-            self.e[0][0], self.e[0][1], self.e[0][2], 
-            self.e[1][0], self.e[1][1], self.e[1][2],
-            self.e[2][0], self.e[2][1], self.e[2][2]); } }
-    impl Matrix4    { pub fn to_string(&self)   -> String {
+            self.e[0][0], self.e[1][0], self.e[2][0],
+            self.e[0][1], self.e[1][1], self.e[2][1],
+            self.e[0][2], self.e[1][2], self.e[2][2]); } }
+    impl<T: Scalar> Matrix4<T>    { pub fn to_string(&self)   -> String {
         return format!("[[{}, {}, {}, {}], [{}, {}, {}, {}], [{}, {}, {}, {}], [{}, {}, {}, {}]]", 
-            self.e[0][0], self.e[0][1], self.e[0][2], self.e[0][3],
-            self.e[1][0], self.e[1][1], self.e[1][2], self.e[1][3],
-            self.e[2][0], self.e[2][1], self.e[2][2], self.e[2][3],
-            self.e[3][0], self.e[3][1], self.e[3][2], self.e[3][3]); } }
+            self.e[0][0], self.e[1][0], self.e[2][0], self.e[3][0],
+            self.e[0][1], self.e[1][1], self.e[2][1], self.e[3][1],
+            self.e[0][2], self.e[1][2], self.e[2][2], self.e[3][2],
+            self.e[0][3], self.e[1][3], self.e[2][3], self.e[3][3]); } }
 
 
 
@@ -953,212 +1623,253 @@ pub mod linalg {
         impl Add<&Vector2> for &Vector2 { type Output = Vector2; fn add(self, v: &Vector2) -> Vector2 { 
             return Vector2::new(self.x + v.x, self.y + v.y); } }
         */
-    impl Add<Vector2> for Vector2 { type Output = Self; fn add(self, v: Self) -> Self {
+    impl<T: Scalar> Add<Vector2<T>> for Vector2<T> { type Output = Self; fn add(self, v: Self) -> Self {
         return Self::new(self.x + v.x, self.y + v.y); } }
-    impl Add<Complex> for Complex { type Output = Self; fn add(self, c: Self) -> Self {
+    impl<T: Scalar> Add<Complex<T>> for Complex<T> { type Output = Self; fn add(self, c: Self) -> Self {
         return Self::new(self.r + c.r, self.i + c.i); } }
-    impl Add<Dual> for Dual { type Output = Self; fn add(self, d: Self) -> Self {
+    impl<T: Scalar> Add<Dual<T>> for Dual<T> { type Output = Self; fn add(self, d: Self) -> Self {
         return Self::new(self.r + d.r, self.e + d.e); } }
-    impl Add<Vector3> for Vector3 { type Output = Self; fn add(self, v: Self) -> Self {
+    impl<T: Scalar> Add<Vector3<T>> for Vector3<T> { type Output = Self; fn add(self, v: Self) -> Self {
         return Self::new(self.x + v.x, self.y + v.y, self.z + v.z); } }
-    impl Add<Vector4> for Vector4 { type Output = Self; fn add(self, v: Self) -> Self {
+    impl<T: Scalar> Add<Vector4<T>> for Vector4<T> { type Output = Self; fn add(self, v: Self) -> Self {
         return Self::new(self.x + v.x, self.y + v.y, self.z + v.z, self.w + v.w); } }
-    impl Add<Matrix2> for Matrix2 { type Output = Self; fn add(self, m: Self) -> Self {
+    impl<T: Scalar> Add<Quaternion<T>> for Quaternion<T> { type Output = Self; fn add(self, q: Self) -> Self {
+        return Self::new(self.s + q.s, self.i + q.i, self.j + q.j, self.k + q.k); } }
+    impl<T: Scalar> Add<QuaternionDual<T>> for QuaternionDual<T> { type Output = Self; fn add(self, dq: Self) -> Self {
+        return Self::new(self.r + dq.r, self.e + dq.e); } }
+    impl<T: Scalar> Add<Matrix2<T>> for Matrix2<T> { type Output = Self; fn add(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] + m.e[0][0], self.e[0][1] + m.e[0][1],
-            self.e[1][0] + m.e[1][0], self.e[1][1] + m.e[1][1]); } }
-    impl Add<Matrix3> for Matrix3 { type Output = Self; fn add(self, m: Self) -> Self {
+            self.e[0][0] + m.e[0][0], self.e[1][0] + m.e[1][0],
+            self.e[0][1] + m.e[0][1], self.e[1][1] + m.e[1][1]); } }
+    impl<T: Scalar> Add<Matrix3<T>> for Matrix3<T> { type Output = Self; fn add(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] + m.e[0][0], self.e[0][1] + m.e[0][1], self.e[0][2] + m.e[0][2],
-            self.e[1][0] + m.e[1][0], self.e[1][1] + m.e[1][1], self.e[1][2] + m.e[1][2],
-            self.e[2][0] + m.e[2][0], self.e[2][1] + m.e[2][1], self.e[2][2] + m.e[2][2]); } }
-    impl Add<Matrix4> for Matrix4 { type Output = Self; fn add(self, m: Self) -> Self {
+            self.e[0][0] + m.e[0][0], self.e[1][0] + m.e[1][0], self.e[2][0] + m.e[2][0],
+            self.e[0][1] + m.e[0][1], self.e[1][1] + m.e[1][1], self.e[2][1] + m.e[2][1],
+            self.e[0][2] + m.e[0][2], self.e[1][2] + m.e[1][2], self.e[2][2] + m.e[2][2]); } }
+    impl<T: Scalar> Add<Matrix4<T>> for Matrix4<T> { type Output = Self; fn add(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] + m.e[0][0], self.e[0][1] + m.e[0][1], self.e[0][2] + m.e[0][2], self.e[0][3] + m.e[0][3],
-            self.e[1][0] + m.e[1][0], self.e[1][1] + m.e[1][1], self.e[1][2] + m.e[1][2], self.e[1][3] + m.e[1][3],
-            self.e[2][0] + m.e[2][0], self.e[2][1] + m.e[2][1], self.e[2][2] + m.e[2][2], self.e[2][3] + m.e[2][3],
-            self.e[3][0] + m.e[3][0], self.e[3][1] + m.e[3][1], self.e[3][2] + m.e[3][2], self.e[3][3] + m.e[3][3]); } }
-    impl AddAssign<Vector2> for Vector2 { fn add_assign(&mut self, v: Self) { 
+            self.e[0][0] + m.e[0][0], self.e[1][0] + m.e[1][0], self.e[2][0] + m.e[2][0], self.e[3][0] + m.e[3][0],
+            self.e[0][1] + m.e[0][1], self.e[1][1] + m.e[1][1], self.e[2][1] + m.e[2][1], self.e[3][1] + m.e[3][1],
+            self.e[0][2] + m.e[0][2], self.e[1][2] + m.e[1][2], self.e[2][2] + m.e[2][2], self.e[3][2] + m.e[3][2],
+            self.e[0][3] + m.e[0][3], self.e[1][3] + m.e[1][3], self.e[2][3] + m.e[2][3], self.e[3][3] + m.e[3][3]); } }
+    impl<T: Scalar> AddAssign<Vector2<T>> for Vector2<T> { fn add_assign(&mut self, v: Self) { 
         self.x += v.x; self.y += v.y; } }
-    impl AddAssign<Complex> for Complex { fn add_assign(&mut self, c: Self) { 
+    impl<T: Scalar> AddAssign<Complex<T>> for Complex<T> { fn add_assign(&mut self, c: Self) { 
         self.r += c.r; self.i += c.i; } }
-    impl AddAssign<Dual> for Dual { fn add_assign(&mut self, d: Self) { 
+    impl<T: Scalar> AddAssign<Dual<T>> for Dual<T> { fn add_assign(&mut self, d: Self) { 
         self.r += d.r; self.e += d.e; } }
-    impl AddAssign<Vector3> for Vector3 { fn add_assign(&mut self, v: Self) { 
+    impl<T: Scalar> AddAssign<Vector3<T>> for Vector3<T> { fn add_assign(&mut self, v: Self) { 
         self.x += v.x; self.y += v.y; self.z += v.z; } }
-    impl AddAssign<Vector4> for Vector4 { fn add_assign(&mut self, v: Self) { 
+    impl<T: Scalar> AddAssign<Vector4<T>> for Vector4<T> { fn add_assign(&mut self, v: Self) { 
         self.x += v.x; self.y += v.y; self.z += v.z; self.w += v.w; } }
-    impl AddAssign<Matrix2> for Matrix2 { fn add_assign(&mut self, m: Self) { 
+    impl<T: Scalar> AddAssign<Quaternion<T>> for Quaternion<T> { fn add_assign(&mut self, q: Self) { 
+        self.s += q.s; self.i += q.i; self.j += q.j; self.k += q.k; } }
+    impl<T: Scalar> AddAssign<QuaternionDual<T>> for QuaternionDual<T> { fn add_assign(&mut self, dq: Self) { 
+        self.r += dq.r; self.e += dq.e; } }
+    impl<T: Scalar> AddAssign<Matrix2<T>> for Matrix2<T> { fn add_assign(&mut self, m: Self) { 
         self.e[0][0] += m.e[0][0];  self.e[0][1] += m.e[0][1];
         self.e[1][0] += m.e[1][0];  self.e[1][1] += m.e[1][1]; } }
-    impl AddAssign<Matrix3> for Matrix3 { fn add_assign(&mut self, m: Self) { 
+    impl<T: Scalar> AddAssign<Matrix3<T>> for Matrix3<T> { fn add_assign(&mut self, m: Self) { 
         self.e[0][0] += m.e[0][0];  self.e[0][1] += m.e[0][1];  self.e[0][2] += m.e[0][2];
         self.e[1][0] += m.e[1][0];  self.e[1][1] += m.e[1][1];  self.e[1][2] += m.e[1][2];
         self.e[2][0] += m.e[2][0];  self.e[2][1] += m.e[2][1];  self.e[2][2] += m.e[2][2]; } }
-    impl AddAssign<Matrix4> for Matrix4 { fn add_assign(&mut self, m: Self) { 
+    impl<T: Scalar> AddAssign<Matrix4<T>> for Matrix4<T> { fn add_assign(&mut self, m: Self) { 
         self.e[0][0] += m.e[0][0];  self.e[0][1] += m.e[0][1];  self.e[0][2] += m.e[0][2];  self.e[0][3] += m.e[0][3];
         self.e[1][0] += m.e[1][0];  self.e[1][1] += m.e[1][1];  self.e[1][2] += m.e[1][2];  self.e[1][3] += m.e[1][3];
         self.e[2][0] += m.e[2][0];  self.e[2][1] += m.e[2][1];  self.e[2][2] += m.e[2][2];  self.e[2][3] += m.e[2][3];
         self.e[3][0] += m.e[3][0];  self.e[3][1] += m.e[3][1];  self.e[3][2] += m.e[3][2];  self.e[3][3] += m.e[3][3]; } }
     //      Subtraction: { a - b, a -= b, -a }
-    impl Sub<Vector2> for Vector2 { type Output = Self; fn sub(self, v: Self) -> Self { 
+    impl<T: Scalar> Sub<Vector2<T>> for Vector2<T> { type Output = Self; fn sub(self, v: Self) -> Self { 
         return Self::new(self.x - v.x, self.y - v.y); } }
-    impl Sub<Complex> for Complex { type Output = Self; fn sub(self, c: Self) -> Self { 
+    impl<T: Scalar> Sub<Complex<T>> for Complex<T> { type Output = Self; fn sub(self, c: Self) -> Self { 
         return Self::new(self.r - c.r, self.i - c.i); } }
-    impl Sub<Dual> for Dual { type Output = Self; fn sub(self, d: Self) -> Self { 
+    impl<T: Scalar> Sub<Dual<T>> for Dual<T> { type Output = Self; fn sub(self, d: Self) -> Self { 
         return Self::new(self.r - d.r, self.e - d.e); } }
-    impl Sub<Vector3> for Vector3 { type Output = Self; fn sub(self, v: Self) -> Self { 
+    impl<T: Scalar> Sub<Vector3<T>> for Vector3<T> { type Output = Self; fn sub(self, v: Self) -> Self { 
         return Self::new(self.x - v.x, self.y - v.y, self.z - v.z); } }
-    impl Sub<Vector4> for Vector4 { type Output = Self; fn sub(self, v: Self) -> Self { 
+    impl<T: Scalar> Sub<Vector4<T>> for Vector4<T> { type Output = Self; fn sub(self, v: Self) -> Self { 
         return Self::new(self.x - v.x, self.y - v.y, self.z - v.z, self.w - v.w); } }
-    impl Sub<Matrix2> for Matrix2 { type Output = Self; fn sub(self, m: Self) -> Self {
+    impl<T: Scalar> Sub<Quaternion<T>> for Quaternion<T> { type Output = Self; fn sub(self, q: Self) -> Self { 
+        return Self::new(self.s - q.s, self.i - q.i, self.j - q.j, self.k - q.k); } }
+    impl<T: Scalar> Sub<QuaternionDual<T>> for QuaternionDual<T> { type Output = Self; fn sub(self, dq: Self) -> Self { 
+        return Self::new(self.r - dq.r, self.e - dq.e); } }
+    impl<T: Scalar> Sub<Matrix2<T>> for Matrix2<T> { type Output = Self; fn sub(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] - m.e[0][0], self.e[0][1] - m.e[0][1],
-            self.e[1][0] - m.e[1][0], self.e[1][1] - m.e[1][1]); } }
-    impl Sub<Matrix3> for Matrix3 { type Output = Self; fn sub(self, m: Self) -> Self {
+            self.e[0][0] - m.e[0][0], self.e[1][0] - m.e[1][0],
+            self.e[0][1] - m.e[0][1], self.e[1][1] - m.e[1][1]); } }
+    impl<T: Scalar> Sub<Matrix3<T>> for Matrix3<T> { type Output = Self; fn sub(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] - m.e[0][0], self.e[0][1] - m.e[0][1], self.e[0][2] - m.e[0][2],
-            self.e[1][0] - m.e[1][0], self.e[1][1] - m.e[1][1], self.e[1][2] - m.e[1][2],
-            self.e[2][0] - m.e[2][0], self.e[2][1] - m.e[2][1], self.e[2][2] - m.e[2][2]); } }
-    impl Sub<Matrix4> for Matrix4 { type Output = Self; fn sub(self, m: Self) -> Self {
+            self.e[0][0] - m.e[0][0], self.e[1][0] - m.e[1][0], self.e[2][0] - m.e[2][0],
+            self.e[0][1] - m.e[0][1], self.e[1][1] - m.e[1][1], self.e[2][1] - m.e[2][1],
+            self.e[0][2] - m.e[0][2], self.e[1][2] - m.e[1][2], self.e[2][2] - m.e[2][2]); } }
+    impl<T: Scalar> Sub<Matrix4<T>> for Matrix4<T> { type Output = Self; fn sub(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] - m.e[0][0], self.e[0][1] - m.e[0][1], self.e[0][2] - m.e[0][2], self.e[0][3] - m.e[0][3],
-            self.e[1][0] - m.e[1][0], self.e[1][1] - m.e[1][1], self.e[1][2] - m.e[1][2], self.e[1][3] - m.e[1][3],
-            self.e[2][0] - m.e[2][0], self.e[2][1] - m.e[2][1], self.e[2][2] - m.e[2][2], self.e[2][3] - m.e[2][3],
-            self.e[3][0] - m.e[3][0], self.e[3][1] - m.e[3][1], self.e[3][2] - m.e[3][2], self.e[3][3] - m.e[3][3]); } }
-    impl SubAssign<Vector2> for Vector2 { fn sub_assign(&mut self, v: Self) { 
+            self.e[0][0] - m.e[0][0], self.e[1][0] - m.e[1][0], self.e[2][0] - m.e[2][0], self.e[3][0] - m.e[3][0],
+            self.e[0][1] - m.e[0][1], self.e[1][1] - m.e[1][1], self.e[2][1] - m.e[2][1], self.e[3][1] - m.e[3][1],
+            self.e[0][2] - m.e[0][2], self.e[1][2] - m.e[1][2], self.e[2][2] - m.e[2][2], self.e[3][2] - m.e[3][2],
+            self.e[0][3] - m.e[0][3], self.e[1][3] - m.e[1][3], self.e[2][3] - m.e[2][3], self.e[3][3] - m.e[3][3]); } }
+    impl<T: Scalar> SubAssign<Vector2<T>> for Vector2<T> { fn sub_assign(&mut self, v: Self) { 
         self.x -= v.x; self.y -= v.y; } }
-    impl SubAssign<Complex> for Complex { fn sub_assign(&mut self, c: Self) { 
+    impl<T: Scalar> SubAssign<Complex<T>> for Complex<T> { fn sub_assign(&mut self, c: Self) { 
         self.r -= c.r; self.i -= c.i; } }
-    impl SubAssign<Dual> for Dual { fn sub_assign(&mut self, d: Self) { 
+    impl<T: Scalar> SubAssign<Dual<T>> for Dual<T> { fn sub_assign(&mut self, d: Self) { 
         self.r -= d.r; self.e -= d.e; } }
-    impl SubAssign<Vector3> for Vector3 { fn sub_assign(&mut self, v: Self) { 
+    impl<T: Scalar> SubAssign<Vector3<T>> for Vector3<T> { fn sub_assign(&mut self, v: Self) { 
         self.x -= v.x; self.y -= v.y; self.z -= v.z; } }
-    impl SubAssign<Vector4> for Vector4 { fn sub_assign(&mut self, v: Self) { 
+    impl<T: Scalar> SubAssign<Vector4<T>> for Vector4<T> { fn sub_assign(&mut self, v: Self) { 
         self.x -= v.x; self.y -= v.y; self.z -= v.z; self.w -= v.w; } }
-    impl SubAssign<Matrix2> for Matrix2 { fn sub_assign(&mut self, m: Self) { 
+    impl<T: Scalar> SubAssign<Quaternion<T>> for Quaternion<T> { fn sub_assign(&mut self, q: Self) { 
+        self.s -= q.s; self.i -= q.i; self.j -= q.j; self.k -= q.k; } }
+    impl<T: Scalar> SubAssign<QuaternionDual<T>> for QuaternionDual<T> { fn sub_assign(&mut self, dq: Self) { 
+        self.r -= dq.r; self.e -= dq.e; } }
+    impl<T: Scalar> SubAssign<Matrix2<T>> for Matrix2<T> { fn sub_assign(&mut self, m: Self) { 
         self.e[0][0] -= m.e[0][0];  self.e[0][1] -= m.e[0][1];
         self.e[1][0] -= m.e[1][0];  self.e[1][1] -= m.e[1][1]; } }
-    impl SubAssign<Matrix3> for Matrix3 { fn sub_assign(&mut self, m: Self) { 
+    impl<T: Scalar> SubAssign<Matrix3<T>> for Matrix3<T> { fn sub_assign(&mut self, m: Self) { 
         self.e[0][0] -= m.e[0][0];  self.e[0][1] -= m.e[0][1];  self.e[0][2] -= m.e[0][2];
         self.e[1][0] -= m.e[1][0];  self.e[1][1] -= m.e[1][1];  self.e[1][2] -= m.e[1][2];
         self.e[2][0] -= m.e[2][0];  self.e[2][1] -= m.e[2][1];  self.e[2][2] -= m.e[2][2]; } }
-    impl SubAssign<Matrix4> for Matrix4 { fn sub_assign(&mut self, m: Self) { 
+    impl<T: Scalar> SubAssign<Matrix4<T>> for Matrix4<T> { fn sub_assign(&mut self, m: Self) { 
         self.e[0][0] -= m.e[0][0];  self.e[0][1] -= m.e[0][1];  self.e[0][2] -= m.e[0][2];  self.e[0][3] -= m.e[0][3];
         self.e[1][0] -= m.e[1][0];  self.e[1][1] -= m.e[1][1];  self.e[1][2] -= m.e[1][2];  self.e[1][3] -= m.e[1][3];
         self.e[2][0] -= m.e[2][0];  self.e[2][1] -= m.e[2][1];  self.e[2][2] -= m.e[2][2];  self.e[2][3] -= m.e[2][3];
         self.e[3][0] -= m.e[3][0];  self.e[3][1] -= m.e[3][1];  self.e[3][2] -= m.e[3][2];  self.e[3][3] -= m.e[3][3]; } }
-    impl Neg for Vector2 { type Output = Self; fn neg(self) -> Self { 
+    impl<T: Scalar> Neg for Vector2<T> { type Output = Self; fn neg(self) -> Self { 
         return Self::new(-self.x, -self.y); } }
-    impl Neg for Vector3 { type Output = Self; fn neg(self) -> Self { 
+    impl<T: Scalar> Neg for Vector3<T> { type Output = Self; fn neg(self) -> Self { 
         return Self::new(-self.x, -self.y, -self.z); } }
-    impl Neg for Vector4 { type Output = Self; fn neg(self) -> Self { 
+    impl<T: Scalar> Neg for Vector4<T> { type Output = Self; fn neg(self) -> Self { 
         return Self::new(-self.x, -self.y, -self.z, -self.w); } }
     //      Special unary operators (conjugate, transpose, inverse, etc.)
     //          Conjugate (-(a + bi) = (a - bi))
-    impl Neg for Complex { type Output = Self; fn neg(self) -> Self { 
+    impl<T: Scalar> Neg for Complex<T> { type Output = Self; fn neg(self) -> Self { 
         return Self::new(self.r, -self.i); } }
-    impl Neg for Dual { type Output = Self; fn neg(self) -> Self { 
-        return Self::new(self.r, -self.e); } }
+    impl<T: Scalar> Neg for Quaternion<T> { type Output = Self; fn neg(self) -> Self { 
+        return self.conjugate(); } }
+    impl<T: Scalar> Neg for QuaternionDual<T> { type Output = Self; fn neg(self) -> Self { 
+        return self.conjugate(); } }
+    //          Negation (duals are differentiated through, so -(a + bε) = (-a - bε), and the conjugate is conjugate())
+    impl<T: Scalar> Neg for Dual<T> { type Output = Self; fn neg(self) -> Self { 
+        return Self::new(-self.r, -self.e); } }
     //          Transpose
-    impl Neg for Matrix2 { type Output = Self; fn neg(self) -> Self {
+    impl<T: Scalar> Neg for Matrix2<T> { type Output = Self; fn neg(self) -> Self {
         return self.transpose(); } }
-    impl Neg for Matrix3 { type Output = Self; fn neg(self) -> Self {
+    impl<T: Scalar> Neg for Matrix3<T> { type Output = Self; fn neg(self) -> Self {
         return self.transpose(); } }
-    impl Neg for Matrix4 { type Output = Self; fn neg(self) -> Self {
+    impl<T: Scalar> Neg for Matrix4<T> { type Output = Self; fn neg(self) -> Self {
         return self.transpose(); } }
     //          Inverse
-    impl Not for Complex { type Output = Self; fn not(self) -> Self {
-        let d = 1.0 / self.magnitude_sqr();
-        return (-self) * d; } }
-    impl Not for Matrix2 { type Output = Self; fn not(self) -> Self {
-        if self.determinant() == 0.0 {
+    //          1 / (a + bi) = (a - bi) / (a^2 + b^2), the reverse rotation for a unit complex number
+    impl<T: Scalar> Not for Complex<T> { type Output = Self; fn not(self) -> Self {
+        return self.inverse(); } }
+    impl<T: Scalar> Not for Quaternion<T> { type Output = Self; fn not(self) -> Self {
+        return self.inverse(); } }
+    impl<T: Scalar> Not for QuaternionDual<T> { type Output = Self; fn not(self) -> Self {
+        return self.inverse(); } }
+    impl<T: Scalar> Not for Matrix2<T> { type Output = Self; fn not(self) -> Self {
+        if self.determinant() == T::ZERO {
             return Self::zero();
         } else {
             return self.adjugate() / self.determinant(); } } }
-    impl Not for Matrix3 { type Output = Self; fn not(self) -> Self {
-        if self.determinant() == 0.0 {
+    impl<T: Scalar> Not for Matrix3<T> { type Output = Self; fn not(self) -> Self {
+        if self.determinant() == T::ZERO {
             return Self::zero();
         } else {
             return self.adjugate() / self.determinant(); } } }
-    impl Not for Matrix4 { type Output = Self; fn not(self) -> Self {
-        if self.determinant() == 0.0 {
+    impl<T: Scalar> Not for Matrix4<T> { type Output = Self; fn not(self) -> Self {
+        if self.determinant() == T::ZERO {
             return Self::zero();
         } else {
             return self.adjugate() / self.determinant(); } } }
     //      Scalar-Struct Multiplication: { a * s, a *= s, s * a }
-    impl Mul<f32> for Vector2 { type Output = Self; fn mul(self, s: f32) -> Self { 
+    impl<T: Scalar> Mul<T> for Vector2<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(self.x * s, self.y * s); } }
-    impl Mul<f32> for Complex { type Output = Self; fn mul(self, s: f32) -> Self { 
+    impl<T: Scalar> Mul<T> for Complex<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(self.r * s, self.i * s); } }
-    impl Mul<f32> for Dual { type Output = Self; fn mul(self, s: f32) -> Self { 
+    impl<T: Scalar> Mul<T> for Dual<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(self.r * s, self.e * s); } }
-    impl Mul<f32> for Vector3 { type Output = Self; fn mul(self, s: f32) -> Self { 
+    impl<T: Scalar> Mul<T> for Vector3<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(self.x * s, self.y * s, self.z * s); } }
-    impl Mul<f32> for Vector4 { type Output = Self; fn mul(self, s: f32) -> Self { 
+    impl<T: Scalar> Mul<T> for Vector4<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(self.x * s, self.y * s, self.z * s, self.w * s); } }
-    impl Mul<f32> for Matrix2 { type Output = Self; fn mul(self, s: f32) -> Self { 
+    impl<T: Scalar> Mul<T> for Quaternion<T> { type Output = Self; fn mul(self, s: T) -> Self { 
+        return Self::new(self.s * s, self.i * s, self.j * s, self.k * s); } }
+    impl<T: Scalar> Mul<T> for QuaternionDual<T> { type Output = Self; fn mul(self, s: T) -> Self { 
+        return Self::new(self.r * s, self.e * s); } }
+    impl<T: Scalar> Mul<T> for Matrix2<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(
-            self.e[0][0] * s, self.e[0][1] * s, 
-            self.e[1][0] * s, self.e[1][1] * s); } }
-    impl Mul<f32> for Matrix3 { type Output = Self; fn mul(self, s: f32) -> Self { 
+            self.e[0][0] * s, self.e[1][0] * s, 
+            self.e[0][1] * s, self.e[1][1] * s); } }
+    impl<T: Scalar> Mul<T> for Matrix3<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(
-            self.e[0][0] * s, self.e[0][1] * s, self.e[0][2] * s,
-            self.e[1][0] * s, self.e[1][1] * s, self.e[1][2] * s,
-            self.e[2][0] * s, self.e[2][1] * s, self.e[2][2] * s); } }
-    impl Mul<f32> for Matrix4 { type Output = Self; fn mul(self, s: f32) -> Self { 
+            self.e[0][0] * s, self.e[1][0] * s, self.e[2][0] * s,
+            self.e[0][1] * s, self.e[1][1] * s, self.e[2][1] * s,
+            self.e[0][2] * s, self.e[1][2] * s, self.e[2][2] * s); } }
+    impl<T: Scalar> Mul<T> for Matrix4<T> { type Output = Self; fn mul(self, s: T) -> Self { 
         return Self::new(
-            self.e[0][0] * s, self.e[0][1] * s, self.e[0][2] * s, self.e[0][3] * s,
-            self.e[1][0] * s, self.e[1][1] * s, self.e[1][2] * s, self.e[1][3] * s,
-            self.e[2][0] * s, self.e[2][1] * s, self.e[2][2] * s, self.e[2][3] * s,
-            self.e[3][0] * s, self.e[3][1] * s, self.e[3][2] * s, self.e[3][3] * s); } }
-    impl MulAssign<f32> for Vector2 { fn mul_assign(&mut self, s: f32) { 
+            self.e[0][0] * s, self.e[1][0] * s, self.e[2][0] * s, self.e[3][0] * s,
+            self.e[0][1] * s, self.e[1][1] * s, self.e[2][1] * s, self.e[3][1] * s,
+            self.e[0][2] * s, self.e[1][2] * s, self.e[2][2] * s, self.e[3][2] * s,
+            self.e[0][3] * s, self.e[1][3] * s, self.e[2][3] * s, self.e[3][3] * s); } }
+    impl<T: Scalar> MulAssign<T> for Vector2<T> { fn mul_assign(&mut self, s: T) { 
         self.x *= s; self.y *= s; } }
-    impl MulAssign<f32> for Complex { fn mul_assign(&mut self, s: f32) { 
+    impl<T: Scalar> MulAssign<T> for Complex<T> { fn mul_assign(&mut self, s: T) { 
         self.r *= s; self.i *= s; } }
-    impl MulAssign<f32> for Dual { fn mul_assign(&mut self, s: f32) { 
+    impl<T: Scalar> MulAssign<T> for Dual<T> { fn mul_assign(&mut self, s: T) { 
         self.r *= s; self.e *= s; } }
-    impl MulAssign<f32> for Vector3 { fn mul_assign(&mut self, s: f32) { 
+    impl<T: Scalar> MulAssign<T> for Vector3<T> { fn mul_assign(&mut self, s: T) { 
         self.x *= s; self.y *= s; self.z *= s; } }
-    impl MulAssign<f32> for Vector4 { fn mul_assign(&mut self, s: f32) { 
+    impl<T: Scalar> MulAssign<T> for Vector4<T> { fn mul_assign(&mut self, s: T) { 
         self.x *= s; self.y *= s; self.z *= s; self.w *= s; } }
-    impl MulAssign<f32> for Matrix2 { fn mul_assign(&mut self, s: f32) { 
+    impl<T: Scalar> MulAssign<T> for Quaternion<T> { fn mul_assign(&mut self, s: T) { 
+        self.s *= s; self.i *= s; self.j *= s; self.k *= s; } }
+    impl<T: Scalar> MulAssign<T> for QuaternionDual<T> { fn mul_assign(&mut self, s: T) { 
+        self.r *= s; self.e *= s; } }
+    impl<T: Scalar> MulAssign<T> for Matrix2<T> { fn mul_assign(&mut self, s: T) { 
         self.e[0][0] *= s; self.e[0][1] *= s; 
-        self.e[0][1] *= s; self.e[1][1] *= s; } }
-    impl MulAssign<f32> for Matrix3 { fn mul_assign(&mut self, s: f32) { 
+        self.e[1][0] *= s; self.e[1][1] *= s; } }
+    impl<T: Scalar> MulAssign<T> for Matrix3<T> { fn mul_assign(&mut self, s: T) { 
         self.e[0][0] *= s; self.e[0][1] *= s; self.e[0][2] *= s;
         self.e[1][0] *= s; self.e[1][1] *= s; self.e[1][2] *= s;
         self.e[2][0] *= s; self.e[2][1] *= s; self.e[2][2] *= s; } }
-    impl MulAssign<f32> for Matrix4 { fn mul_assign(&mut self, s: f32) { 
+    impl<T: Scalar> MulAssign<T> for Matrix4<T> { fn mul_assign(&mut self, s: T) { 
         self.e[0][0] *= s; self.e[0][1] *= s; self.e[0][2] *= s; self.e[0][3] *= s;
         self.e[1][0] *= s; self.e[1][1] *= s; self.e[1][2] *= s; self.e[1][3] *= s;
         self.e[2][0] *= s; self.e[2][1] *= s; self.e[2][2] *= s; self.e[2][3] *= s;
         self.e[3][0] *= s; self.e[3][1] *= s; self.e[3][2] *= s; self.e[3][3] *= s;} }
-    impl Mul<Vector2> for f32 { type Output = Vector2; fn mul(self, v: Vector2) -> Vector2 {
-        return Vector2::new(v.x * self, v.y * self); } }
-    impl Mul<Complex> for f32 { type Output = Complex; fn mul(self, c: Complex) -> Complex {
-        return Complex::new(c.r * self, c.i * self); } }
-    impl Mul<Dual> for f32 { type Output = Dual; fn mul(self, d: Dual) -> Dual {
-        return Dual::new(d.r * self, d.e * self); } }
-    impl Mul<Vector3> for f32 { type Output = Vector3; fn mul(self, v: Vector3) -> Vector3 {
-        return Vector3::new(v.x * self, v.y * self, v.z * self); } }
-    impl Mul<Vector4> for f32 { type Output = Vector4; fn mul(self, v: Vector4) -> Vector4 {
-        return Vector4::new(v.x * self, v.y * self, v.z * self, v.w * self); } }
-    impl Mul<Matrix2> for f32 { type Output = Matrix2; fn mul(self, m: Matrix2) -> Matrix2 {
-        return Matrix2::new(
-            m.e[0][0] * self, m.e[0][1] * self, 
-            m.e[1][0] * self, m.e[1][1] * self); } }
-    impl Mul<Matrix3> for f32 { type Output = Matrix3; fn mul(self, m: Matrix3) -> Matrix3 {
-        return Matrix3::new(
-            m.e[0][0] * self, m.e[0][1] * self, m.e[0][2] * self,
-            m.e[1][0] * self, m.e[1][1] * self, m.e[1][2] * self,
-            m.e[2][0] * self, m.e[2][1] * self, m.e[2][2] * self); } }
-    impl Mul<Matrix4> for f32 { type Output = Matrix4; fn mul(self, m: Matrix4) -> Matrix4 {
-        return Matrix4::new(
-            m.e[0][0] * self, m.e[0][1] * self, m.e[0][2] * self, m.e[0][3] * self,
-            m.e[1][0] * self, m.e[1][1] * self, m.e[1][2] * self, m.e[1][3] * self,
-            m.e[2][0] * self, m.e[2][1] * self, m.e[2][2] * self, m.e[2][3] * self,
-            m.e[3][0] * self, m.e[3][1] * self, m.e[3][2] * self, m.e[3][3] * self); } }
+    //      (The scalar on the left is a foreign type, so these are implemented per concrete scalar type)
+    macro_rules! impl_scalar_struct_mul { ($($S:ty),*) => { $(
+        impl Mul<Vector2<$S>> for $S { type Output = Vector2<$S>; fn mul(self, v: Vector2<$S>) -> Vector2<$S> {
+            return Vector2::new(v.x * self, v.y * self); } }
+        impl Mul<Complex<$S>> for $S { type Output = Complex<$S>; fn mul(self, c: Complex<$S>) -> Complex<$S> {
+            return Complex::new(c.r * self, c.i * self); } }
+        impl Mul<Dual<$S>> for $S { type Output = Dual<$S>; fn mul(self, d: Dual<$S>) -> Dual<$S> {
+            return Dual::new(d.r * self, d.e * self); } }
+        impl Mul<Vector3<$S>> for $S { type Output = Vector3<$S>; fn mul(self, v: Vector3<$S>) -> Vector3<$S> {
+            return Vector3::new(v.x * self, v.y * self, v.z * self); } }
+        impl Mul<Vector4<$S>> for $S { type Output = Vector4<$S>; fn mul(self, v: Vector4<$S>) -> Vector4<$S> {
+            return Vector4::new(v.x * self, v.y * self, v.z * self, v.w * self); } }
+        impl Mul<Quaternion<$S>> for $S { type Output = Quaternion<$S>; fn mul(self, q: Quaternion<$S>) -> Quaternion<$S> {
+            return Quaternion::new(q.s * self, q.i * self, q.j * self, q.k * self); } }
+        impl Mul<QuaternionDual<$S>> for $S { type Output = QuaternionDual<$S>; fn mul(self, dq: QuaternionDual<$S>) -> QuaternionDual<$S> {
+            return QuaternionDual::new(dq.r * self, dq.e * self); } }
+        impl Mul<Matrix2<$S>> for $S { type Output = Matrix2<$S>; fn mul(self, m: Matrix2<$S>) -> Matrix2<$S> {
+            return Matrix2::new(
+                m.e[0][0] * self, m.e[1][0] * self, 
+                m.e[0][1] * self, m.e[1][1] * self); } }
+        impl Mul<Matrix3<$S>> for $S { type Output = Matrix3<$S>; fn mul(self, m: Matrix3<$S>) -> Matrix3<$S> {
+            return Matrix3::new(
+                m.e[0][0] * self, m.e[1][0] * self, m.e[2][0] * self,
+                m.e[0][1] * self, m.e[1][1] * self, m.e[2][1] * self,
+                m.e[0][2] * self, m.e[1][2] * self, m.e[2][2] * self); } }
+        impl Mul<Matrix4<$S>> for $S { type Output = Matrix4<$S>; fn mul(self, m: Matrix4<$S>) -> Matrix4<$S> {
+            return Matrix4::new(
+                m.e[0][0] * self, m.e[1][0] * self, m.e[2][0] * self, m.e[3][0] * self,
+                m.e[0][1] * self, m.e[1][1] * self, m.e[2][1] * self, m.e[3][1] * self,
+                m.e[0][2] * self, m.e[1][2] * self, m.e[2][2] * self, m.e[3][2] * self,
+                m.e[0][3] * self, m.e[1][3] * self, m.e[2][3] * self, m.e[3][3] * self); } }
+    )* } }
+    impl_scalar_struct_mul!(f32, f64);
     
     /*
         Struct-Struct Multiplication: { 
@@ -1166,95 +1877,104 @@ pub mod linalg {
             complex multiplication, dual multiplication,
             matrix multiplication }
     */
-    impl Mul<Vector2> for Vector2 { type Output = f32; fn mul(self, v: Self) -> f32 {
+    impl<T: Scalar> Mul<Vector2<T>> for Vector2<T> { type Output = T; fn mul(self, v: Self) -> T {
         return self.x * v.x + self.y * v.y; } }
-    impl Mul<Complex> for Complex { type Output = Self; fn mul(self, c: Self) -> Self {
-        return Self::new(self.r * self.i - c.r * c.i, self.r * c.i + c.r * self.i); } }
-    impl Mul<Dual> for Dual { type Output = Self; fn mul(self, d: Self) -> Self {
-        return Self::new(self.r * self.e, self.r * d.e + d.r * self.e); } }
-    impl Mul<Vector3> for Vector3 { type Output = f32; fn mul(self, v: Self) -> f32 {
+    //      (a + bi)(c + di) = (ac - bd) + (ad + bc)i   (rotates and scales: the angles add, the magnitudes multiply)
+    impl<T: Scalar> Mul<Complex<T>> for Complex<T> { type Output = Self; fn mul(self, c: Self) -> Self {
+        return Self::new(self.r * c.r - self.i * c.i, self.r * c.i + self.i * c.r); } }
+    impl<T: Scalar> MulAssign<Complex<T>> for Complex<T> { fn mul_assign(&mut self, c: Self) { *self = (*self) * c; } }
+    //      (a + bi) / (c + di) = (a + bi)(c - di) / (c^2 + d^2)
+    impl<T: Scalar> Div<Complex<T>> for Complex<T> { type Output = Self; fn div(self, c: Self) -> Self {
+        let d = T::ONE / c.magnitude_sqr();
+        return Self::new((self.r * c.r + self.i * c.i) * d, (self.i * c.r - self.r * c.i) * d); } }
+    impl<T: Scalar> DivAssign<Complex<T>> for Complex<T> { fn div_assign(&mut self, c: Self) { *self = (*self) / c; } }
+    impl<T: Scalar> Mul<Dual<T>> for Dual<T> { type Output = Self; fn mul(self, d: Self) -> Self {
+        return Self::new(self.r * d.r, self.r * d.e + d.r * self.e); } }
+    //      (a + bε) / (c + dε) = a / c + ((bc - ad) / c^2)ε
+    impl<T: Scalar> Div<Dual<T>> for Dual<T> { type Output = Self; fn div(self, d: Self) -> Self {
+        return Self::new(self.r / d.r, (self.e * d.r - self.r * d.e) / (d.r * d.r)); } }
+    impl<T: Scalar> Mul<Vector3<T>> for Vector3<T> { type Output = T; fn mul(self, v: Self) -> T {
         return self.x * v.x + self.y * v.y + self.z * v.z; } }
-    impl Mul<Vector4> for Vector4 { type Output = f32; fn mul(self, v: Self) -> f32 {
+    impl<T: Scalar> Mul<Vector4<T>> for Vector4<T> { type Output = T; fn mul(self, v: Self) -> T {
         return self.x * v.x + self.y * v.y + self.z * v.z + self.w * v.w; } }
-    impl Mul<Matrix2> for Matrix2 { type Output = Self; fn mul(self, m: Self) -> Self {
+    //      Hamilton product
+    impl<T: Scalar> Mul<Quaternion<T>> for Quaternion<T> { type Output = Self; fn mul(self, q: Self) -> Self {
         return Self::new(
-            self.e[0][0] * m.e[0][0] + self.e[0][1] * m.e[1][0],
-            self.e[0][0] * m.e[0][1] + self.e[0][1] * m.e[1][1],
+            self.s * q.s - self.i * q.i - self.j * q.j - self.k * q.k,
+            self.s * q.i + self.i * q.s + self.j * q.k - self.k * q.j,
+            self.s * q.j - self.i * q.k + self.j * q.s + self.k * q.i,
+            self.s * q.k + self.i * q.j - self.j * q.i + self.k * q.s); } }
+    //      Composition (a * b applies b first, then a)
+    impl<T: Scalar> Mul<QuaternionDual<T>> for QuaternionDual<T> { type Output = Self; fn mul(self, dq: Self) -> Self {
+        return Self::new(self.r * dq.r, self.r * dq.e + self.e * dq.r); } }
+    impl<T: Scalar> Mul<Matrix2<T>> for Matrix2<T> { type Output = Self; fn mul(self, m: Self) -> Self {
+        return Self::new(
+            self.e[0][0] * m.e[0][0] + self.e[1][0] * m.e[0][1],
+            self.e[0][0] * m.e[1][0] + self.e[1][0] * m.e[1][1],
 
-            self.e[1][0] * m.e[0][0] + self.e[1][1] * m.e[1][0],
-            self.e[1][0] * m.e[0][1] + self.e[1][1] * m.e[1][1]); } }
-    impl Mul<Matrix3> for Matrix3 { type Output = Self; fn mul(self, m: Self) -> Self {
+            self.e[0][1] * m.e[0][0] + self.e[1][1] * m.e[0][1],
+            self.e[0][1] * m.e[1][0] + self.e[1][1] * m.e[1][1]); } }
+    impl<T: Scalar> Mul<Matrix3<T>> for Matrix3<T> { type Output = Self; fn mul(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] * m.e[0][0] + self.e[0][1] * m.e[1][0] + self.e[0][2] * m.e[2][0],
-            self.e[0][0] * m.e[0][1] + self.e[0][1] * m.e[1][1] + self.e[0][2] * m.e[2][1],
-            self.e[0][0] * m.e[0][2] + self.e[0][1] * m.e[1][2] + self.e[0][2] * m.e[2][2],
-
-            self.e[1][0] * m.e[0][0] + self.e[1][1] * m.e[1][0] + self.e[1][2] * m.e[2][0],
-            self.e[1][0] * m.e[0][1] + self.e[1][1] * m.e[1][1] + self.e[1][2] * m.e[2][1],
-            self.e[1][0] * m.e[0][2] + self.e[1][1] * m.e[1][2] + self.e[1][2] * m.e[2][2],
-
-            self.e[2][0] * m.e[0][0] + self.e[2][1] * m.e[1][0] + self.e[2][2] * m.e[2][0],
-            self.e[2][0] * m.e[0][1] + self.e[2][1] * m.e[1][1] + self.e[2][2] * m.e[2][1],
-            self.e[2][0] * m.e[0][2] + self.e[2][1] * m.e[1][2] + self.e[2][2] * m.e[2][2]); } }
-    impl Mul<Matrix4> for Matrix4 { type Output = Self; fn mul(self, m: Self) -> Self {
+            self.e[0][0] * m.e[0][0] + self.e[1][0] * m.e[0][1] + self.e[2][0] * m.e[0][2],
+            self.e[0][0] * m.e[1][0] + self.e[1][0] * m.e[1][1] + self.e[2][0] * m.e[1][2],
+            self.e[0][0] * m.e[2][0] + self.e[1][0] * m.e[2][1] + self.e[2][0] * m.e[2][2],
+
+            self.e[0][1] * m.e[0][0] + self.e[1][1] * m.e[0][1] + self.e[2][1] * m.e[0][2],
+            self.e[0][1] * m.e[1][0] + self.e[1][1] * m.e[1][1] + self.e[2][1] * m.e[1][2],
+            self.e[0][1] * m.e[2][0] + self.e[1][1] * m.e[2][1] + self.e[2][1] * m.e[2][2],
+
+            self.e[0][2] * m.e[0][0] + self.e[1][2] * m.e[0][1] + self.e[2][2] * m.e[0][2],
+            self.e[0][2] * m.e[1][0] + self.e[1][2] * m.e[1][1] + self.e[2][2] * m.e[1][2],
+            self.e[0][2] * m.e[2][0] + self.e[1][2] * m.e[2][1] + self.e[2][2] * m.e[2][2]); } }
+    impl<T: Scalar> Mul<Matrix4<T>> for Matrix4<T> { type Output = Self; fn mul(self, m: Self) -> Self {
         return Self::new(
-            self.e[0][0] * m.e[0][0] + self.e[0][1] * m.e[1][0] + self.e[0][2] * m.e[2][0] + self.e[0][3] * m.e[3][0],
-            self.e[0][0] * m.e[0][1] + self.e[0][1] * m.e[1][1] + self.e[0][2] * m.e[2][1] + self.e[0][3] * m.e[3][1],
-            self.e[0][0] * m.e[0][2] + self.e[0][1] * m.e[1][2] + self.e[0][2] * m.e[2][2] + self.e[0][3] * m.e[3][2],
-            self.e[0][0] * m.e[0][3] + self.e[0][1] * m.e[1][3] + self.e[0][2] * m.e[2][3] + self.e[0][3] * m.e[3][3],
-
-            self.e[1][0] * m.e[0][0] + self.e[1][1] * m.e[1][0] + self.e[1][2] * m.e[2][0] + self.e[1][3] * m.e[3][0],
-            self.e[1][0] * m.e[0][1] + self.e[1][1] * m.e[1][1] + self.e[1][2] * m.e[2][1] + self.e[1][3] * m.e[3][1],
-            self.e[1][0] * m.e[0][2] + self.e[1][1] * m.e[1][2] + self.e[1][2] * m.e[2][2] + self.e[1][3] * m.e[3][2],
-            self.e[1][0] * m.e[0][3] + self.e[1][1] * m.e[1][3] + self.e[1][2] * m.e[2][3] + self.e[1][3] * m.e[3][3],
-
-            self.e[2][0] * m.e[0][0] + self.e[2][1] * m.e[1][0] + self.e[2][2] * m.e[2][0] + self.e[2][3] * m.e[3][0],
-            self.e[2][0] * m.e[0][1] + self.e[2][1] * m.e[1][1] + self.e[2][2] * m.e[2][1] + self.e[2][3] * m.e[3][1],
-            self.e[2][0] * m.e[0][2] + self.e[2][1] * m.e[1][2] + self.e[2][2] * m.e[2][2] + self.e[2][3] * m.e[3][2],
-            self.e[2][0] * m.e[0][3] + self.e[2][1] * m.e[1][3] + self.e[2][2] * m.e[2][3] + self.e[2][3] * m.e[3][3],
-
-            self.e[3][0] * m.e[0][0] + self.e[3][1] * m.e[1][0] + self.e[3][2] * m.e[2][0] + self.e[3][3] * m.e[3][0],
-            self.e[3][0] * m.e[0][1] + self.e[3][1] * m.e[1][1] + self.e[3][2] * m.e[2][1] + self.e[3][3] * m.e[3][1],
-            self.e[3][0] * m.e[0][2] + self.e[3][1] * m.e[1][2] + self.e[3][2] * m.e[2][2] + self.e[3][3] * m.e[3][2],
-            self.e[3][0] * m.e[0][3] + self.e[3][1] * m.e[1][3] + self.e[3][2] * m.e[2][3] + self.e[3][3] * m.e[3][3]); } }
-    impl Vector2 { fn product_scalar(a: &Self, b: &Self) -> f32 { 
-        return &a.x * &b.x + &a.y * &b.y; } }
-    impl Vector3 { fn product_scalar(a: &Self, b: &Self) -> f32 { 
-        return &a.x * &b.x + &a.y * &b.y + &a.z * &b.z; } }
-    impl Vector4 { fn product_scalar(a: &Self, b: &Self) -> f32 { 
-        return &a.x * &b.x + &a.y * &b.y + &a.z * &b.z + &a.w * &b.w; } }
-    impl Div<Vector2> for Vector2 { type Output = f32; fn div(self, v: Self) -> f32 {
+            self.e[0][0] * m.e[0][0] + self.e[1][0] * m.e[0][1] + self.e[2][0] * m.e[0][2] + self.e[3][0] * m.e[0][3],
+            self.e[0][0] * m.e[1][0] + self.e[1][0] * m.e[1][1] + self.e[2][0] * m.e[1][2] + self.e[3][0] * m.e[1][3],
+            self.e[0][0] * m.e[2][0] + self.e[1][0] * m.e[2][1] + self.e[2][0] * m.e[2][2] + self.e[3][0] * m.e[2][3],
+            self.e[0][0] * m.e[3][0] + self.e[1][0] * m.e[3][1] + self.e[2][0] * m.e[3][2] + self.e[3][0] * m.e[3][3],
+
+            self.e[0][1] * m.e[0][0] + self.e[1][1] * m.e[0][1] + self.e[2][1] * m.e[0][2] + self.e[3][1] * m.e[0][3],
+            self.e[0][1] * m.e[1][0] + self.e[1][1] * m.e[1][1] + self.e[2][1] * m.e[1][2] + self.e[3][1] * m.e[1][3],
+            self.e[0][1] * m.e[2][0] + self.e[1][1] * m.e[2][1] + self.e[2][1] * m.e[2][2] + self.e[3][1] * m.e[2][3],
+            self.e[0][1] * m.e[3][0] + self.e[1][1] * m.e[3][1] + self.e[2][1] * m.e[3][2] + self.e[3][1] * m.e[3][3],
+
+            self.e[0][2] * m.e[0][0] + self.e[1][2] * m.e[0][1] + self.e[2][2] * m.e[0][2] + self.e[3][2] * m.e[0][3],
+            self.e[0][2] * m.e[1][0] + self.e[1][2] * m.e[1][1] + self.e[2][2] * m.e[1][2] + self.e[3][2] * m.e[1][3],
+            self.e[0][2] * m.e[2][0] + self.e[1][2] * m.e[2][1] + self.e[2][2] * m.e[2][2] + self.e[3][2] * m.e[2][3],
+            self.e[0][2] * m.e[3][0] + self.e[1][2] * m.e[3][1] + self.e[2][2] * m.e[3][2] + self.e[3][2] * m.e[3][3],
+
+            self.e[0][3] * m.e[0][0] + self.e[1][3] * m.e[0][1] + self.e[2][3] * m.e[0][2] + self.e[3][3] * m.e[0][3],
+            self.e[0][3] * m.e[1][0] + self.e[1][3] * m.e[1][1] + self.e[2][3] * m.e[1][2] + self.e[3][3] * m.e[1][3],
+            self.e[0][3] * m.e[2][0] + self.e[1][3] * m.e[2][1] + self.e[2][3] * m.e[2][2] + self.e[3][3] * m.e[2][3],
+            self.e[0][3] * m.e[3][0] + self.e[1][3] * m.e[3][1] + self.e[2][3] * m.e[3][2] + self.e[3][3] * m.e[3][3]); } }
+    impl<T: Scalar> Vector2<T> { fn product_scalar(a: &Self, b: &Self) -> T { 
+        return a.x * b.x + a.y * b.y; } }
+    impl<T: Scalar> Vector3<T> { fn product_scalar(a: &Self, b: &Self) -> T { 
+        return a.x * b.x + a.y * b.y + a.z * b.z; } }
+    impl<T: Scalar> Vector4<T> { fn product_scalar(a: &Self, b: &Self) -> T { 
+        return a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w; } }
+    impl<T: Scalar> Quaternion<T> { pub fn product_scalar(a: &Self, b: &Self) -> T { 
+        return a.s * b.s + a.i * b.i + a.j * b.j + a.k * b.k; } }
+    impl<T: Scalar> Div<Vector2<T>> for Vector2<T> { type Output = T; fn div(self, v: Self) -> T {
         return self.x * v.y - self.y * v.x; }}
-    impl Div<Vector3> for Vector3 { type Output = Self; fn div(self, v: Self) -> Self {
+    impl<T: Scalar> Div<Vector3<T>> for Vector3<T> { type Output = Self; fn div(self, v: Self) -> Self {
         return Self::new(
             self.y * v.z - v.y * self.z,
             v.x * self.z - self.x * v.z,
             self.x * v.y - v.x * self.y); } }
-    impl Vector2 { fn product_vector(a: &Self, b: &Self) -> f32 { 
+    impl<T: Scalar> Vector2<T> { fn product_vector(a: &Self, b: &Self) -> T { 
         return a.x * b.y - a.y * b.x; } }
-    impl Vector3 { fn product_vector(a: &Self, b: &Self) -> Self { 
+    impl<T: Scalar> Vector3<T> { fn product_vector(a: &Self, b: &Self) -> Self { 
         return Self::new(
             a.y * b.z - b.y * a.z,
             b.x * a.z - a.x * b.z,
             a.x * b.y - b.x * a.y); } }
-    impl BitXor<Vector2> for Vector2 { type Output = Vector2 /*Bivector2*/; fn bitxor(self, v: Vector2) -> Vector2 /*Bivector2*/ {
-        return Vector2::new(0.0, 0.0); /* This is just filler */ }}
-        // Also do Vector3 and Vector4
-    impl Vector2 {
-        // fn product_geometric(a: &Vector2, b: &Vector2) -> Bivector2 {
-        //     return ()
-        // }
-    }
-    impl Vector3 {
-        // geometric product
-    }
-    impl Vector4 {
-        // geometric product
-    }
-    impl Vector3 {
+    //      Wedge product (a ^ b) and geometric product (product_geometric) are implemented in linalg::ga
+    impl<T: Scalar> Vector3<T> {
         // http://mathworld.wolfram.com/ScalarTripleProduct.html
         // [a * (b / c)]
-        pub fn product_scalar_triple(a: Self, b: Self, c: Self) -> f32 {
+        pub fn product_scalar_triple(a: Self, b: Self, c: Self) -> T {
             return Vector3::product_scalar(&a, &Vector3::product_vector(&b, &c));
         }
         // http://mathworld.wolfram.com/VectorTripleProduct.html
@@ -1263,133 +1983,292 @@ pub mod linalg {
             return b * Vector3::product_scalar(&a, &c) - c * Vector3::product_scalar(&a, &b);
         }
     }
-    impl Vector4 {
+    impl<T: Scalar> Vector4<T> {
         // scalar triple / vector triple exists in R4?
     }
 
     //      Division
-    impl Div<f32> for Vector2 { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return Self::new(self.x, self.y) * t; } }
-    impl Div<f32> for Complex { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return Self::new(self.r, self.i) * t; } }
-    impl Div<f32> for Dual { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return Self::new(self.r, self.e) * t; } }
-    impl Div<f32> for Vector3 { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return Self::new(self.x, self.y, self.z) * t; } }
-    impl Div<f32> for Vector4 { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return self * t; } }
-    impl Div<f32> for Matrix2 { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return self * t; } }
-    impl Div<f32> for Matrix3 { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return self * t; } }
-    impl Div<f32> for Matrix4 { type Output = Self; fn div(self, s: f32) -> Self { 
-        let t = 1.0 / s; return self * t; } }
-    impl DivAssign<f32> for Vector2 { fn div_assign(&mut self, s: f32) { 
-        let t = 1.0 / s; self.x *= t; self.y *= t; } }
-    impl DivAssign<f32> for Complex { fn div_assign(&mut self, s: f32) { 
-        let t = 1.0 / s; self.r *= t; self.i *= t; } }
-    impl DivAssign<f32> for Dual { fn div_assign(&mut self, s: f32) { 
-        let t = 1.0 / s; self.r *= t; self.e *= t; } }
-    impl DivAssign<f32> for Vector3 { fn div_assign(&mut self, s: f32) { 
-        let t = 1.0 / s; self.x *= t; self.y *= t; self.z *= t; } }
-    impl DivAssign<f32> for Vector4 { fn div_assign(&mut self, s: f32) { 
-        let t = 1.0 / s; self.x *= t; self.y *= t; self.z *= t; self.w *= t; } }
+    impl<T: Scalar> Div<T> for Vector2<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return Self::new(self.x, self.y) * t; } }
+    impl<T: Scalar> Div<T> for Complex<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return Self::new(self.r, self.i) * t; } }
+    impl<T: Scalar> Div<T> for Dual<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return Self::new(self.r, self.e) * t; } }
+    impl<T: Scalar> Div<T> for Vector3<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return Self::new(self.x, self.y, self.z) * t; } }
+    impl<T: Scalar> Div<T> for Vector4<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return self * t; } }
+    impl<T: Scalar> Div<T> for Quaternion<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return self * t; } }
+    impl<T: Scalar> Div<T> for QuaternionDual<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return self * t; } }
+    impl<T: Scalar> Div<T> for Matrix2<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return self * t; } }
+    impl<T: Scalar> Div<T> for Matrix3<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return self * t; } }
+    impl<T: Scalar> Div<T> for Matrix4<T> { type Output = Self; fn div(self, s: T) -> Self { 
+        let t = T::ONE / s; return self * t; } }
+    impl<T: Scalar> DivAssign<T> for Vector2<T> { fn div_assign(&mut self, s: T) { 
+        let t = T::ONE / s; self.x *= t; self.y *= t; } }
+    impl<T: Scalar> DivAssign<T> for Complex<T> { fn div_assign(&mut self, s: T) { 
+        let t = T::ONE / s; self.r *= t; self.i *= t; } }
+    impl<T: Scalar> DivAssign<T> for Dual<T> { fn div_assign(&mut self, s: T) { 
+        let t = T::ONE / s; self.r *= t; self.e *= t; } }
+    impl<T: Scalar> DivAssign<T> for Vector3<T> { fn div_assign(&mut self, s: T) { 
+        let t = T::ONE / s; self.x *= t; self.y *= t; self.z *= t; } }
+    impl<T: Scalar> DivAssign<T> for Vector4<T> { fn div_assign(&mut self, s: T) { 
+        let t = T::ONE / s; self.x *= t; self.y *= t; self.z *= t; self.w *= t; } }
+    impl<T: Scalar> DivAssign<T> for Quaternion<T> { fn div_assign(&mut self, s: T) { 
+        let t = T::ONE / s; self.s *= t; self.i *= t; self.j *= t; self.k *= t; } }
+    impl<T: Scalar> DivAssign<T> for QuaternionDual<T> { fn div_assign(&mut self, s: T) { 
+        let t = T::ONE / s; self.r *= t; self.e *= t; } }
 
 
     // Inter-Struct Product: { Matrix-Vector }
+    //      M * v treats v as a column vector, v * M treats v as a row vector (v * M = transpose(M) * v).
+    //      Elements are read as e[column][row].
+    impl<T: Scalar> Mul<Vector2<T>> for Matrix2<T> { type Output = Vector2<T>; fn mul(self, v: Vector2<T>) -> Vector2<T> {
+        return Vector2::new(
+            self.e[0][0] * v.x + self.e[1][0] * v.y,
+            self.e[0][1] * v.x + self.e[1][1] * v.y); } }
+    impl<T: Scalar> Mul<Matrix2<T>> for Vector2<T> { type Output = Vector2<T>; fn mul(self, m: Matrix2<T>) -> Vector2<T> {
+        return Vector2::new(
+            self.x * m.e[0][0] + self.y * m.e[0][1],
+            self.x * m.e[1][0] + self.y * m.e[1][1]); } }
+    impl<T: Scalar> Mul<Vector3<T>> for Matrix3<T> { type Output = Vector3<T>; fn mul(self, v: Vector3<T>) -> Vector3<T> {
+        return Vector3::new(
+            self.e[0][0] * v.x + self.e[1][0] * v.y + self.e[2][0] * v.z,
+            self.e[0][1] * v.x + self.e[1][1] * v.y + self.e[2][1] * v.z,
+            self.e[0][2] * v.x + self.e[1][2] * v.y + self.e[2][2] * v.z); } }
+    impl<T: Scalar> Mul<Matrix3<T>> for Vector3<T> { type Output = Vector3<T>; fn mul(self, m: Matrix3<T>) -> Vector3<T> {
+        return Vector3::new(
+            self.x * m.e[0][0] + self.y * m.e[0][1] + self.z * m.e[0][2],
+            self.x * m.e[1][0] + self.y * m.e[1][1] + self.z * m.e[1][2],
+            self.x * m.e[2][0] + self.y * m.e[2][1] + self.z * m.e[2][2]); } }
+    impl<T: Scalar> Mul<Vector4<T>> for Matrix4<T> { type Output = Vector4<T>; fn mul(self, v: Vector4<T>) -> Vector4<T> {
+        return Vector4::new(
+            self.e[0][0] * v.x + self.e[1][0] * v.y + self.e[2][0] * v.z + self.e[3][0] * v.w,
+            self.e[0][1] * v.x + self.e[1][1] * v.y + self.e[2][1] * v.z + self.e[3][1] * v.w,
+            self.e[0][2] * v.x + self.e[1][2] * v.y + self.e[2][2] * v.z + self.e[3][2] * v.w,
+            self.e[0][3] * v.x + self.e[1][3] * v.y + self.e[2][3] * v.z + self.e[3][3] * v.w); } }
+    impl<T: Scalar> Mul<Matrix4<T>> for Vector4<T> { type Output = Vector4<T>; fn mul(self, m: Matrix4<T>) -> Vector4<T> {
+        return Vector4::new(
+            self.x * m.e[0][0] + self.y * m.e[0][1] + self.z * m.e[0][2] + self.w * m.e[0][3],
+            self.x * m.e[1][0] + self.y * m.e[1][1] + self.z * m.e[1][2] + self.w * m.e[1][3],
+            self.x * m.e[2][0] + self.y * m.e[2][1] + self.z * m.e[2][2] + self.w * m.e[2][3],
+            self.x * m.e[3][0] + self.y * m.e[3][1] + self.z * m.e[3][2] + self.w * m.e[3][3]); } }
+    //      Points are extended with w = 1 and divided by the resulting w (perspective divide, skipped when w = 0).
+    //      Directions are extended with w = 0, so they ignore translation and projection.
+    impl<T: Scalar> Matrix4<T> { pub fn transform_point(&self, p: &Vector3<T>) -> Vector3<T> {
+        let v = (*self) * Vector4::new(p.x, p.y, p.z, T::ONE);
+        if v.w == T::ZERO || v.w == T::ONE { return Vector3::new(v.x, v.y, v.z); }
+        return Vector3::new(v.x / v.w, v.y / v.w, v.z / v.w); } }
+    impl<T: Scalar> Matrix4<T> { pub fn transform_direction(&self, d: &Vector3<T>) -> Vector3<T> {
+        let v = (*self) * Vector4::new(d.x, d.y, d.z, T::ZERO);
+        return Vector3::new(v.x, v.y, v.z); } }
+    //      Keeps w (clip space), for when the caller needs it for clipping before the divide
+    impl<T: Scalar> Matrix4<T> { pub fn transform_point_homogeneous(&self, p: &Vector3<T>) -> Vector4<T> {
+        return (*self) * Vector4::new(p.x, p.y, p.z, T::ONE); } }
+    //      Rotates v by a unit quaternion (q * v * q^-1, expanded)
+    //      https://fgiesen.wordpress.com/2019/02/09/rotating-a-single-vector-using-a-quaternion/
+    impl<T: Scalar> Quaternion<T> { pub fn rotate(&self, v: &Vector3<T>) -> Vector3<T> {
+        let u = Vector3::new(self.i, self.j, self.k);
+        let t = (u / (*v)) * T::TWO;
+        return (*v) + t * self.s + u / t; } }
+    impl<T: Scalar> Mul<Vector3<T>> for Quaternion<T> { type Output = Vector3<T>; fn mul(self, v: Vector3<T>) -> Vector3<T> {
+        return self.rotate(&v); } }
+    //      Points are rotated and translated, directions are only rotated (expects a unit dual quaternion)
+    impl<T: Scalar> QuaternionDual<T> { pub fn transform_point(&self, v: &Vector3<T>) -> Vector3<T> {
+        return self.r.rotate(v) + self.to_translation(); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn transform_direction(&self, v: &Vector3<T>) -> Vector3<T> {
+        return self.r.rotate(v); } }
+    impl<T: Scalar> Mul<Vector3<T>> for QuaternionDual<T> { type Output = Vector3<T>; fn mul(self, v: Vector3<T>) -> Vector3<T> {
+        return self.transform_point(&v); } }
     // Geometry
     //      Magnitude
-    impl Vector2 { pub fn magnitude(&self)      -> f32 { return f32::sqrt((*self) * (*self)); } }
-    impl Complex { pub fn magnitude(&self)      -> f32 { return f32::sqrt(self.r * self.r + self.i * self.i); } }
-    impl Vector3 { pub fn magnitude(&self)      -> f32 { return f32::sqrt((*self) * (*self)); } }
-    impl Vector4 { pub fn magnitude(&self)      -> f32 { return f32::sqrt((*self) * (*self)); } }
-    impl Matrix2 { pub fn determinant(&self)    -> f32 { return 
+    impl<T: Scalar> Vector2<T> { pub fn magnitude(&self)      -> T { return T::sqrt((*self) * (*self)); } }
+    impl<T: Scalar> Complex<T> { pub fn magnitude(&self)      -> T { return T::sqrt(self.r * self.r + self.i * self.i); } }
+    impl<T: Scalar> Vector3<T> { pub fn magnitude(&self)      -> T { return T::sqrt((*self) * (*self)); } }
+    impl<T: Scalar> Vector4<T> { pub fn magnitude(&self)      -> T { return T::sqrt((*self) * (*self)); } }
+    impl<T: Scalar> Quaternion<T> { pub fn magnitude(&self)   -> T { return T::sqrt(self.magnitude_sqr()); } }
+    impl<T: Scalar> Matrix2<T> { pub fn determinant(&self)    -> T { return 
         (*self).e[0][0] 
             * (*self).minor(0, 0) 
         - (*self).e[1][0] 
-            * (*self).minor(1, 0); } }
-    impl Matrix2 { pub fn determinant2(&self)   -> f32 { 
+            * (*self).minor(0, 1); } }
+    impl<T: Scalar> Matrix2<T> { pub fn determinant2(&self)   -> T { 
         let dia = self.triangular_lower().diagonal();
         return dia.x * dia.y; } }
-    impl Matrix3 { pub fn determinant(&self)    -> f32 { return
+    impl<T: Scalar> Matrix3<T> { pub fn determinant(&self)    -> T { return
         (*self).e[0][0]
             * (*self).minor(0, 0).determinant()
-        - (*self).e[0][1]
+        - (*self).e[1][0]
             * (*self).minor(0, 1).determinant()
-        + (*self).e[0][2]
+        + (*self).e[2][0]
             * (*self).minor(0, 2).determinant(); } }
-    impl Matrix3 { pub fn determinant2(&self)   -> f32 {
+    impl<T: Scalar> Matrix3<T> { pub fn determinant2(&self)   -> T {
         let dia = self.triangular_lower().diagonal();
         return dia.x * dia.y * dia.z; } }
-    impl Matrix4 { pub fn determinant(&self)    -> f32 { return
+    impl<T: Scalar> Matrix4<T> { pub fn determinant(&self)    -> T { return
         (*self).e[0][0]
             * (*self).minor(0, 0).determinant()
-        - (*self).e[0][1]
+        - (*self).e[1][0]
             * (*self).minor(0, 1).determinant()
-        + (*self).e[0][2]
+        + (*self).e[2][0]
             * (*self).minor(0, 2).determinant()
-        - (*self).e[0][3]
+        - (*self).e[3][0]
             * (*self).minor(0, 3).determinant(); } }
-    impl Matrix4 { pub fn determinant2(&self)   -> f32 {
+    impl<T: Scalar> Matrix4<T> { pub fn determinant2(&self)   -> T {
         let dia = self.triangular_lower().diagonal();
         return dia.x * dia.y * dia.z * dia.w; } }
-    impl Vector2 { pub fn magnitude_sqr(&self)  -> f32 { return (*self) * (*self); } }
-    impl Complex { pub fn magnitude_sqr(&self)  -> f32 { return self.r * self.r + self.i * self.i; } }
-    impl Vector3 { pub fn magnitude_sqr(&self)  -> f32 { return (*self) * (*self); } }
-    impl Vector4 { pub fn magnitude_sqr(&self)  -> f32 { return (*self) * (*self); } }
-    impl Vector2 { pub fn normalization(&self)  -> Self { let d = 1.0 / self.magnitude(); return (*self) * d; } }
-    impl Vector3 { pub fn normalization(&self)  -> Self { let d = 1.0 / self.magnitude(); return (*self) * d; } }
-    impl Vector4 { pub fn normalization(&self)  -> Self { let d = 1.0 / self.magnitude(); return (*self) * d; } }
+    impl<T: Scalar> Vector2<T> { pub fn magnitude_sqr(&self)  -> T { return (*self) * (*self); } }
+    impl<T: Scalar> Complex<T> { pub fn magnitude_sqr(&self)  -> T { return self.r * self.r + self.i * self.i; } }
+    impl<T: Scalar> Vector3<T> { pub fn magnitude_sqr(&self)  -> T { return (*self) * (*self); } }
+    impl<T: Scalar> Vector4<T> { pub fn magnitude_sqr(&self)  -> T { return (*self) * (*self); } }
+    impl<T: Scalar> Quaternion<T> { pub fn magnitude_sqr(&self) -> T { return Self::product_scalar(self, self); } }
+    impl<T: Scalar> Vector2<T> { pub fn normalization(&self)  -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+    impl<T: Scalar> Vector3<T> { pub fn normalization(&self)  -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+    impl<T: Scalar> Vector4<T> { pub fn normalization(&self)  -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+    impl<T: Scalar> Quaternion<T> { pub fn normalization(&self) -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+    impl<T: Scalar> Complex<T> { pub fn normalization(&self)  -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+    impl<T: Scalar> Quaternion<T> { pub fn conjugate(&self)   -> Self { return Self::new(self.s, -self.i, -self.j, -self.k); } }
+    impl<T: Scalar> Quaternion<T> { pub fn inverse(&self)     -> Self { return self.conjugate() / self.magnitude_sqr(); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn magnitude(&self) -> T { return self.r.magnitude(); } }
+    //      Also re-orthogonalizes the dual part, so that r * e = 0 holds after accumulated error
+    impl<T: Scalar> QuaternionDual<T> { pub fn normalization(&self) -> Self {
+        let d = T::ONE / self.r.magnitude();
+        let r = self.r * d;
+        let e = self.e * d;
+        return Self::new(r, e - r * Quaternion::product_scalar(&r, &e)); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn conjugate(&self) -> Self { return Self::new(self.r.conjugate(), self.e.conjugate()); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn inverse(&self)   -> Self {
+        let r = self.r.inverse();
+        return Self::new(r, r * self.e * r * (-T::ONE)); } }
     // /* Consider not using mutating functions */ impl Vector2 { pub fn normalize(&mut self)  { let d = 1.0 / self.mag(); self.x *= d; self.y *= d; } }
     
     //      Interpolation
-    impl Vector2    { pub fn lerp(a: &Self, b: &Self, t: f32)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
-    impl Complex    { pub fn lerp(a: &Self, b: &Self, t: f32)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
-    impl Dual       { pub fn lerp(a: &Self, b: &Self, t: f32)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
-    impl Vector3    { pub fn lerp(a: &Self, b: &Self, t: f32)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
-    impl Vector4    { pub fn lerp(a: &Self, b: &Self, t: f32)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
+    impl<T: Scalar> Vector2<T>    { pub fn lerp(a: &Self, b: &Self, t: T)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
+    impl<T: Scalar> Complex<T>    { pub fn lerp(a: &Self, b: &Self, t: T)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
+    impl<T: Scalar> Dual<T>       { pub fn lerp(a: &Self, b: &Self, t: T)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
+    impl<T: Scalar> Vector3<T>    { pub fn lerp(a: &Self, b: &Self, t: T)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
+    impl<T: Scalar> Vector4<T>    { pub fn lerp(a: &Self, b: &Self, t: T)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
+    impl<T: Scalar> Quaternion<T> { pub fn lerp(a: &Self, b: &Self, t: T)   -> Self { return (*a) + ((*b) - (*a)) * t; } }
+    //      Normalized lerp along the shortest arc. Cheap, but not constant velocity.
+    impl<T: Scalar> Quaternion<T> { pub fn nlerp(a: &Self, b: &Self, t: T)  -> Self {
+        let mut b = *b;
+        if Self::product_scalar(a, &b) < T::ZERO {
+            b = b * (-T::ONE);
+        }
+        return Self::lerp(a, &b, t).normalization(); } }
+    //      Spherical lerp along the shortest arc (expects unit quaternions)
+    //      https://en.wikipedia.org/wiki/Slerp
+    impl<T: Scalar> Quaternion<T> { pub fn slerp(a: &Self, b: &Self, t: T)  -> Self {
+        let mut b = *b;
+        let mut cos = Self::product_scalar(a, &b);
+        if cos < T::ZERO {
+            b = b * (-T::ONE);
+            cos = -cos;
+        }
+        if cos > T::from_f32(0.9995) {
+            // Nearly parallel, sin(theta) approaches zero
+            return Self::lerp(a, &b, t).normalization();
+        }
+        let theta = T::acos(cos);
+        let sin = T::sin(theta);
+        return (*a) * (T::sin((T::ONE - t) * theta) / sin) + b * (T::sin(t * theta) / sin); } }
+    //      Screw parameters: rotation angle, translation along the axis (pitch), axis direction, and axis moment
+    //      https://www.xbdev.net/misc_demos/demos/dual_quaternions_beyond/paper.pdf
+    impl<T: Scalar> QuaternionDual<T> { pub fn to_screw(&self) -> (Rad<T>, T, Vector3<T>, Vector3<T>) {
+        let dq = self.normalization();
+        let s = T::max(-T::ONE, T::min(T::ONE, dq.r.s));
+        let v = Vector3::new(dq.r.i, dq.r.j, dq.r.k);
+        let sin = v.magnitude();
+        if sin <= T::EPSILON {
+            // Pure translation: the axis is the translation direction, and there is no moment
+            let t = dq.to_translation();
+            let pitch = t.magnitude();
+            if pitch <= T::EPSILON {
+                return (Rad::zero(), T::ZERO, Vector3::right(), Vector3::zero());
+            }
+            return (Rad::zero(), pitch, t / pitch, Vector3::zero());
+        }
+        let angle = Rad::acos(s) * T::TWO;
+        let axis = v / sin;
+        let pitch = -T::TWO * dq.e.s / sin;
+        let moment = (Vector3::new(dq.e.i, dq.e.j, dq.e.k) - axis * (pitch * T::HALF * s)) / sin;
+        return (angle, pitch, axis, moment); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn from_screw<A: Into<Rad<T>>>(angle: A, pitch: T, axis: Vector3<T>, moment: Vector3<T>) -> Self {
+        let (sin, cos) = (angle.into() * T::HALF).sin_cos();
+        let r = Quaternion::new(cos, axis.x * sin, axis.y * sin, axis.z * sin);
+        let m = moment * sin + axis * (pitch * T::HALF * cos);
+        return Self::new(r, Quaternion::new(-pitch * T::HALF * sin, m.x, m.y, m.z)); } }
+    impl<T: Scalar> QuaternionDual<T> { pub fn powf(&self, t: T) -> Self {
+        let (angle, pitch, axis, moment) = self.to_screw();
+        if angle.0.abs() <= T::EPSILON { return Self::translation(axis * (pitch * t)); }
+        return Self::from_screw(angle * t, pitch * t, axis, moment); } }
+    //      Screw linear interpolation (constant speed along the screw motion from a to b)
+    impl<T: Scalar> QuaternionDual<T> { pub fn sclerp(a: &Self, b: &Self, t: T) -> Self {
+        let mut b = *b;
+        if Quaternion::product_scalar(&a.r, &b.r) < T::ZERO { b *= -T::ONE; }
+        return ((*a) * (a.conjugate() * b).powf(t)).normalization(); } }
+    //      Dual quaternion linear blending (DLB), for skinning with more than two influences.
+    //      One weight per dual quaternion, panics otherwise. Identity when there is nothing to blend (no dual
+    //      quaternions, or weights that sum to zero or cancel out).
+    //      https://www.cs.utah.edu/~ladislav/kavan07skinning/kavan07skinning.pdf
+    impl<T: Scalar> QuaternionDual<T> { pub fn blend(dqs: &[Self], weights: &[T]) -> Self {
+        assert_eq!(dqs.len(), weights.len(), "QuaternionDual::blend: {} dual quaternions but {} weights", dqs.len(), weights.len());
+        if dqs.is_empty() { return Self::identity(); }
+        let mut b = Self::zero();
+        let pivot = dqs[0].r;
+        for (dq, w) in dqs.iter().zip(weights.iter()) {
+            // Keep every rotation in the pivot's hemisphere, so no influence goes the long way around
+            let w = if Quaternion::product_scalar(&pivot, &dq.r) < T::ZERO { -*w } else { *w };
+            b += (*dq) * w;
+        }
+        if b.r.magnitude() < T::EPSILON { return Self::identity(); }
+        return b.normalization(); } }
     // impl Vector2 { pub fn slerp(a: &Self, b: &Self, t: f32) -> Self { return; } }
     // impl Vector3 { pub fn slerp(a: &Self, b: &Self, t: f32) -> Self { return; } }
     // impl Vector4 { pub fn slerp(a: &Self, b: &Self, t: f32) -> Self { return; } }
     
     //      Measurement (angles in radians)
-    impl Vector2    { pub fn angle(a: &Self, b: &Self)         -> f32 { return f32::acos(((*a) * (*b)) / (a.magnitude() * b.magnitude())); } }
-    impl Vector3    { pub fn angle(a: &Self, b: &Self)         -> f32 { return f32::acos(((*a) * (*b)) / (a.magnitude() * b.magnitude())); } }
-    impl Vector4    { pub fn angle(a: &Self, b: &Self)         -> f32 { return f32::acos(((*a) * (*b)) / (a.magnitude() * b.magnitude())); } }
-    impl Vector2    { pub fn angle_safe(a: &Self, b: &Self)    -> f32 {
+    impl<T: Scalar> Vector2<T>    { pub fn angle(a: &Self, b: &Self)         -> T { return T::acos(((*a) * (*b)) / (a.magnitude() * b.magnitude())); } }
+    impl<T: Scalar> Vector3<T>    { pub fn angle(a: &Self, b: &Self)         -> T { return T::acos(((*a) * (*b)) / (a.magnitude() * b.magnitude())); } }
+    impl<T: Scalar> Vector4<T>    { pub fn angle(a: &Self, b: &Self)         -> T { return T::acos(((*a) * (*b)) / (a.magnitude() * b.magnitude())); } }
+    impl<T: Scalar> Vector2<T>    { pub fn angle_safe(a: &Self, b: &Self)    -> T {
         let d = a.magnitude() * b.magnitude();
-        if d <= f32::EPSILON {
-            return f32::NAN;
+        if d <= T::EPSILON {
+            return T::NAN;
         } else {
             return Self::angle(&a, &b);
         }
     } }
-    impl Vector3    { pub fn angle_safe(a: &Self, b: &Self)    -> f32 {
+    impl<T: Scalar> Vector3<T>    { pub fn angle_safe(a: &Self, b: &Self)    -> T {
         let d = a.magnitude() * b.magnitude();
-        if d <= f32::EPSILON {
-            return f32::NAN;
+        if d <= T::EPSILON {
+            return T::NAN;
         } else {
             return Self::angle(&a, &b);
         }
     } }
-    impl Vector4    { pub fn angle_safe(a: &Self, b: &Self)    -> f32 {
+    impl<T: Scalar> Vector4<T>    { pub fn angle_safe(a: &Self, b: &Self)    -> T {
         let d = a.magnitude() * b.magnitude();
-        if d <= f32::EPSILON {
-            return f32::NAN;
+        if d <= T::EPSILON {
+            return T::NAN;
         } else {
             return Self::angle(&a, &b);
         }
     } }
-    impl Vector2    { pub fn angle_unit(a: &Self, b: &Self)    -> f32 { return f32::acos((*a) * (*b)); } }
-    impl Vector3    { pub fn angle_unit(a: &Self, b: &Self)    -> f32 { return f32::acos((*a) * (*b)); } }
-    impl Vector4    { pub fn angle_unit(a: &Self, b: &Self)    -> f32 { return f32::acos((*a) * (*b)); } }
+    impl<T: Scalar> Vector2<T>    { pub fn angle_unit(a: &Self, b: &Self)    -> T { return T::acos((*a) * (*b)); } }
+    impl<T: Scalar> Vector3<T>    { pub fn angle_unit(a: &Self, b: &Self)    -> T { return T::acos((*a) * (*b)); } }
+    impl<T: Scalar> Vector4<T>    { pub fn angle_unit(a: &Self, b: &Self)    -> T { return T::acos((*a) * (*b)); } }
     // Taken from https://stackoverflow.com/questions/14066933/direct-way-of-computing-clockwise-angle-between-2-vectors
-    impl Vector2    { pub fn angle_signed(a: &Self, b: &Self)  -> f32 {
+    impl<T: Scalar> Vector2<T>    { pub fn angle_signed(a: &Self, b: &Self)  -> T {
         let dot = (*a) * (*b);
         let det = (*a) / (*b);
-        let mut angle = f32::atan2(det, dot);
-        if angle < 0.0 {
-            angle += TAU;
+        let mut angle = T::atan2(det, dot);
+        if angle < T::ZERO {
+            angle += T::from_f32(TAU);
         }
         return angle;
     } }
@@ -1397,85 +2276,1010 @@ pub mod linalg {
 
     // } }
     //      Vector Projection
-    impl Vector2 { pub fn projection(a: &Self, b: &Self)        -> Self { return (*b) * (((*a) * (*b)) / ((*b) * (*b))); } }
-    impl Vector3 { pub fn projection(a: &Self, b: &Self)        -> Self { return (*b) * (((*a) * (*b)) / ((*b) * (*b))); } }
-    impl Vector4 { pub fn projection(a: &Self, b: &Self)        -> Self { return (*b) * (((*a) * (*b)) / ((*b) * (*b))); } }
-    impl Vector2 { pub fn projection_unit(a: &Self, b: &Self)   -> Self { return (*b) * ((*a) * (*b)); } }
-    impl Vector3 { pub fn projection_unit(a: &Self, b: &Self)   -> Self { return (*b) * ((*a) * (*b)); } }
-    impl Vector4 { pub fn projection_unit(a: &Self, b: &Self)   -> Self { return (*b) * ((*a) * (*b)); } }
+    impl<T: Scalar> Vector2<T> { pub fn projection(a: &Self, b: &Self)        -> Self { return (*b) * (((*a) * (*b)) / ((*b) * (*b))); } }
+    impl<T: Scalar> Vector3<T> { pub fn projection(a: &Self, b: &Self)        -> Self { return (*b) * (((*a) * (*b)) / ((*b) * (*b))); } }
+    impl<T: Scalar> Vector4<T> { pub fn projection(a: &Self, b: &Self)        -> Self { return (*b) * (((*a) * (*b)) / ((*b) * (*b))); } }
+    impl<T: Scalar> Vector2<T> { pub fn projection_unit(a: &Self, b: &Self)   -> Self { return (*b) * ((*a) * (*b)); } }
+    impl<T: Scalar> Vector3<T> { pub fn projection_unit(a: &Self, b: &Self)   -> Self { return (*b) * ((*a) * (*b)); } }
+    impl<T: Scalar> Vector4<T> { pub fn projection_unit(a: &Self, b: &Self)   -> Self { return (*b) * ((*a) * (*b)); } }
     //      Vector Rejection
-    impl Vector2 { pub fn rejection(a: &Self, b: &Self)         -> Self { return (*a) - Self::projection(a, b); } }
-    impl Vector3 { pub fn rejection(a: &Self, b: &Self)         -> Self { return (*a) - Self::projection(a, b); } }
-    impl Vector4 { pub fn rejection(a: &Self, b: &Self)         -> Self { return (*a) - Self::projection(a, b); } }
-    impl Vector2 { pub fn rejection_unit(a: &Self, b: &Self)    -> Self { return (*a) - Self::projection_unit(a, b); } }
-    impl Vector3 { pub fn rejection_unit(a: &Self, b: &Self)    -> Self { return (*a) - Self::projection_unit(a, b); } }
-    impl Vector4 { pub fn rejection_unit(a: &Self, b: &Self)    -> Self { return (*a) - Self::projection_unit(a, b); } }
+    impl<T: Scalar> Vector2<T> { pub fn rejection(a: &Self, b: &Self)         -> Self { return (*a) - Self::projection(a, b); } }
+    impl<T: Scalar> Vector3<T> { pub fn rejection(a: &Self, b: &Self)         -> Self { return (*a) - Self::projection(a, b); } }
+    impl<T: Scalar> Vector4<T> { pub fn rejection(a: &Self, b: &Self)         -> Self { return (*a) - Self::projection(a, b); } }
+    impl<T: Scalar> Vector2<T> { pub fn rejection_unit(a: &Self, b: &Self)    -> Self { return (*a) - Self::projection_unit(a, b); } }
+    impl<T: Scalar> Vector3<T> { pub fn rejection_unit(a: &Self, b: &Self)    -> Self { return (*a) - Self::projection_unit(a, b); } }
+    impl<T: Scalar> Vector4<T> { pub fn rejection_unit(a: &Self, b: &Self)    -> Self { return (*a) - Self::projection_unit(a, b); } }
     //      Vector Reflection
-    impl Vector2 { pub fn reflection(a: &Self, b: &Self)        -> Self { return (*a) - 2.0 * Self::projection(a, b); } }
-    impl Vector3 { pub fn reflection(a: &Self, b: &Self)        -> Self { return (*a) - 2.0 * Self::projection(a, b); } }
-    impl Vector4 { pub fn reflection(a: &Self, b: &Self)        -> Self { return (*a) - 2.0 * Self::projection(a, b); } }
-    impl Vector2 { pub fn reflection_unit(a: &Self, b: &Self)   -> Self { return (*a) - 2.0 * Self::projection_unit(a, b); } }
-    impl Vector3 { pub fn reflection_unit(a: &Self, b: &Self)   -> Self { return (*a) - 2.0 * Self::projection_unit(a, b); } }
-    impl Vector4 { pub fn reflection_unit(a: &Self, b: &Self)   -> Self { return (*a) - 2.0 * Self::projection_unit(a, b); } }
+    impl<T: Scalar> Vector2<T> { pub fn reflection(a: &Self, b: &Self)        -> Self { return (*a) - Self::projection(a, b) * T::TWO; } }
+    impl<T: Scalar> Vector3<T> { pub fn reflection(a: &Self, b: &Self)        -> Self { return (*a) - Self::projection(a, b) * T::TWO; } }
+    impl<T: Scalar> Vector4<T> { pub fn reflection(a: &Self, b: &Self)        -> Self { return (*a) - Self::projection(a, b) * T::TWO; } }
+    impl<T: Scalar> Vector2<T> { pub fn reflection_unit(a: &Self, b: &Self)   -> Self { return (*a) - Self::projection_unit(a, b) * T::TWO; } }
+    impl<T: Scalar> Vector3<T> { pub fn reflection_unit(a: &Self, b: &Self)   -> Self { return (*a) - Self::projection_unit(a, b) * T::TWO; } }
+    impl<T: Scalar> Vector4<T> { pub fn reflection_unit(a: &Self, b: &Self)   -> Self { return (*a) - Self::projection_unit(a, b) * T::TWO; } }
     //      Vector Refraction
-    impl Vector2 { pub fn refraction(a: &Self, b: &Self, n1: f32, n2: f32)      -> Self {
+    impl<T: Scalar> Vector2<T> { pub fn refraction(a: &Self, b: &Self, n1: T, n2: T)      -> Self {
         let mag = a.magnitude();
         let n = n1 / n2;
         let t1 = Self::angle(&(-(*a)), b);
-        let mut signum = f32::cos(Self::angle(a, &Self::right()));
-        if signum > 0.0 {
-            signum = 1.0;
+        let mut signum = T::cos(Self::angle(a, &Self::right()));
+        if signum > T::ZERO {
+            signum = T::ONE;
         } else {
-            signum = (-1.0);
+            signum = (-T::ONE);
         }
-        let t2 = f32::asin(n * f32::sin(t1)) * signum;
+        let t2 = T::asin(n * T::sin(t1)) * signum;
         let t3 = Self::angle(b, &Self::right());
-        let arg = t3 + PI + t2;
+        let arg = t3 + T::from_f32(PI) + t2;
 
-        return Self::from_polar(arg, mag);
+        return Self::from_polar(Rad(arg), mag);
     } }
-    impl Vector2 { pub fn refraction_unit(a: &Self, b: &Self, n1: f32, n2: f32) -> Self {
+    impl<T: Scalar> Vector2<T> { pub fn refraction_unit(a: &Self, b: &Self, n1: T, n2: T) -> Self {
         let n = n1 / n2;
         let t1 = Self::angle_unit(&(-(*a)), b);
-        let mut signum = f32::cos(Self::angle_unit(a, &Self::right()));
-        if signum > 0.0 {
-            signum = 1.0;
+        let mut signum = T::cos(Self::angle_unit(a, &Self::right()));
+        if signum > T::ZERO {
+            signum = T::ONE;
         } else {
-            signum = (-1.0);
+            signum = (-T::ONE);
         }
-        let t2 = f32::asin(n * f32::sin(t1)) * signum;
+        let t2 = T::asin(n * T::sin(t1)) * signum;
         let t3 = Self::angle(b, &Self::right());
-        let arg = t3 + PI + t2;
+        let arg = t3 + T::from_f32(PI) + t2;
 
-        return Self::from_polar(arg, 1.0);
+        return Self::from_polar(Rad(arg), T::ONE);
     } }
+    //          Vector form of Snell's law (no angles): t = η * a + (η * cos_i - cos_t) * b,   η = n1 / n2
+    //          a is the incident direction (towards the surface), b the surface normal (either side; it is flipped
+    //          to face the incident ray), n1 the index on the incident side. None on total internal reflection,
+    //          in which case the light follows reflection() instead.
+    //          https://graphics.stanford.edu/courses/cs148-10-summer/docs/2006--degreve--reflection_refraction.pdf
+    impl<T: Scalar> Vector3<T> { pub fn refraction(a: &Self, b: &Self, n1: T, n2: T)      -> Option<Self> {
+        let mag = a.magnitude();
+        return Self::refraction_unit(&((*a) / mag), &b.normalization(), n1, n2).map(|t| t * mag); } }
+    impl<T: Scalar> Vector3<T> { pub fn refraction_unit(a: &Self, b: &Self, n1: T, n2: T) -> Option<Self> {
+        let mut n = *b;
+        let mut cos_i = -((*a) * n);
+        if cos_i < T::ZERO {
+            n = -n;
+            cos_i = -cos_i;
+        }
+        let eta = n1 / n2;
+        let k = T::ONE - eta * eta * (T::ONE - cos_i * cos_i);
+        if k < T::ZERO { return None; }
+        return Some((*a) * eta + n * (eta * cos_i - T::sqrt(k))); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_total_internal_reflection(a: &Self, b: &Self, n1: T, n2: T) -> bool {
+        let cos_i = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return (n1 / n2) * (n1 / n2) * (T::ONE - cos_i * cos_i) > T::ONE; } }
+    //          Fresnel reflectance for a ray a hitting a surface with normal b (see num::fresnel_*)
+    impl<T: Scalar> Vector3<T> { pub fn fresnel_schlick(a: &Self, b: &Self, n1: T, n2: T)    -> T {
+        return super::num::fresnel_schlick(((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr()), n1, n2); } }
+    impl<T: Scalar> Vector3<T> { pub fn fresnel_dielectric(a: &Self, b: &Self, n1: T, n2: T) -> T {
+        return super::num::fresnel_dielectric(((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr()), n1, n2); } }
+
+    //      Complex Analysis
+    //      Multi-valued functions (arg, ln, sqrt, powers) return the principal value, with arg in (-pi, pi]
+    //      and the branch cut along the negative real axis.
+    //      https://en.wikipedia.org/wiki/Complex_logarithm#Principal_value
+    impl<T: Scalar> Complex<T> { pub fn real(&self)       -> T { return self.r; } }
+    impl<T: Scalar> Complex<T> { pub fn imaginary(&self)  -> T { return self.i; } }
+    impl<T: Scalar> Complex<T> { pub fn conjugate(&self)  -> Self { return Self::new(self.r, -self.i); } }
+    impl<T: Scalar> Complex<T> { pub fn inverse(&self)    -> Self { return self.conjugate() / self.magnitude_sqr(); } }
+    impl<T: Scalar> Complex<T> { pub fn arg(&self)        -> Rad<T> { return Rad::atan2(self.i, self.r); } }
+    //          (angle, radius), the inverse of from_polar()
+    impl<T: Scalar> Complex<T> { pub fn to_polar(&self)   -> (Rad<T>, T) { return (self.arg(), self.magnitude()); } }
+    //          e^(a + bi) = e^a (cos b + i sin b)
+    impl<T: Scalar> Complex<T> { pub fn exp(&self)        -> Self { return Self::from_polar(Rad(self.i), T::exp(self.r)); } }
+    //          ln(z) = ln|z| + i arg(z)
+    impl<T: Scalar> Complex<T> { pub fn ln(&self)         -> Self { return Self::new(T::ln(self.magnitude()), self.arg().0); } }
+    //          z^n = |z|^n (cos(n arg z) + i sin(n arg z)),  and 0^n = 0 (but z^0 = 1, including 0^0, like f64::powf)
+    impl<T: Scalar> Complex<T> { pub fn powf(&self, n: T) -> Self {
+        if n == T::ZERO { return Self::one(); }
+        if self.r == T::ZERO && self.i == T::ZERO { return if n > T::ZERO { Self::zero() } else { Self::new(T::INFINITY, T::ZERO) }; }
+        return Self::from_polar(self.arg() * n, T::powf(self.magnitude(), n)); } }
+    //          z^w = e^(w ln z)
+    impl<T: Scalar> Complex<T> { pub fn powc(&self, w: &Self) -> Self {
+        if w.r == T::ZERO && w.i == T::ZERO { return Self::one(); }
+        if self.r == T::ZERO && self.i == T::ZERO { return if w.r > T::ZERO { Self::zero() } else { Self::new(T::INFINITY, T::ZERO) }; }
+        return ((*w) * self.ln()).exp(); } }
+    //          Without going through angles: sqrt((|z| + a) / 2) + i sign(b) sqrt((|z| - a) / 2)
+    impl<T: Scalar> Complex<T> { pub fn sqrt(&self)       -> Self {
+        let m = self.magnitude();
+        let r = T::sqrt(T::max(T::ZERO, (m + self.r) * T::HALF));
+        let i = T::sqrt(T::max(T::ZERO, (m - self.r) * T::HALF));
+        return Self::new(r, if self.i < T::ZERO { -i } else { i }); } }
+    //          Trigonometric and hyperbolic functions (sin(a + bi) = sin a cosh b + i cos a sinh b, etc.)
+    impl<T: Scalar> Complex<T> { pub fn sin(&self)        -> Self {
+        let (e, f) = (T::exp(self.i), T::exp(-self.i));
+        return Self::new(T::sin(self.r) * (e + f) * T::HALF, T::cos(self.r) * (e - f) * T::HALF); } }
+    impl<T: Scalar> Complex<T> { pub fn cos(&self)        -> Self {
+        let (e, f) = (T::exp(self.i), T::exp(-self.i));
+        return Self::new(T::cos(self.r) * (e + f) * T::HALF, -T::sin(self.r) * (e - f) * T::HALF); } }
+    impl<T: Scalar> Complex<T> { pub fn tan(&self)        -> Self { return self.sin() / self.cos(); } }
+    impl<T: Scalar> Complex<T> { pub fn sinh(&self)       -> Self { return Self::new(-self.i, self.r).sin() * Self::new(T::ZERO, -T::ONE); } }
+    impl<T: Scalar> Complex<T> { pub fn cosh(&self)       -> Self { return Self::new(-self.i, self.r).cos(); } }
+    //          The n n-th roots of unity, e^(2 pi i k / n), for FFT twiddle factors
+    impl<T: Scalar> Complex<T> { pub fn roots_of_unity(n: usize) -> Vec<Self> {
+        let turn = Rad::<T>::full_turn();
+        return (0..n).map(|k| Self::from_rotor(turn * (T::from_f64(k as f64) / T::from_f64(n as f64)))).collect(); } }
+    //      Complex numbers as 2D rotations (a unit complex number rotates, any other also scales)
+    //          https://en.wikipedia.org/wiki/Complex_number#Multiplication_and_square
+    impl<T: Scalar> Complex<T> { pub fn rotate(&self, v: &Vector2<T>) -> Vector2<T> {
+        return Vector2::new(self.r * v.x - self.i * v.y, self.r * v.y + self.i * v.x); } }
+    impl<T: Scalar> Mul<Vector2<T>> for Complex<T> { type Output = Vector2<T>; fn mul(self, v: Vector2<T>) -> Vector2<T> {
+        return self.rotate(&v); } }
+    //          Unit complex number rotating the direction of a onto the direction of b
+    impl<T: Scalar> Complex<T> { pub fn rotation_between(a: &Vector2<T>, b: &Vector2<T>) -> Self {
+        return Self::new((*a) * (*b), a.x * b.y - a.y * b.x).normalization(); } }
+    impl<T: Scalar> Vector2<T> { pub fn rotation<A: Into<Rad<T>>>(&self, angle: A)  -> Self { return Complex::from_rotor(angle).rotate(self); } }
+    impl<T: Scalar> Matrix2<T> { pub fn from_complex(c: &Complex<T>)  -> Self {
+        return Self::new(
+            c.r,    -c.i,
+            c.i,    c.r); } }
+    impl<T: Scalar> Complex<T> { pub fn to_matrix2(&self)             -> Matrix2<T> { return Matrix2::from_complex(self); } }
+    //          Constant angular speed between two unit complex numbers (along the shorter arc)
+    impl<T: Scalar> Complex<T> { pub fn slerp(a: &Self, b: &Self, t: T) -> Self {
+        let delta = (*b) * a.conjugate();
+        return (*a) * Self::from_rotor(delta.arg() * t); } }
+
+    //      Automatic Differentiation (forward mode)
+    //      Evaluating f(a + 1ε) gives f(a) + f'(a)ε, so the derivative is exact and comes for free with the value.
+    impl<T: Scalar> Dual<T> { pub fn constant(a: T) -> Self { return Self::new(a, T::ZERO); } }
+    impl<T: Scalar> Dual<T> { pub fn variable(a: T) -> Self { return Self::new(a, T::ONE); } }
+    impl<T: Scalar> Dual<T> { pub fn real(&self)      -> T  { return self.r; } }
+    impl<T: Scalar> Dual<T> { pub fn dual(&self)      -> T  { return self.e; } }
+    impl<T: Scalar> Dual<T> { pub fn conjugate(&self) -> Self { return Self::new(self.r, -self.e); } }
+    //          Elementary functions: f(a + bε) = f(a) + b * f'(a)ε
+    impl<T: Scalar> Dual<T> { pub fn sin(&self)       -> Self { return Self::new(T::sin(self.r), self.e * T::cos(self.r)); } }
+    impl<T: Scalar> Dual<T> { pub fn cos(&self)       -> Self { return Self::new(T::cos(self.r), -self.e * T::sin(self.r)); } }
+    impl<T: Scalar> Dual<T> { pub fn tan(&self)       -> Self {
+        let cos = T::cos(self.r);
+        return Self::new(T::tan(self.r), self.e / (cos * cos)); } }
+    impl<T: Scalar> Dual<T> { pub fn asin(&self)      -> Self { return Self::new(T::asin(self.r), self.e / T::sqrt(T::ONE - self.r * self.r)); } }
+    impl<T: Scalar> Dual<T> { pub fn acos(&self)      -> Self { return Self::new(T::acos(self.r), -self.e / T::sqrt(T::ONE - self.r * self.r)); } }
+    impl<T: Scalar> Dual<T> { pub fn atan(&self)      -> Self { return Self::new(T::atan(self.r), self.e / (T::ONE + self.r * self.r)); } }
+    impl<T: Scalar> Dual<T> { pub fn exp(&self)       -> Self {
+        let exp = T::exp(self.r);
+        return Self::new(exp, self.e * exp); } }
+    impl<T: Scalar> Dual<T> { pub fn ln(&self)        -> Self { return Self::new(T::ln(self.r), self.e / self.r); } }
+    impl<T: Scalar> Dual<T> { pub fn sqrt(&self)      -> Self {
+        let sqrt = T::sqrt(self.r);
+        return Self::new(sqrt, self.e / (T::TWO * sqrt)); } }
+    impl<T: Scalar> Dual<T> { pub fn abs(&self)       -> Self { return Self::new(T::abs(self.r), self.e * T::signum(self.r)); } }
+    impl<T: Scalar> Dual<T> { pub fn powf(&self, n: T) -> Self {
+        return Self::new(T::powf(self.r, n), self.e * n * T::powf(self.r, n - T::ONE)); } }
+    //          a^c = exp(c * ln(a)), for a > 0
+    impl<T: Scalar> Dual<T> { pub fn pow(&self, d: &Self) -> Self {
+        let pow = T::powf(self.r, d.r);
+        return Self::new(pow, pow * (d.e * T::ln(self.r) + d.r * self.e / self.r)); } }
+    //          Derivatives of closures
+    impl<T: Scalar> Dual<T> { pub fn derivative<F: Fn(Dual<T>) -> Dual<T>>(f: F, x: T) -> T {
+        return f(Self::variable(x)).e; } }
+    impl<T: Scalar> Dual<T> { pub fn gradient2<F: Fn([Dual<T>; 2]) -> Dual<T>>(f: F, p: Vector2<T>) -> Vector2<T> {
+        return Vector2::new(
+            f([Self::variable(p.x), Self::constant(p.y)]).e,
+            f([Self::constant(p.x), Self::variable(p.y)]).e); } }
+    impl<T: Scalar> Dual<T> { pub fn gradient3<F: Fn([Dual<T>; 3]) -> Dual<T>>(f: F, p: Vector3<T>) -> Vector3<T> {
+        return Vector3::new(
+            f([Self::variable(p.x), Self::constant(p.y), Self::constant(p.z)]).e,
+            f([Self::constant(p.x), Self::variable(p.y), Self::constant(p.z)]).e,
+            f([Self::constant(p.x), Self::constant(p.y), Self::variable(p.z)]).e); } }
+    //          Jacobian J[i][j] = ∂f_i / ∂x_j (one forward pass per column)
+    impl<T: Scalar> Dual<T> { pub fn jacobian2<F: Fn([Dual<T>; 2]) -> [Dual<T>; 2]>(f: F, p: Vector2<T>) -> Matrix2<T> {
+        let dx = f([Self::variable(p.x), Self::constant(p.y)]);
+        let dy = f([Self::constant(p.x), Self::variable(p.y)]);
+        return Matrix2::new(
+            dx[0].e,    dy[0].e,
+            dx[1].e,    dy[1].e); } }
+    impl<T: Scalar> Dual<T> { pub fn jacobian3<F: Fn([Dual<T>; 3]) -> [Dual<T>; 3]>(f: F, p: Vector3<T>) -> Matrix3<T> {
+        let dx = f([Self::variable(p.x), Self::constant(p.y), Self::constant(p.z)]);
+        let dy = f([Self::constant(p.x), Self::variable(p.y), Self::constant(p.z)]);
+        let dz = f([Self::constant(p.x), Self::constant(p.y), Self::variable(p.z)]);
+        return Matrix3::new(
+            dx[0].e,    dy[0].e,    dz[0].e,
+            dx[1].e,    dy[1].e,    dz[1].e,
+            dx[2].e,    dy[2].e,    dz[2].e); } }
+
+    // Queries
+    //      Approximate comparison. The *_eps variants take their own epsilon and test mode,
+    //      the others use Scalar::TOLERANCE and TestMode::AbsoluteCoordinates.
+    //      Exact comparison (==, !=) is derived.
+    #[derive(Debug, Copy, Clone, PartialEq)] pub enum TestMode {
+        // Test difference in magnitudes in world-space units
+        AbsoluteMagnitude,
+        // Test difference in magnitude in percentage
+        RelativeMagnitude,
+        // Test difference in coordinates in world-space units
+        AbsoluteCoordinates,
+        // Test difference in coordinates in percentage
+        RelativeCoordinates
+    }
+    //          Compares the elements of a and b as flat arrays (matrices use the Frobenius norm for magnitudes)
+    fn approx_eq_elements<T: Scalar>(a: &[T], b: &[T], epsilon: T, mode: TestMode) -> bool {
+        match mode {
+            TestMode::AbsoluteMagnitude | TestMode::RelativeMagnitude => {
+                let mut difference = T::ZERO;
+                let mut magnitude_a = T::ZERO;
+                let mut magnitude_b = T::ZERO;
+                for i in 0..a.len() {
+                    difference += (a[i] - b[i]) * (a[i] - b[i]);
+                    magnitude_a += a[i] * a[i];
+                    magnitude_b += b[i] * b[i];
+                }
+                let tolerance = if mode == TestMode::AbsoluteMagnitude { epsilon } else { epsilon * T::sqrt(T::max(magnitude_a, magnitude_b)) };
+                return T::sqrt(difference) <= tolerance;
+            },
+            TestMode::AbsoluteCoordinates | TestMode::RelativeCoordinates => {
+                for i in 0..a.len() {
+                    let tolerance = if mode == TestMode::AbsoluteCoordinates { epsilon } else { epsilon * T::max(a[i].abs(), b[i].abs()) };
+                    if (a[i] - b[i]).abs() > tolerance { return false; }
+                }
+                return true;
+            }
+        }
+    }
+    impl<T: Scalar> Vector2<T> { pub fn approx_eq(&self, v: &Self) -> bool { return self.approx_eq_eps(v, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+    impl<T: Scalar> Complex<T> { pub fn approx_eq(&self, c: &Self) -> bool { return self.approx_eq_eps(c, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+    impl<T: Scalar> Vector3<T> { pub fn approx_eq(&self, v: &Self) -> bool { return self.approx_eq_eps(v, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+    impl<T: Scalar> Vector4<T> { pub fn approx_eq(&self, v: &Self) -> bool { return self.approx_eq_eps(v, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+    impl<T: Scalar> Matrix2<T> { pub fn approx_eq(&self, m: &Self) -> bool { return self.approx_eq_eps(m, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+    impl<T: Scalar> Matrix3<T> { pub fn approx_eq(&self, m: &Self) -> bool { return self.approx_eq_eps(m, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+    impl<T: Scalar> Matrix4<T> { pub fn approx_eq(&self, m: &Self) -> bool { return self.approx_eq_eps(m, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+    impl<T: Scalar> Vector2<T> { pub fn approx_eq_eps(&self, v: &Self, epsilon: T, mode: TestMode) -> bool {
+        return approx_eq_elements(&[self.x, self.y], &[v.x, v.y], epsilon, mode); } }
+    impl<T: Scalar> Complex<T> { pub fn approx_eq_eps(&self, c: &Self, epsilon: T, mode: TestMode) -> bool {
+        return approx_eq_elements(&[self.r, self.i], &[c.r, c.i], epsilon, mode); } }
+    impl<T: Scalar> Vector3<T> { pub fn approx_eq_eps(&self, v: &Self, epsilon: T, mode: TestMode) -> bool {
+        return approx_eq_elements(&[self.x, self.y, self.z], &[v.x, v.y, v.z], epsilon, mode); } }
+    impl<T: Scalar> Vector4<T> { pub fn approx_eq_eps(&self, v: &Self, epsilon: T, mode: TestMode) -> bool {
+        return approx_eq_elements(&[self.x, self.y, self.z, self.w], &[v.x, v.y, v.z, v.w], epsilon, mode); } }
+    impl<T: Scalar> Matrix2<T> { pub fn approx_eq_eps(&self, m: &Self, epsilon: T, mode: TestMode) -> bool {
+        return approx_eq_elements(self.as_slice(), m.as_slice(), epsilon, mode); } }
+    impl<T: Scalar> Matrix3<T> { pub fn approx_eq_eps(&self, m: &Self, epsilon: T, mode: TestMode) -> bool {
+        return approx_eq_elements(self.as_slice(), m.as_slice(), epsilon, mode); } }
+    impl<T: Scalar> Matrix4<T> { pub fn approx_eq_eps(&self, m: &Self, epsilon: T, mode: TestMode) -> bool {
+        return approx_eq_elements(self.as_slice(), m.as_slice(), epsilon, mode); } }
+    //      Direction queries. Parallel means same direction, anti-parallel opposite direction, and collinear either.
+    //      The general versions compare the cosine of the angle between a and b (zero vectors are never parallel
+    //      or orthogonal), the *_unit versions expect unit vectors and skip the normalization.
+    impl<T: Scalar> Vector2<T> { pub fn is_normalized(&self)                           -> bool { return self.is_normalized_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_normalized(&self)                           -> bool { return self.is_normalized_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_normalized(&self)                           -> bool { return self.is_normalized_eps(T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_normalized_eps(&self, epsilon: T)           -> bool { return (self.magnitude_sqr() - T::ONE).abs() <= epsilon * T::TWO; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_normalized_eps(&self, epsilon: T)           -> bool { return (self.magnitude_sqr() - T::ONE).abs() <= epsilon * T::TWO; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_normalized_eps(&self, epsilon: T)           -> bool { return (self.magnitude_sqr() - T::ONE).abs() <= epsilon * T::TWO; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_parallel(a: &Self, b: &Self)          -> bool { return Self::is_parallel_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_parallel(a: &Self, b: &Self)          -> bool { return Self::is_parallel_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_parallel(a: &Self, b: &Self)          -> bool { return Self::is_parallel_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_parallel_unit(a: &Self, b: &Self)     -> bool { return Self::is_parallel_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_parallel_unit(a: &Self, b: &Self)     -> bool { return Self::is_parallel_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_parallel_unit(a: &Self, b: &Self)     -> bool { return Self::is_parallel_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_parallel_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_parallel_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_parallel_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_parallel_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_parallel_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_parallel_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_anti_parallel(a: &Self, b: &Self)          -> bool { return Self::is_anti_parallel_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_anti_parallel(a: &Self, b: &Self)          -> bool { return Self::is_anti_parallel_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_anti_parallel(a: &Self, b: &Self)          -> bool { return Self::is_anti_parallel_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_anti_parallel_unit(a: &Self, b: &Self)     -> bool { return Self::is_anti_parallel_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_anti_parallel_unit(a: &Self, b: &Self)     -> bool { return Self::is_anti_parallel_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_anti_parallel_unit(a: &Self, b: &Self)     -> bool { return Self::is_anti_parallel_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_anti_parallel_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos <= epsilon - T::ONE; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_anti_parallel_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos <= epsilon - T::ONE; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_anti_parallel_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos <= epsilon - T::ONE; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_anti_parallel_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos <= epsilon - T::ONE; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_anti_parallel_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos <= epsilon - T::ONE; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_anti_parallel_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos <= epsilon - T::ONE; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_collinear(a: &Self, b: &Self)          -> bool { return Self::is_collinear_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_collinear(a: &Self, b: &Self)          -> bool { return Self::is_collinear_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_collinear(a: &Self, b: &Self)          -> bool { return Self::is_collinear_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_collinear_unit(a: &Self, b: &Self)     -> bool { return Self::is_collinear_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_collinear_unit(a: &Self, b: &Self)     -> bool { return Self::is_collinear_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_collinear_unit(a: &Self, b: &Self)     -> bool { return Self::is_collinear_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_collinear_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos.abs() >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_collinear_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos.abs() >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_collinear_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos.abs() >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_collinear_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos.abs() >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_collinear_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos.abs() >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_collinear_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos.abs() >= T::ONE - epsilon; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_orthogonal(a: &Self, b: &Self)          -> bool { return Self::is_orthogonal_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_orthogonal(a: &Self, b: &Self)          -> bool { return Self::is_orthogonal_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_orthogonal(a: &Self, b: &Self)          -> bool { return Self::is_orthogonal_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_orthogonal_unit(a: &Self, b: &Self)     -> bool { return Self::is_orthogonal_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector3<T> { pub fn is_orthogonal_unit(a: &Self, b: &Self)     -> bool { return Self::is_orthogonal_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector4<T> { pub fn is_orthogonal_unit(a: &Self, b: &Self)     -> bool { return Self::is_orthogonal_unit_eps(a, b, T::TOLERANCE); } }
+    impl<T: Scalar> Vector2<T> { pub fn is_orthogonal_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos.abs() <= epsilon; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_orthogonal_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos.abs() <= epsilon; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_orthogonal_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = ((*a) * (*b)) / T::sqrt(a.magnitude_sqr() * b.magnitude_sqr());
+        return cos.abs() <= epsilon; } }
+    impl<T: Scalar> Vector2<T> { pub fn is_orthogonal_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos.abs() <= epsilon; } }
+    impl<T: Scalar> Vector3<T> { pub fn is_orthogonal_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos.abs() <= epsilon; } }
+    impl<T: Scalar> Vector4<T> { pub fn is_orthogonal_unit_eps(a: &Self, b: &Self, epsilon: T) -> bool {
+        let cos = (*a) * (*b);
+        return cos.abs() <= epsilon; } }
+
+    // Regression tests for the storage convention
+    //      Reference values are what cgmath 0.16 produces for the same input (column-major, as uploaded to OpenGL).
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use super::super::num;
+
+        fn assert_cols(m: &Matrix4<f32>, reference: [f32; 16]) {
+            let cols = m.to_cols_array();
+            for i in 0..16 {
+                assert!((cols[i] - reference[i]).abs() < 1.0e-5, "element {}: {} != {}", i, cols[i], reference[i]);
+            }
+        }
 
+        // Unit quaternions q and -q are the same rotation
+        fn same_rotation(a: &Quaternion<f64>, b: &Quaternion<f64>) -> bool {
+            return (Quaternion::product_scalar(a, b).abs() - 1.0).abs() < 1.0e-9;
+        }
 
-    /*
-        // Queries
-        ==
-        <
-        <=
-        >
-        >=
-        isNormalized
-        isParallel
-        isParallelUnit
-        isAntiParallel
-        isAntiParallelUnit
-        isCollinear
-        isCollinearUnit
-        isOrthogonal
-        isOrthogonalUnit
-        TestMode {
-            // Test difference in magnitudes in world-space units
-            AbsoluteMagnitude,
-            // Test difference in magnitude in percentage
-            RelativeMagnitude,
-            // Test difference in coordinates in world-space units
-            AbsoluteCoordinates,
-            // Test difference in coordinates in percentage
-            RelativeCoordinates
+        #[test]
+        fn perspective_matches_reference() {
+            // cgmath::perspective(Deg(45.0), 4.0 / 3.0, 0.1, 100.0)
+            assert_cols(&Matrix4::perspective(Deg(45.0), 4.0 / 3.0, 0.1, 100.0), [
+                1.8106602,  0.0,        0.0,            0.0,
+                0.0,        2.4142136,  0.0,            0.0,
+                0.0,        0.0,        -1.002002,      -1.0,
+                0.0,        0.0,        -0.2002002,     0.0]);
         }
-    */
-}
\ No newline at end of file
+
+        #[test]
+        fn translation_matches_reference() {
+            // cgmath::Matrix4::from_translation(vec3(1.0, 2.0, 3.0))
+            let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+            let reference = [
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                1.0, 2.0, 3.0, 1.0];
+            assert_cols(&m, reference);
+            // What OpenGL reads through the pointer
+            let uploaded = unsafe { std::slice::from_raw_parts(m.as_ptr(), 16) };
+            assert_eq!(uploaded, &reference[..]);
+        }
+
+        #[test]
+        fn rotation_matches_reference() {
+            // cgmath::Matrix4::from_axis_angle(vec3(1.0, 0.3, 0.5).normalize(), Rad(0.7))
+            assert_cols(&Matrix4::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization()), [
+                0.9403331,  0.3309069,  -0.0792103, 0.0,
+                -0.2256124, 0.7806364,  0.582843,   0.0,
+                0.2547012,  -0.5301957, 0.8087149,  0.0,
+                0.0,        0.0,        0.0,        1.0]);
+            assert!(Matrix4::rotation_x(Rad(0.7)).approx_eq(&Matrix4::rotation(Rad(0.7), Vector3::new(1.0, 0.0, 0.0))));
+            assert!(Matrix4::rotation_y(Rad(0.7)).approx_eq(&Matrix4::rotation(Rad(0.7), Vector3::new(0.0, 1.0, 0.0))));
+            assert!(Matrix4::rotation_z(Rad(0.7)).approx_eq(&Matrix4::rotation(Rad(0.7), Vector3::new(0.0, 0.0, 1.0))));
+        }
+
+        #[test]
+        fn product_applies_right_operand_first() {
+            let t = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+            let r = Matrix4::rotation_z(Deg(90.0));
+            let p = Vector3::new(1.0, 0.0, 0.0);
+            assert!((t * r).transform_point(&p).approx_eq(&t.transform_point(&r.transform_point(&p))));
+            assert!((t * r).transform_point(&p).approx_eq(&Vector3::new(1.0, 3.0, 3.0)));
+        }
+
+        #[test]
+        fn quaternion_rotation() {
+            let axis = Vector3::new(1.0, 0.3, 0.5).normalization();
+            let (q, m) = (Quaternion::rotation(Rad(0.7), axis), Matrix4::rotation(Rad(0.7), axis));
+            let v = Vector3::new(0.2, -1.0, 3.0);
+            // Same convention as the matrices, composes like them, and the inverse undoes it
+            assert!(Quaternion::rotation_z(Deg(90.0)).rotate(&Vector3::right()).approx_eq(&Vector3::forth()));
+            assert!(q.rotate(&v).approx_eq(&m.transform_direction(&v)) && (q * v).approx_eq(&q.rotate(&v)));
+            let r = Quaternion::rotation_x(Deg(30.0));
+            assert!((q * r).rotate(&v).approx_eq(&q.rotate(&r.rotate(&v))) && q.inverse().rotate(&q.rotate(&v)).approx_eq(&v));
+            let (angle, around) = q.to_axis_angle();
+            assert!((angle.0 - 0.7).abs() < 1.0e-9 && around.approx_eq(&axis));
+
+            // Matrix round trips, including a half turn (trace -1, where the naive formula divides by zero)
+            assert!(same_rotation(&Quaternion::from_matrix3(&Matrix3::from_quaternion(&q)), &q) && same_rotation(&Quaternion::from_matrix4(&m), &q));
+            let half = Quaternion::rotation(Deg(180.0), Vector3::new(0.0, 0.6, 0.8));
+            assert!(same_rotation(&Quaternion::from_matrix3(&Matrix3::from_quaternion(&half)), &half));
+
+            // Slerp turns at a constant rate along the short arc, nlerp meets it halfway
+            let (a, b) = (Quaternion::rotation_z(Deg(10.0)), Quaternion::rotation_z(Deg(130.0)));
+            assert!(same_rotation(&Quaternion::slerp(&a, &b, 0.25), &Quaternion::rotation_z(Deg(40.0))));
+            assert!(same_rotation(&Quaternion::slerp(&a, &(b * -1.0), 0.25), &Quaternion::rotation_z(Deg(40.0))));
+            assert!(same_rotation(&Quaternion::nlerp(&a, &b, 0.5), &Quaternion::rotation_z(Deg(70.0))));
+            assert!(same_rotation(&Quaternion::slerp(&a, &b, 0.0), &a) && same_rotation(&Quaternion::slerp(&a, &b, 1.0), &b));
+        }
+
+        #[test]
+        fn dual_quaternion_rigid_transforms() {
+            let axis = Vector3::new(1.0, 0.3, 0.5).normalization();
+            let (q, t) = (Quaternion::rotation(Rad(0.7), axis), Vector3::new(1.0, 2.0, 3.0));
+            let dq = QuaternionDual::from_rotation_translation(&q, &t);
+            let m = Matrix4::translation(t) * Matrix4::rotation(Rad(0.7), axis);
+            let p = Vector3::new(0.2, -1.0, 3.0);
+            // Rotates, then translates, like the matrix (directions are only rotated)
+            assert!(dq.transform_point(&p).approx_eq(&m.transform_point(&p)) && (dq * p).approx_eq(&m.transform_point(&p)));
+            assert!(dq.transform_direction(&p).approx_eq(&m.transform_direction(&p)) && Matrix4::from_quaternion_dual(&dq).approx_eq(&m));
+            let (r, s) = QuaternionDual::from_matrix4(&m).to_rotation_translation();
+            assert!(same_rotation(&r, &q) && s.approx_eq(&t));
+            let other = QuaternionDual::from_rotation_translation(&Quaternion::rotation_z(Deg(90.0)), &Vector3::new(-4.0, 0.5, 1.0));
+            assert!((dq * other).transform_point(&p).approx_eq(&dq.transform_point(&other.transform_point(&p))));
+            assert!((dq * dq.inverse()).transform_point(&p).approx_eq(&p));
+
+            // ScLERP follows the screw: half of (a quarter turn about z while rising 4) is an eighth turn rising 2
+            let b = QuaternionDual::translation(Vector3::new(0.0, 0.0, 4.0)) * QuaternionDual::rotation(Deg(90.0), Vector3::up());
+            let half = QuaternionDual::sclerp(&QuaternionDual::identity(), &b, 0.5);
+            assert!(same_rotation(&half.to_rotation(), &Quaternion::rotation_z(Deg(45.0))) && half.to_translation().approx_eq(&Vector3::new(0.0, 0.0, 2.0)));
+            assert!(QuaternionDual::sclerp(&dq, &b, 1.0).transform_point(&p).approx_eq(&b.transform_point(&p)));
+            // Points on an off-origin screw axis stay on it all the way
+            let hinge = QuaternionDual::translation(Vector3::right()) * QuaternionDual::rotation(Deg(120.0), Vector3::up()) * QuaternionDual::translation(Vector3::new(-1.0, 0.0, 0.0));
+            assert!(QuaternionDual::sclerp(&QuaternionDual::identity(), &hinge, 0.3).transform_point(&Vector3::new(1.0, 0.0, 5.0)).approx_eq(&Vector3::new(1.0, 0.0, 5.0)));
+
+            // DLB: equal weights land in between, whichever sign the inputs carry
+            let (c, d) = (QuaternionDual::rotation(Deg(20.0), Vector3::up()), QuaternionDual::rotation(Deg(80.0), Vector3::up()));
+            assert!(same_rotation(&QuaternionDual::blend(&[c, d], &[0.5, 0.5]).to_rotation(), &Quaternion::rotation_z(Deg(50.0))));
+            assert!(same_rotation(&QuaternionDual::blend(&[c, d * -1.0], &[0.5, 0.5]).to_rotation(), &Quaternion::rotation_z(Deg(50.0))));
+            assert!(QuaternionDual::blend(&[dq], &[2.0]).transform_point(&p).approx_eq(&dq.transform_point(&p)));
+            assert!(QuaternionDual::<f64>::blend(&[], &[]).transform_point(&p).approx_eq(&p));
+            // Weights that sum to zero, or cancel out, leave nothing to normalize
+            assert!(QuaternionDual::blend(&[c, d], &[0.0, 0.0]).transform_point(&p).approx_eq(&p));
+            assert!(QuaternionDual::blend(&[dq, dq], &[1.0, -1.0]).transform_point(&p).approx_eq(&p));
+        }
+
+        #[test]
+        #[should_panic(expected = "2 dual quaternions but 1 weights")]
+        fn blend_needs_a_weight_per_dual_quaternion() {
+            QuaternionDual::blend(&[QuaternionDual::<f64>::identity(), QuaternionDual::translation(Vector3::up())], &[1.0]);
+        }
+
+        #[test]
+        fn dual_differentiation() {
+            let f = |x: Dual<f64>| -x * x;
+            assert_eq!(f(Dual::variable(2.0)), Dual::new(-4.0, -4.0));
+            assert_eq!(Dual::derivative(f, 2.0), -4.0);
+            assert_eq!(-Dual::new(1.0, 2.0), Dual::new(-1.0, -2.0));
+            assert_eq!(Dual::new(1.0, 2.0).conjugate(), Dual::new(1.0, -2.0));
+            assert!((Dual::derivative(|x| x.sin() * x.exp(), 0.5) - 0.5f64.exp() * (0.5f64.sin() + 0.5f64.cos())).abs() < 1e-12);
+
+            // f(x, y) = xy + sin(x) and g(x, y, z) = -xyz
+            let gradient = Dual::gradient2(|[x, y]| x * y + x.sin(), Vector2::new(1.0, 2.0));
+            assert!(gradient.approx_eq(&Vector2::new(2.0 + 1.0f64.cos(), 1.0)));
+            let gradient = Dual::gradient3(|[x, y, z]| -(x * y * z), Vector3::new(1.0, 2.0, 3.0));
+            assert_eq!(gradient, Vector3::new(-6.0, -3.0, -2.0));
+
+            // Polar to Cartesian has J = [cos -r sin; sin r cos]
+            let (r, t) = (2.0, 0.3f64);
+            let jacobian = Dual::jacobian2(|[r, t]| [r * t.cos(), r * t.sin()], Vector2::new(r, t));
+            assert!(jacobian.approx_eq(&Matrix2::new(t.cos(), -r * t.sin(), t.sin(), r * t.cos())));
+            let jacobian = Dual::jacobian3(|[x, y, z]| [-x, x * y, y - z * z], Vector3::new(1.0, 2.0, 3.0));
+            assert_eq!(jacobian, Matrix3::new(-1.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, 1.0, -6.0));
+        }
+
+        #[test]
+        fn rotors_match_quaternions() {
+            let axis = Vector3::new(1.0, 0.3, 0.5).normalization();
+            let (rotor, q) = (Rotor3::rotation(Rad(0.7), Bivector3::from_vector3(&axis)), Quaternion::rotation(Rad(0.7), axis));
+            let v = Vector3::new(0.2, -1.0, 3.0);
+            // Rotating in the plane dual to an axis is rotating around the axis
+            assert!(rotor.rotate(&v).approx_eq(&q.rotate(&v)) && (rotor * v).approx_eq(&q.rotate(&v)));
+            assert!(same_rotation(&Quaternion::from_rotor3(&rotor), &q) && Rotor3::from_quaternion(&q).rotate(&v).approx_eq(&rotor.rotate(&v)));
+            assert!(Matrix4::from_rotor3(&rotor).approx_eq(&Matrix4::rotation(Rad(0.7), axis)) && Matrix3::from_rotor3(&rotor).approx_eq(&Matrix3::from_quaternion(&q)));
+            // Composition and the reverse behave like the quaternion product and conjugate
+            let other = Rotor3::rotation(Deg(90.0), Bivector3::xy_plane());
+            let q_other = Quaternion::from_rotor3(&other);
+            assert!((rotor * other).rotate(&v).approx_eq(&(q * q_other).rotate(&v)) && rotor.reverse().rotate(&rotor.rotate(&v)).approx_eq(&v));
+            let (a, b) = (Vector3::new(1.0, 2.0, 2.0) / 3.0, Vector3::new(0.0, -0.6, 0.8));
+            assert!(Rotor3::from_vectors(&a, &b).rotate(&a).approx_eq(&b));
+            // Opposite vectors take a half turn
+            assert!(Rotor3::from_vectors(&a, &-a).rotate(&a).approx_eq(&-a));
+            assert!(Rotor3::from_vectors(&Vector3d::up(), &Vector3::down()).rotate(&Vector3::up()).approx_eq(&Vector3::down()));
+
+            // Wedge products: the plane of two vectors is dual to their cross product, and three give the volume
+            assert!(Vector3::from_bivector3(&(a ^ b)).approx_eq(&(a / b)) && (Vector2::<f64>::right() ^ Vector2::forth()).xy() == 1.0);
+            let c = Vector3::new(0.5, 1.0, -2.0);
+            assert!((((a ^ b) ^ c).xyz() - Matrix3::from_vector3(a, b, c).determinant()).abs() < 1.0e-12 && ((a ^ b) ^ c) == (c ^ (a ^ b)));
+            let (dot, wedge) = Vector3::product_geometric(&a, &b);
+            assert!((dot - a * b).abs() < 1.0e-12 && wedge == (a ^ b) && (b ^ a) == -(a ^ b));
+        }
+
+        #[test]
+        fn precision_casts() {
+            // Widening is exact (and available through Into), narrowing rounds to the nearest f32
+            let v = Vector3f::new(0.1, -2.5, 1.0e30);
+            let wide: Vector3d = v.into();
+            assert!(wide == Vector3d::new(0.1f32 as f64, -2.5, 1.0e30f32 as f64) && wide.cast::<f32>() == v);
+            let m = Matrix4f::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization()) * Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+            assert!(Matrix4d::from(m).cast::<f32>() == m && Quaterniond::from(Quaternionf::rotation_z(Deg(30.0))).cast::<f32>() == Quaternionf::rotation_z(Deg(30.0)));
+            assert!(Vector3d::new(0.1, 1.0 / 3.0, 2.0).cast::<f32>() == Vector3f::new(0.1, 1.0 / 3.0, 2.0));
+            // Large-world positions keep a millimetre in f64 and lose it in f32
+            let far = Vector3d::new(1.0e7, 0.0, 0.0) + Vector3d::new(0.001, 0.0, 0.0);
+            assert!((far.x() - 1.0e7 - 0.001).abs() < 1.0e-9 && far.cast::<f32>().x() == 1.0e7);
+            // The operators are the same in both precisions
+            let (a, b) = (Vector3f::new(1.0, 2.0, 3.0), Vector3f::new(-1.0, 0.5, 2.0));
+            assert!(Vector3d::from(a / b).approx_eq(&(Vector3d::from(a) / Vector3d::from(b))) && (a * b) as f64 == Vector3d::from(a) * Vector3d::from(b));
+        }
+
+        #[test]
+        fn matrix_vector_products() {
+            // Rows dotted with a column vector, or a row vector dotted with the columns
+            let m2 = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+            assert!(m2 * Vector2::new(1.0, -1.0) == Vector2::new(-1.0, -1.0) && Vector2::new(1.0, -1.0) * m2 == Vector2::new(-2.0, -2.0));
+            let m3 = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+            let v3 = Vector3::new(1.0, 0.0, -1.0);
+            assert!(m3 * v3 == Vector3::new(-2.0, -2.0, -3.0) && v3 * m3 == m3.transpose() * v3 && v3 * m3 == Vector3::new(-6.0, -6.0, -7.0));
+            let m4 = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::rotation_z(Deg(90.0));
+            assert!((m4 * Vector4::new(1.0, 0.0, 0.0, 1.0)).approx_eq(&Vector4::new(1.0, 3.0, 3.0, 1.0)));
+            assert!((Vector4::new(1.0, 0.0, 0.0, 1.0) * m4).approx_eq(&(m4.transpose() * Vector4::new(1.0, 0.0, 0.0, 1.0))));
+            // Products of products agree with the matrix product
+            assert!((m3 * (m3 * v3)) == (m3 * m3) * v3);
+
+            // Points are translated, directions are not
+            let p = Vector3::new(1.0, 0.0, 0.0);
+            assert!(m4.transform_point(&p).approx_eq(&Vector3::new(1.0, 3.0, 3.0)) && m4.transform_direction(&p).approx_eq(&Vector3::forth()));
+            // Projection: w is the view depth, and the divide lands the near plane at depth -1
+            let projection = Matrix4::perspective(Deg(90.0), 1.0, 1.0, 10.0);
+            let clip = projection.transform_point_homogeneous(&Vector3::new(1.0, 0.5, -2.0));
+            assert!((clip.w() - 2.0).abs() < 1.0e-12);
+            assert!(projection.transform_point(&Vector3::new(1.0, 0.5, -2.0)).approx_eq(&Vector3::new(clip.x() / 2.0, clip.y() / 2.0, clip.z() / 2.0)));
+            assert!((projection.transform_point(&Vector3::new(0.3, -0.2, -1.0)).z() + 1.0).abs() < 1.0e-12);
+        }
+
+        #[test]
+        fn approximate_comparison_modes() {
+            let (a, b) = (Vector2::new(1000.0, 0.0), Vector2::new(1000.5, 0.3));
+            // Coordinates are checked one by one, magnitudes on the difference vector as a whole
+            assert!(!a.approx_eq_eps(&b, 0.4, TestMode::AbsoluteCoordinates) && a.approx_eq_eps(&b, 0.5, TestMode::AbsoluteCoordinates));
+            assert!(!a.approx_eq_eps(&b, 0.5, TestMode::AbsoluteMagnitude) && a.approx_eq_eps(&b, 0.6, TestMode::AbsoluteMagnitude));
+            // Relative modes scale with the values, which absolute ones do not
+            assert!(a.approx_eq_eps(&b, 1.0e-3, TestMode::RelativeMagnitude) && !a.approx_eq_eps(&b, 1.0e-3, TestMode::RelativeCoordinates));
+            let (tiny, twice) = (Vector3::new(1.0e-8, 0.0, 0.0), Vector3::new(2.0e-8, 0.0, 0.0));
+            assert!(tiny.approx_eq_eps(&twice, 1.0e-6, TestMode::AbsoluteCoordinates) && !tiny.approx_eq_eps(&twice, 0.1, TestMode::RelativeCoordinates));
+            // Matrices compare like flat vectors, and == stays exact
+            let m = Matrix3::identity() * 100.0;
+            assert!(m.approx_eq_eps(&(m * (1.0 + 1.0e-6)), 1.0e-5, TestMode::RelativeMagnitude) && !m.approx_eq(&(m * (1.0 + 1.0e-6))));
+            assert!(Vector3::new(0.1, 0.2, 0.3) == Vector3::new(0.1, 0.2, 0.3) && Vector3::new(0.1 + 0.2, 0.0, 0.0) != Vector3::new(0.3, 0.0, 0.0));
+
+            // Direction queries (the _unit versions skip normalizing)
+            let (x, y) = (Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 3.0, 0.0));
+            assert!(Vector3::new(0.6, 0.8, 0.0).is_normalized() && !x.is_normalized());
+            assert!(Vector3::is_parallel(&x, &(x * 5.0)) && !Vector3::is_parallel(&x, &-x) && Vector3::is_anti_parallel(&x, &-x));
+            assert!(Vector3::is_collinear(&x, &-x) && !Vector3::is_collinear(&x, &y) && Vector3::is_orthogonal(&x, &y));
+            assert!(Vector3d::is_parallel_unit(&Vector3::right(), &Vector3::right()) && Vector3d::is_orthogonal_unit(&Vector3::right(), &Vector3::up()));
+            let nearly = Vector3::new(1.0, 1.0e-3, 0.0);
+            assert!(!Vector3::is_parallel(&x, &nearly) && Vector3::is_parallel_eps(&x, &nearly, 1.0e-6));
+        }
+
+        #[test]
+        fn indexing_and_array_conversions() {
+            let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+            assert!(v[0] == 1.0 && v[3] == 4.0);
+            v[2] = 7.0;
+            v.set_w(8.0);
+            assert!(v == Vector4::new(1.0, 2.0, 7.0, 8.0) && v.iter().sum::<f64>() == 18.0);
+            for a in v.iter_mut() { *a *= 2.0; }
+            assert!(<[f64; 4]>::from(v) == [2.0, 4.0, 14.0, 16.0] && Vector4::from([2.0, 4.0, 14.0, 16.0]) == v);
+
+            // m[c] is a column, m[(r, c)] an element, and arrays of arrays are arrays of columns
+            let mut m = Matrix3::new(
+                1.0, 2.0, 3.0,
+                4.0, 5.0, 6.0,
+                7.0, 8.0, 9.0);
+            assert!(m[1] == [2.0, 5.0, 8.0] && m[(0, 1)] == 2.0 && m[(2, 0)] == 7.0);
+            assert!(Matrix3::from([[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 9.0]]) == m && <[[f64; 3]; 3]>::from(m)[2] == [3.0, 6.0, 9.0]);
+            m[(1, 2)] = -6.0;
+            m[0][2] = -7.0;
+            m.set_row(0, Vector3::new(0.0, 0.0, 1.0));
+            m.set_element(1, 0, 10.0);
+            assert!(m == Matrix3::new(0.0, 0.0, 1.0, 10.0, 5.0, -6.0, -7.0, 8.0, 9.0) && m.iter().copied().eq(m.to_cols_array().iter().copied()));
+            m.set_column(1, Vector3::new(1.0, 1.0, 1.0));
+            assert!(m.column(1) == Vector3::new(1.0, 1.0, 1.0) && m.row(2) == Vector3::new(-7.0, 1.0, 9.0));
+        }
+
+        #[test]
+        fn typed_angles() {
+            use std::f64::consts::{ PI, FRAC_PI_2 };
+            assert!((Rad::from(Deg(180.0)).0 - PI).abs() < 1.0e-15 && (Rad(FRAC_PI_2).to_deg().0 - 90.0).abs() < 1.0e-12);
+            assert!(Deg(30.0) + Deg(60.0) == Deg(90.0) && Deg(90.0) * 2.0 == Deg(180.0) && -Deg(45.0) == Deg(-45.0) && Deg(90.0) / Deg(30.0) == 3.0);
+            assert!((Deg(30.0).sin() - 0.5).abs() < 1.0e-12 && (Rad::atan2(1.0, 1.0).to_deg().0 - 45.0).abs() < 1.0e-12);
+            // Wrapping into [0, 360) and (-180, 180]
+            assert!(Deg(370.0).normalization() == Deg(10.0) && Deg(-90.0).normalization() == Deg(270.0));
+            assert!(Deg(270.0).normalization_signed() == Deg(-90.0) && Deg(180.0).normalization_signed() == Deg(180.0));
+            assert!((Rad(-0.5).normalization().0 - (2.0 * PI - 0.5)).abs() < 1.0e-12);
+
+            // Every angle-taking constructor reads degrees and radians alike
+            let (deg, rad) = (Deg(90.0), Rad(FRAC_PI_2));
+            assert!(Matrix4::rotation(deg, Vector3::up()).approx_eq(&Matrix4::rotation(rad, Vector3::up())) && Matrix4::rotation_x(deg).approx_eq(&Matrix4::rotation_x(rad)));
+            assert!(Matrix4::perspective(deg, 1.5, 0.1, 10.0).approx_eq(&Matrix4::perspective(rad, 1.5, 0.1, 10.0)));
+            assert!(same_rotation(&Quaternion::rotation_z(deg), &Quaternion::rotation_z(rad)) && same_rotation(&Quaternion::from_euler(deg, deg, deg), &Quaternion::from_euler(rad, rad, rad)));
+            assert!(Complex::from_rotor(deg).approx_eq(&Complex::i()) && Vector2::from_polar(deg, 2.0).approx_eq(&Vector2::new(0.0, 2.0)));
+            assert!((Vector3d::angle(&Vector3::right(), &Vector3::up()) - rad.0).abs() < 1.0e-12);
+        }
+
+        #[test]
+        fn inverse_is_two_sided() {
+            let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization()) * Matrix4::scale_uniform(2.0);
+            assert!((m * m.inverse()).approx_eq(&Matrix4::identity()));
+            assert!((m.inverse() * m).approx_eq(&Matrix4::identity()));
+        }
+
+        #[test]
+        fn camera_matrices() {
+            // The eye goes to the origin and the target onto -z (right-handed) or +z (left-handed), up stays up
+            let (eye, target) = (Vector3::new(3.0, -4.0, 2.0), Vector3::new(0.0, 0.0, 2.0));
+            let rh = Matrix4::look_at_rh(eye, target, Vector3::up());
+            assert!(rh.transform_point(&eye).approx_eq(&Vector3::zero()) && rh.transform_point(&target).approx_eq(&Vector3::new(0.0, 0.0, -5.0)));
+            assert!(rh.transform_direction(&Vector3::up()).approx_eq(&Vector3::forth()) && (rh * rh.inverse_orthonormal()).approx_eq(&Matrix4::identity()));
+            let lh = Matrix4::look_at_lh(eye, target, Vector3::up());
+            assert!(lh.transform_point(&target).approx_eq(&Vector3::new(0.0, 0.0, 5.0)) && lh.transform_direction(&Vector3::up()).approx_eq(&Vector3::forth()));
+
+            // The view volume lands on the [-1, 1] cube, near at depth -1
+            let ortho = Matrix4::orthographic(-2.0, 4.0, -1.0, 1.0, 0.5, 10.0);
+            assert!(ortho.transform_point(&Vector3::new(-2.0, -1.0, -0.5)).approx_eq(&Vector3::new(-1.0, -1.0, -1.0)));
+            assert!(ortho.transform_point(&Vector3::new(4.0, 1.0, -10.0)).approx_eq(&Vector3::new(1.0, 1.0, 1.0)));
+            let off_centre = Matrix4::frustum(-0.1, 0.3, -0.1, 0.1, 0.1, 100.0);
+            assert!(off_centre.transform_point(&Vector3::new(0.3, -0.1, -0.1)).approx_eq(&Vector3::new(1.0, -1.0, -1.0)));
+            assert!(off_centre.transform_point(&Vector3::new(-100.0, 100.0, -100.0)).approx_eq(&Vector3::new(-1.0, 1.0, 1.0)));
+            // A symmetric frustum is the plain perspective, and the infinite one is its limit
+            let fov = Deg(90.0);
+            assert!(Matrix4::frustum(-0.1, 0.1, -0.1, 0.1, 0.1, 100.0).approx_eq(&Matrix4::perspective(fov, 1.0, 0.1, 100.0)));
+            assert!(Matrix4::perspective(fov, 1.0, 0.1, 1.0e12).approx_eq_eps(&Matrix4::perspective_infinite(fov, 1.0, 0.1), 1.0e-9, TestMode::AbsoluteCoordinates));
+            // Reversed-Z puts near at 1 and far at 0 (or infinity at 0)
+            let reversed = Matrix4::perspective_reversed_z(fov, 1.0, 0.1, 100.0);
+            assert!((reversed.transform_point(&Vector3::new(0.0, 0.0, -0.1)).z() - 1.0).abs() < 1.0e-12 && reversed.transform_point(&Vector3::new(0.0, 0.0, -100.0)).z().abs() < 1.0e-12);
+            let infinite = Matrix4::perspective_infinite_reversed_z(fov, 1.0, 0.1);
+            assert!((infinite.transform_point(&Vector3::new(0.0, 0.0, -0.1)).z() - 1.0).abs() < 1.0e-12 && infinite.transform_point(&Vector3::new(0.0, 0.0, -1.0e9)).z() < 1.0e-9);
+        }
+
+        #[test]
+        fn screen_space_depth_ranges() {
+            let eye = Vector3::new(0.0, -5.0, 2.0);
+            let view = Matrix4::look_at_rh(eye, Vector3::zero(), Vector3::up());
+            let forward = (Vector3::zero() - eye).normalization();
+            let viewport = Vector4::new(0.0, 0.0, 800.0, 600.0);
+            let p = Vector3::new(0.5, 1.0, -0.3);
+            let projections = [
+                (Matrix4::perspective(Deg(60.0), 4.0 / 3.0, 0.1, 100.0), DepthRange::NegativeOneToOne),
+                (Matrix4::perspective_infinite(Deg(60.0), 4.0 / 3.0, 0.1), DepthRange::NegativeOneToOne),
+                (Matrix4::perspective_reversed_z(Deg(60.0), 4.0 / 3.0, 0.1, 100.0), DepthRange::ZeroToOne),
+                (Matrix4::perspective_infinite_reversed_z(Deg(60.0), 4.0 / 3.0, 0.1), DepthRange::ZeroToOne)];
+            for &(projection, depth) in projections.iter() {
+                let view_projection = projection * view;
+                let window = Matrix4::project(&p, &view_projection, &viewport, depth);
+                assert!(window.z > 0.0 && window.z < 1.0);
+                assert!(Matrix4::unproject(&window, &view_projection, &viewport, depth).unwrap().approx_eq_eps(&p, 1.0e-9, TestMode::AbsoluteCoordinates));
+                // Starts on the near plane and heads away from the eye through p, whichever way the depth runs
+                let (origin, direction) = Matrix4::unproject_ray(window.x, window.y, &view_projection, &viewport, depth).unwrap();
+                assert!(((origin - eye) * forward - 0.1).abs() < 1.0e-9 && direction.approx_eq(&(p - eye).normalization()));
+            }
+            // A degenerate projection has nothing to unproject through
+            let flat = Matrix4::scale_vector(Vector3::new(1.0, 1.0, 0.0)) * view;
+            assert!(Matrix4::unproject(&Vector3::new(400.0, 300.0, 0.5), &flat, &viewport, DepthRange::NegativeOneToOne).is_none());
+            assert!(Matrix4::unproject_ray(400.0, 300.0, &flat, &viewport, DepthRange::NegativeOneToOne).is_none());
+        }
+
+        #[test]
+        fn euler_angles_round_trip() {
+            let m = Matrix3::from_quaternion(&Quaternion::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization()));
+            for &order in EulerOrder::all().iter() {
+                let e = EulerAngles::from_matrix3(&m, order);
+                assert!(e.to_matrix3().approx_eq(&m), "{}", e.to_string());
+                // Gimbal lock: b at the singularity, a and c only defined together
+                let b = if order.is_proper() { Rad(0.0) } else { Rad::half_turn() * 0.5 };
+                let locked = EulerAngles::new(Rad(0.4), b, Rad(0.9), order);
+                let e = EulerAngles::from_matrix3(&locked.to_matrix3(), order);
+                assert!(e.is_gimbal_locked() && e.approx_eq(&locked), "{}", e.to_string());
+            }
+            assert!(EulerAngles::new(Rad(0.4), Rad(0.5), Rad(0.6), EulerOrder::XYZ).to_matrix3().approx_eq(&Matrix3::from_quaternion(&Quaternion::from_euler(Rad(0.4), Rad(0.5), Rad(0.6)))));
+            let (yaw, pitch, roll) = EulerAngles::from_yaw_pitch_roll(Deg(30.0), Deg(-20.0), Deg(10.0)).to_yaw_pitch_roll();
+            assert!((yaw.to_deg().0 - 30.0).abs() < 1.0e-4 && (pitch.to_deg().0 + 20.0).abs() < 1.0e-4 && (roll.to_deg().0 - 10.0).abs() < 1.0e-4);
+        }
+
+        #[test]
+        fn transform_round_trip() {
+            let a = Transform::new(Vector3::new(1.0, 2.0, 3.0), Quaternion::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization()), Vector3::new(2.0, 2.0, 2.0));
+            let b = Transform::new(Vector3::new(-4.0, 0.5, 1.0), Quaternion::rotation_z(Deg(30.0)), Vector3::new(1.0, 3.0, 0.5));
+            assert!((a * b).to_matrix4().approx_eq(&(a.to_matrix4() * b.to_matrix4())));
+            assert!((a * a.inverse()).approx_eq(&Transform::identity()));
+            assert!(Transform::from_matrix4(&b.to_matrix4()).approx_eq(&b));
+            // Mirrored
+            let c = Transform::new(Vector3::zero(), Quaternion::rotation_x(Deg(40.0)), Vector3::new(-1.0, -2.0, -3.0));
+            assert!(Transform::from_matrix4(&c.to_matrix4()).approx_eq(&c));
+            // Shear is dropped, but the rotation and the translation survive
+            let shear = Matrix4::new(
+                1.0, 0.5, 0.0, 7.0,
+                0.0, 1.0, 0.0, 8.0,
+                0.0, 0.0, 1.0, 9.0,
+                0.0, 0.0, 0.0, 1.0);
+            let (q, p) = Matrix3::from_rows_array([1.0, 0.5, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]).polar_decomposition();
+            assert!((q * p).approx_eq(&Matrix3::from_rows_array([1.0, 0.5, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0])));
+            assert!((q * q.transpose()).approx_eq(&Matrix3::identity()) && p.approx_eq(&p.transpose()));
+            assert!(Transform::from_matrix4(&shear).translation().approx_eq(&Vector3::new(7.0, 8.0, 9.0)));
+        }
+
+        #[test]
+        fn transform_with_collapsed_scale() {
+            // A zero scale leaves nothing to invert, but the other axes still pin down the rotation
+            let rotation = Quaternion::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization());
+            let flat = Transform::new(Vector3::new(1.0, 2.0, 3.0), rotation, Vector3::new(2.0, 0.0, 3.0));
+            let recovered = Transform::from_matrix4(&flat.to_matrix4());
+            assert!(recovered.approx_eq(&flat) && recovered.to_matrix4().approx_eq(&flat.to_matrix4()));
+            // With a single axis left (or none) the rotation is a guess, but still a rotation that rebuilds the matrix
+            for scale in [Vector3::new(0.0, 0.0, 3.0), Vector3::new(-2.0, 0.0, 3.0), Vector3::zero()].iter() {
+                let m = Transform::new(Vector3::new(1.0, 2.0, 3.0), rotation, *scale).to_matrix4();
+                let (q, p) = Matrix3::from_matrix4(&m).polar_decomposition();
+                assert!((q * q.transpose()).approx_eq(&Matrix3::identity()) && (q.determinant() - 1.0).abs() < 1e-9);
+                assert!((q * p).approx_eq(&Matrix3::from_matrix4(&m)) && Transform::from_matrix4(&m).to_matrix4().approx_eq(&m));
+            }
+        }
+
+        #[test]
+        fn decompositions_rebuild_the_matrix() {
+            let m = Matrix4::new(
+                0.0, 2.0, 1.0, 4.0,
+                1.0, 1.0, 0.5, -2.0,
+                3.0, 0.0, 2.0, 1.0,
+                -1.0, 4.0, 0.0, 3.0);
+            let lu = m.decomposition_lu();
+            assert!((lu.p() * m).approx_eq(&(lu.l() * lu.u())));
+            assert!((lu.determinant() - m.determinant()).abs() < 1.0e-9);
+            assert!((m * lu.inverse().unwrap()).approx_eq(&Matrix4::identity()));
+            let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+            assert!((m * m.solve(&b).unwrap()).approx_eq(&b));
+            assert!(Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).solve(&Vector3::one()).is_none());
+
+            let (q, r) = m.decomposition_qr();
+            assert!((q * r).approx_eq(&m) && (q.transpose() * q).approx_eq(&Matrix4::identity()));
+            assert!(r.element(1, 0) == 0.0 && r.element(3, 2) == 0.0);
+
+            // Symmetric positive definite (an inertia tensor)
+            let s = Matrix3::new(
+                4.0, 1.0, 0.5,
+                1.0, 3.0, 0.2,
+                0.5, 0.2, 2.0);
+            let l = s.decomposition_cholesky().unwrap();
+            assert!((l * l.transpose()).approx_eq(&s));
+            assert!((s * -1.0).decomposition_cholesky().is_none());
+
+            let (values, vectors) = s.eigen_symmetric();
+            for i in 0..3 {
+                assert!((s * vectors.column(i)).approx_eq(&(vectors.column(i) * values[i])));
+            }
+            assert!(values[0] >= values[1] && values[1] >= values[2]);
+
+            let a = Matrix3::new(
+                1.0, -2.0, 0.3,
+                0.5, 4.0, 1.0,
+                -3.0, 0.0, 2.0);
+            let (u, sigma, v) = a.decomposition_svd();
+            let sigma_m = Matrix3::new(sigma.x(), 0.0, 0.0, 0.0, sigma.y(), 0.0, 0.0, 0.0, sigma.z());
+            assert!((u * sigma_m * v.transpose()).approx_eq_eps(&a, 1.0e-9, TestMode::AbsoluteCoordinates));
+            assert!((u.transpose() * u).approx_eq(&Matrix3::identity()));
+            // Rank 1
+            let (u, sigma, v) = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0).decomposition_svd();
+            assert!(sigma.y().abs() < 1.0e-6 && (u.transpose() * u).approx_eq(&Matrix3::identity()));
+            assert!((u * Matrix3::new(sigma.x(), 0.0, 0.0, 0.0, sigma.y(), 0.0, 0.0, 0.0, sigma.z()) * v.transpose()).approx_eq_eps(&Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0), 1.0e-6, TestMode::AbsoluteCoordinates));
+        }
+
+        #[test]
+        fn dynamic_matches_fixed_size() {
+            let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization());
+            let n = MatrixN::from(m);
+            let v = VectorN::from(Vector4::new(1.0, -2.0, 0.0, 1.0));
+            assert!((&n * &v).approx_eq(&VectorN::from(m * Vector4::new(1.0, -2.0, 0.0, 1.0))));
+            assert!((&n * &n).approx_eq(&MatrixN::from(m * m)));
+            assert!((-n.clone()).approx_eq(&MatrixN::from(m.transpose())));
+            assert!((!n.clone()).unwrap().approx_eq(&MatrixN::from(m.inverse())));
+            assert!((n.determinant().unwrap() - m.determinant()).abs() < 1.0e-9);
+            assert!((&n + &n).approx_eq(&(n.clone() * 2.0)) && (&n - &n).approx_eq(&MatrixN::zero(4, 4)));
+            assert_eq!(MatrixN::from(Matrix2::new(1.0, 2.0, 3.0, 4.0)).to_string(), "[[1, 2], [3, 4]]");
+
+            // 6x6 system
+            let mut a = MatrixN::identity(6) * 4.0;
+            for i in 0..5 { a[(i, i + 1)] = -1.0; a[(i + 1, i)] = -1.0; }
+            let b = VectorN::from(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+            let x = a.solve(&b).unwrap();
+            assert!((&a * &x).approx_eq(&b));
+            assert_eq!(a.count_nonzero(), 16);
+            assert!(a.iter_nonzero().all(|(r, c, value)| a[(r, c)] == value && value != 0.0));
+
+            // Line fit y = 2x + 1 through points with symmetric noise
+            let xs = [0.0, 1.0, 2.0, 3.0];
+            let ys = [1.1, 2.9, 5.1, 6.9];
+            let mut design = MatrixN::new(4, 2);
+            for i in 0..4 { design[(i, 0)] = xs[i]; design[(i, 1)] = 1.0; }
+            let fit = design.solve_least_squares(&VectorN::from_slice(&ys)).unwrap();
+            assert!((fit[0] - 1.96).abs() < 1.0e-9 && (fit[1] - 1.06).abs() < 1.0e-9);
+
+            // Non-square matrices have no inverse, a singular one has a zero determinant, and NaN is not multiplied away
+            assert!(design.solve(&VectorN::from_slice(&ys)).is_none() && design.determinant().is_none() && design.inverse().is_none());
+            assert!(MatrixN::<f64>::zero(3, 3).determinant() == Some(0.0) && MatrixN::<f64>::zero(3, 3).inverse().is_none());
+            let mut nan = MatrixN::identity(2);
+            nan[(0, 0)] = f64::NAN;
+            assert!((&MatrixN::<f64>::zero(2, 2) * &nan)[(0, 0)].is_nan() && (&nan * &VectorN::zero(2))[0].is_nan());
+        }
+
+        #[test]
+        fn fallible_and_fast_inverses() {
+            let singular = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+            assert!(!singular.is_invertible() && singular.try_inverse().is_none());
+            assert!(singular.condition_number() > 1.0e12);
+            let tiny = Matrix3::identity() * 1.0e-4;
+            assert!((tiny * tiny.try_inverse().unwrap()).approx_eq(&Matrix3::identity()));
+            let nearly = Matrix2::new(1.0, 1.0, 1.0, 1.0 + 1.0e-14);
+            assert!(nearly.try_inverse().is_none() && nearly.try_inverse_eps(1.0e-15).is_some());
+            // Translation only weighs in once: a camera far from the origin is no closer to singular
+            let eye = Vector3::new(0.0f32, -500.0, 200.0);
+            let far = Matrix4::perspective(Deg(60.0), 4.0 / 3.0, 0.1, 1000.0) * Matrix4::look_at_rh(eye, Vector3::zero(), Vector3::up());
+            assert!(far.try_inverse().is_some());
+
+            let r = Matrix4::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization());
+            assert!((r.condition_number() - 1.0).abs() < 1.0e-9);
+            assert!((Matrix2::new(2.0, 0.0, 0.0, 0.5).condition_number() - 4.0).abs() < 1.0e-9);
+
+            // The pseudo-inverse is the inverse when there is one, and satisfies A * A+ * A = A when there is not
+            let m = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+            assert!(m.pseudo_inverse().approx_eq_eps(&m.inverse(), 1.0e-9, TestMode::AbsoluteCoordinates));
+            let p = singular.pseudo_inverse();
+            assert!((singular * p * singular).approx_eq_eps(&singular, 1.0e-9, TestMode::AbsoluteCoordinates));
+            assert!((p * singular * p).approx_eq_eps(&p, 1.0e-9, TestMode::AbsoluteCoordinates));
+
+            let t = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+            let s = Matrix4::scale_vector(Vector3::new(2.0, 0.5, 3.0));
+            assert!((t * r).inverse_orthonormal().approx_eq(&(t * r).inverse()));
+            assert!((t * r * s).inverse_affine().approx_eq(&(t * r * s).inverse()));
+            assert!((t * r * s).try_inverse_affine().unwrap().approx_eq(&(t * r * s).inverse()));
+            let flat = t * Matrix4::scale_vector(Vector3::new(2.0, 0.0, 3.0));
+            assert!(flat.try_inverse_affine().is_none() && Matrix3::from_matrix4(&flat.inverse_affine()) == Matrix3::zero());
+            let view = Matrix4::look_at_rh(Vector3::new(0.0, -5.0, 2.0), Vector3::zero(), Vector3::up());
+            assert!((view * view.inverse_orthonormal()).approx_eq(&Matrix4::identity()));
+        }
+
+        #[test]
+        fn ill_conditioned_matrices() {
+            // Singular values 1, 1e-3, 1e-9: M^T * M would have 1e-18 next to 1, beyond what f64 resolves
+            let r = Matrix3::from_matrix4(&Matrix4::rotation(Rad(0.7), Vector3::new(1.0, 0.3, 0.5).normalization()));
+            let m = r * Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0e-3, 0.0, 0.0, 0.0, 1.0e-9) * r.transpose();
+            assert!((m.condition_number() / 1.0e9 - 1.0).abs() < 1.0e-6);
+            let exact = r * Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0e3, 0.0, 0.0, 0.0, 1.0e9) * r.transpose();
+            assert!(m.pseudo_inverse().approx_eq_eps(&exact, 1.0e-6, TestMode::RelativeMagnitude));
+            let (u, sigma, v) = m.decomposition_svd();
+            assert!((sigma.z() / 1.0e-9 - 1.0).abs() < 1.0e-6);
+            assert!((u * Matrix3::new(sigma.x(), 0.0, 0.0, 0.0, sigma.y(), 0.0, 0.0, 0.0, sigma.z()) * v.transpose()).approx_eq(&m));
+
+            let moderate = r * Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0e-2, 0.0, 0.0, 0.0, 1.0e-6) * r.transpose();
+            assert!((moderate * moderate.try_inverse().unwrap()).approx_eq_eps(&Matrix3::identity(), 1.0e-9, TestMode::AbsoluteCoordinates));
+        }
+
+        #[test]
+        fn refraction_and_fresnel() {
+            let (air, water, glass) = (Ior::Air.value::<f64>(), Ior::Water.value::<f64>(), Ior::GlassWindow.value::<f64>());
+            let n = Vector3::up();
+            let a = Vector3::new(1.0, 0.0, -1.0).normalization();
+            let t = Vector3::refraction_unit(&a, &n, air, water).unwrap();
+            // Snell: n1 * sin(t1) = n2 * sin(t2), and the refracted ray stays in the plane of incidence
+            assert!((air * a.x() - water * t.x()).abs() < 1.0e-9 && t.y() == 0.0 && t.z() < 0.0);
+            assert!(t.is_normalized());
+            // The normal may face either way
+            assert!(Vector3::refraction_unit(&a, &(-n), air, water).unwrap().approx_eq(&t));
+            assert!(Vector3::refraction(&(a * 3.0), &(n * 2.0), air, water).unwrap().approx_eq(&(t * 3.0)));
+            // Glass to air past the critical angle (about 41 degrees)
+            let steep = Vector3::new(Deg(60.0).sin(), 0.0, -Deg(60.0).cos());
+            assert!(Vector3::refraction_unit(&steep, &n, glass, air).is_none());
+            assert!(Vector3::is_total_internal_reflection(&steep, &n, glass, air));
+            assert_eq!(Vector3::fresnel_dielectric(&steep, &n, glass, air), 1.0);
+
+            // Normal incidence: both give ((n1 - n2) / (n1 + n2))^2, about 4% for glass
+            let r0 = ((air - glass) / (air + glass)).powi(2);
+            assert!((num::fresnel_dielectric(1.0, air, glass) - r0).abs() < 1.0e-9);
+            assert!((num::fresnel_schlick(1.0, air, glass) - r0).abs() < 1.0e-9);
+            // Grazing incidence reflects everything
+            assert!((num::fresnel_dielectric(0.0, air, glass) - 1.0).abs() < 1.0e-9);
+            assert!((num::fresnel_schlick(0.3, air, glass) - num::fresnel_dielectric(0.3, air, glass)).abs() < 0.02);
+            assert_eq!(Ior::Diamond.to_f32(), IOR_diamond);
+        }
+
+        #[test]
+        fn complex_arithmetic() {
+            let a = Complex::new(1.0, 2.0);
+            let b = Complex::new(3.0, -1.0);
+            assert_eq!(a * b, Complex::new(5.0, 5.0));
+            assert!(((a * b) / b).approx_eq(&a) && (a * !a).approx_eq(&Complex::one()));
+            assert_eq!(Complex::i() * Complex::i(), Complex::new(-1.0, 0.0));
+            assert_eq!(-a, a.conjugate());
+
+            let (angle, radius) = a.to_polar();
+            assert!(Complex::from_polar(angle, radius).approx_eq(&a));
+            assert!(a.ln().exp().approx_eq(&a) && a.exp().ln().approx_eq(&a));
+            assert!((a.sqrt() * a.sqrt()).approx_eq(&a) && (b.sqrt() * b.sqrt()).approx_eq(&b));
+            assert!(Complex::new(-4.0, 0.0).sqrt().approx_eq(&Complex::new(0.0, 2.0)));
+            assert!(a.powf(3.0).approx_eq(&(a * a * a)) && a.powc(&Complex::new(2.0, 0.0)).approx_eq(&(a * a)));
+            // Zero to the zero is one, as for real numbers, and zero to a negative power is infinite
+            let zero = Complex::<f64>::zero();
+            assert!(zero.powf(0.0) == Complex::one() && zero.powc(&zero) == Complex::one());
+            assert!(zero.powf(2.0) == zero && zero.powc(&Complex::new(2.0, 1.0)) == zero && a.powf(0.0) == Complex::one());
+            assert!(zero.powf(-1.0) == Complex::new(f64::INFINITY, 0.0) && zero.powc(&Complex::new(-1.0, 0.0)).real().is_infinite());
+            // Euler's identity
+            assert!(Complex::new(0.0, std::f64::consts::PI).exp().approx_eq(&Complex::new(-1.0, 0.0)));
+            assert!((a.sin() * a.sin() + a.cos() * a.cos()).approx_eq(&Complex::one()));
+            assert!((a.cosh() * a.cosh() - a.sinh() * a.sinh()).approx_eq(&Complex::one()));
+            let roots = Complex::<f64>::roots_of_unity(8);
+            assert!(roots.iter().all(|r| r.powf(8.0).approx_eq(&Complex::one())) && roots[2].approx_eq(&Complex::i()));
+
+            // 2D rotation
+            let v = Vector2::new(1.0, 0.0);
+            assert!((Complex::from_rotor(Deg(90.0)) * v).approx_eq(&Vector2::new(0.0, 1.0)));
+            assert!(v.rotation(Deg(90.0)).approx_eq(&(Matrix2::from_complex(&Complex::from_rotor(Deg(90.0))) * v)));
+            let w = Vector2::new(-2.0, 2.0);
+            assert!((Complex::rotation_between(&v, &w) * v).approx_eq(&w.normalization()));
+            let half = Complex::slerp(&Complex::one(), &Complex::from_rotor(Deg(90.0)), 0.5);
+            assert!(half.approx_eq(&Complex::from_rotor(Deg(45.0))));
+        }
+
+        #[test]
+        fn row_and_column_order() {
+            let m = Matrix3::new(
+                1.0, 2.0, 3.0,
+                4.0, 5.0, 6.0,
+                7.0, 8.0, 9.0);
+            assert_eq!(m.to_rows_array(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            assert_eq!(m.to_cols_array(), [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+            assert_eq!(Matrix3::from_rows_array(m.to_rows_array()), m);
+            assert_eq!(Matrix3::from_cols_array(m.to_cols_array()), m);
+            assert_eq!(m.row(0), Vector3::new(1.0, 2.0, 3.0));
+            assert_eq!(m.column(0), Vector3::new(1.0, 4.0, 7.0));
+            assert_eq!(m.to_string(), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+        }
+    }
+}
+
+// Geometric primitives (rays, planes, bounding volumes and frusta)
+pub mod geometry;
+
+// Curves and splines (Bezier, Hermite, Catmull-Rom, B-spline) with arc-length parametrization
+pub mod curves;
@@ -0,0 +1,486 @@
+// Parametric curves and splines (Bezier, Hermite, Catmull-Rom, B-spline) over the linalg vector types
+//      Guidance: https://pomax.github.io/bezierinfo/
+//      B-splines follow Piegl and Tiller, "The NURBS Book" (Springer, 1997)
+use super::linalg::*;
+
+/*
+    Curve conventions:
+    - Constructors taking point lists return None when there are too few points: a Bezier curve needs one, a
+      Catmull-Rom spline four, and a B-spline more than its degree (with points + degree + 1 knots).
+    - Curves run over a parameter t in domain(): [0, 1] for Bezier and Hermite curves, one unit per segment for
+      Catmull-Rom splines and B-splines built from point lists ([0, segments]). Parameters outside are clamped.
+    - Derivatives are with respect to t (velocities, not unit tangents).
+    - Bounds are the tight per-axis (min, max) of the curve itself, not of its control points.
+    - Quaternion curves blend the four components like nlerp, so normalize what point_at() returns before rotating
+      with it. Keep neighbouring keys in the same hemisphere (q * r >= 0), or the curve takes the long way round.
+    - Arc length is reparametrized through a lookup table: sample it once, then map distances to parameters.
+*/
+
+// Struct Definitions
+//      The spaces a curve can run through: a vector space with a dot product (for lengths and closest points)
+pub trait CurvePoint: Copy + Add<Output = Self> + Sub<Output = Self> {
+    type Scalar: Scalar;
+    const DIMENSION: usize;
+    fn zero() -> Self;
+    fn scale(&self, s: Self::Scalar) -> Self;
+    fn dot(a: &Self, b: &Self) -> Self::Scalar;
+    fn component(&self, n: usize) -> Self::Scalar;
+    fn set_component(&mut self, n: usize, a: Self::Scalar);
+}
+//      Bernstein polynomial of any degree (degree = number of points - 1)
+#[derive(Debug, Clone, PartialEq)] pub struct Bezier<P: CurvePoint = Vector3>      { points: Vec<P> }
+//      Cubic from end points and end tangents
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Hermite<P: CurvePoint = Vector3> { p0: P, m0: P, p1: P, m1: P }
+//      Interpolates points[1..n-1] (the first and last points only shape the end tangents).
+//      alpha picks the knot spacing: 0 uniform, 0.5 centripetal (no cusps or self-intersections within a segment), 1 chordal.
+//      Only uniform splines have a continuous derivative. The others keep the tangent direction but not its length.
+#[derive(Debug, Clone, PartialEq)] pub struct CatmullRom<P: CurvePoint = Vector3>  { points: Vec<P>, alpha: P::Scalar }
+//      Piecewise polynomial with a knot vector (knots = points + degree + 1, non-decreasing)
+#[derive(Debug, Clone, PartialEq)] pub struct BSpline<P: CurvePoint = Vector3>     { points: Vec<P>, knots: Vec<P::Scalar>, degree: usize }
+//      Cumulative arc length at evenly spaced parameters
+#[derive(Debug, Clone, PartialEq)] pub struct ArcLengthTable<T: Scalar = f32>      { parameters: Vec<T>, lengths: Vec<T> }
+
+// Precision aliases (the bare names default to f32 Vector3)
+pub type Bezier2f       = Bezier<Vector2<f32>>;         pub type Bezier2d       = Bezier<Vector2<f64>>;
+pub type Bezier3f       = Bezier<Vector3<f32>>;         pub type Bezier3d       = Bezier<Vector3<f64>>;
+pub type Hermite2f      = Hermite<Vector2<f32>>;        pub type Hermite2d      = Hermite<Vector2<f64>>;
+pub type Hermite3f      = Hermite<Vector3<f32>>;        pub type Hermite3d      = Hermite<Vector3<f64>>;
+pub type CatmullRom2f   = CatmullRom<Vector2<f32>>;     pub type CatmullRom2d   = CatmullRom<Vector2<f64>>;
+pub type CatmullRom3f   = CatmullRom<Vector3<f32>>;     pub type CatmullRom3d   = CatmullRom<Vector3<f64>>;
+pub type BSpline2f      = BSpline<Vector2<f32>>;        pub type BSpline2d      = BSpline<Vector2<f64>>;
+pub type BSpline3f      = BSpline<Vector3<f32>>;        pub type BSpline3d      = BSpline<Vector3<f64>>;
+pub type ArcLengthTablef = ArcLengthTable<f32>;         pub type ArcLengthTabled = ArcLengthTable<f64>;
+
+// Control point spaces
+impl<T: Scalar> CurvePoint for Vector2<T> { type Scalar = T; const DIMENSION: usize = 2;
+    fn zero() -> Self { return Vector2::zero(); }
+    fn scale(&self, s: T) -> Self { return (*self) * s; }
+    fn dot(a: &Self, b: &Self) -> T { return (*a) * (*b); }
+    fn component(&self, n: usize) -> T { return self[n]; }
+    fn set_component(&mut self, n: usize, a: T) { self[n] = a; } }
+impl<T: Scalar> CurvePoint for Vector3<T> { type Scalar = T; const DIMENSION: usize = 3;
+    fn zero() -> Self { return Vector3::zero(); }
+    fn scale(&self, s: T) -> Self { return (*self) * s; }
+    fn dot(a: &Self, b: &Self) -> T { return (*a) * (*b); }
+    fn component(&self, n: usize) -> T { return self[n]; }
+    fn set_component(&mut self, n: usize, a: T) { self[n] = a; } }
+impl<T: Scalar> CurvePoint for Vector4<T> { type Scalar = T; const DIMENSION: usize = 4;
+    fn zero() -> Self { return Vector4::zero(); }
+    fn scale(&self, s: T) -> Self { return (*self) * s; }
+    fn dot(a: &Self, b: &Self) -> T { return (*a) * (*b); }
+    fn component(&self, n: usize) -> T { return self[n]; }
+    fn set_component(&mut self, n: usize, a: T) { self[n] = a; } }
+//      Components in the order (i, j, k, s), like Vector4::from_quaternion
+impl<T: Scalar> CurvePoint for Quaternion<T> { type Scalar = T; const DIMENSION: usize = 4;
+    fn zero() -> Self { return Quaternion::zero(); }
+    fn scale(&self, s: T) -> Self { return (*self) * s; }
+    fn dot(a: &Self, b: &Self) -> T { return Quaternion::product_scalar(a, b); }
+    fn component(&self, n: usize) -> T { return Vector4::from_quaternion(self)[n]; }
+    fn set_component(&mut self, n: usize, a: T) { let mut v = Vector4::from_quaternion(self); v[n] = a; *self = Quaternion::from_vector4(&v); } }
+
+// Shared curve queries (everything past bounds() comes for free from the derivatives)
+pub trait Curve<P: CurvePoint> {
+    fn domain(&self) -> (P::Scalar, P::Scalar);
+    fn point_at(&self, t: P::Scalar) -> P;
+    fn derivative_at(&self, t: P::Scalar) -> P;
+    fn second_derivative_at(&self, t: P::Scalar) -> P;
+    fn bounds(&self) -> (P, P);
+
+    fn arc_length_table(&self, samples: usize) -> ArcLengthTable<P::Scalar> { return ArcLengthTable::new(self, samples); }
+    fn length(&self) -> P::Scalar { return self.arc_length_table(64).length(); }
+    fn point_at_distance(&self, table: &ArcLengthTable<P::Scalar>, s: P::Scalar) -> P { return self.point_at(table.parameter_at(s)); }
+    //  Parameter of the closest point: every local minimum of a dense sampling, polished by Newton's method on
+    //  (c(t) - p) * c'(t) = 0, and the best one kept
+    fn closest_parameter(&self, p: &P) -> P::Scalar {
+        let (a, b) = self.domain();
+        let distance_sqr = |t: P::Scalar| { let d = self.point_at(t) - *p; return P::dot(&d, &d); };
+        let samples = 16 * to_usize(P::Scalar::max((b - a).floor(), P::Scalar::ONE)) + 1;
+        let ts: Vec<P::Scalar> = (0..samples).map(|i| a + (b - a) * from_usize(i) / from_usize(samples - 1)).collect();
+        let ds: Vec<P::Scalar> = ts.iter().map(|t| distance_sqr(*t)).collect();
+        let mut best = (a, ds[0]);
+        for i in 0..samples {
+            if (i > 0 && ds[i - 1] < ds[i]) || (i + 1 < samples && ds[i + 1] < ds[i]) { continue; }
+            let mut t = ts[i];
+            for _ in 0..16 {
+                let (d, v, w) = (self.point_at(t) - *p, self.derivative_at(t), self.second_derivative_at(t));
+                let slope = P::dot(&v, &v) + P::dot(&d, &w);
+                if slope <= P::Scalar::ZERO { break; }
+                let next = clamp(t - P::dot(&d, &v) / slope, a, b);
+                let step = (next - t).abs();
+                t = next;
+                if step <= P::Scalar::TOLERANCE * (b - a) { break; }
+            }
+            for c in [ts[i], t].iter() {
+                let d = distance_sqr(*c);
+                if d < best.1 { best = (*c, d); }
+            }
+        }
+        return best.0; }
+    fn closest_point(&self, p: &P) -> P { return self.point_at(self.closest_parameter(p)); }
+}
+
+// Helpers
+fn from_usize<T: Scalar>(n: usize) -> T { return T::from_f64(n as f64); }
+fn to_usize<T: Scalar>(x: T) -> usize { return x.to_f64() as usize; }
+fn clamp<T: Scalar>(x: T, lo: T, hi: T) -> T { return T::min(T::max(x, lo), hi); }
+fn lerp<P: CurvePoint>(a: &P, b: &P, t: P::Scalar) -> P { return *a + (*b - *a).scale(t); }
+//      Per-axis bounds of a point set
+fn bounds_of<P: CurvePoint>(points: &[P]) -> (P, P) {
+    let (mut min, mut max) = (points[0], points[0]);
+    for p in points.iter() {
+        for n in 0..P::DIMENSION {
+            if p.component(n) < min.component(n) { min.set_component(n, p.component(n)); }
+            if p.component(n) > max.component(n) { max.set_component(n, p.component(n)); }
+        }
+    }
+    return (min, max); }
+fn merge_bounds<P: CurvePoint>(a: &(P, P), b: &(P, P)) -> (P, P) { return bounds_of(&[a.0, a.1, b.0, b.1]); }
+//      Roots in [lo, hi] of a polynomial in Bernstein form, isolated by halving (a Bernstein polynomial has no more
+//      roots in its interval than its coefficients have sign changes). The results are parameters to test, so spurious
+//      ones near a double root do no harm.
+fn bernstein_roots<T: Scalar>(c: &[T], lo: T, hi: T, epsilon: T, roots: &mut Vec<T>) {
+    if c.iter().all(|x| *x > epsilon) || c.iter().all(|x| *x < -epsilon) { return; }
+    let mid = (lo + hi) * T::HALF;
+    if hi - lo <= T::TOLERANCE || c.iter().all(|x| x.abs() <= epsilon) { roots.push(mid); return; }
+    let (left, right) = casteljau(c, T::HALF);
+    bernstein_roots(&left, lo, mid, epsilon, roots);
+    bernstein_roots(&right, mid, hi, epsilon, roots); }
+//      de Casteljau's algorithm on scalars: the control points of both halves
+fn casteljau<T: Scalar>(c: &[T], t: T) -> (Vec<T>, Vec<T>) {
+    let mut work = c.to_vec();
+    let (mut left, mut right) = (vec![work[0]], vec![work[work.len() - 1]]);
+    for r in 1..c.len() {
+        for i in 0..c.len() - r { work[i] = work[i] + (work[i + 1] - work[i]) * t; }
+        left.push(work[0]);
+        right.push(work[c.len() - r - 1]);
+    }
+    right.reverse();
+    return (left, right); }
+
+// Construction
+impl<P: CurvePoint> Bezier<P>       { pub fn new(points: Vec<P>)                  -> Option<Self> { return if points.is_empty() { None } else { Some(Self { points }) }; } }
+impl<P: CurvePoint> Bezier<P>       { pub fn from_slice(points: &[P])             -> Option<Self> { return Self::new(points.to_vec()); } }
+impl<P: CurvePoint> Bezier<P>       { pub fn linear(a: P, b: P)                   -> Self { return Self { points: vec![a, b] }; } }
+impl<P: CurvePoint> Bezier<P>       { pub fn quadratic(a: P, b: P, c: P)          -> Self { return Self { points: vec![a, b, c] }; } }
+impl<P: CurvePoint> Bezier<P>       { pub fn cubic(a: P, b: P, c: P, d: P)        -> Self { return Self { points: vec![a, b, c, d] }; } }
+impl<P: CurvePoint> Hermite<P>      { pub fn new(p0: P, m0: P, p1: P, m1: P)      -> Self { return Self { p0, m0, p1, m1 }; } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn new(points: Vec<P>, alpha: P::Scalar) -> Option<Self> { return if points.len() < 4 { None } else { Some(Self { points, alpha }) }; } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn uniform(points: Vec<P>)              -> Option<Self> { return Self::new(points, P::Scalar::ZERO); } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn centripetal(points: Vec<P>)          -> Option<Self> { return Self::new(points, P::Scalar::HALF); } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn chordal(points: Vec<P>)              -> Option<Self> { return Self::new(points, P::Scalar::ONE); } }
+impl<P: CurvePoint> BSpline<P>      { pub fn new(points: Vec<P>, knots: Vec<P::Scalar>, degree: usize) -> Option<Self> {
+    if points.len() <= degree || knots.len() != points.len() + degree + 1 { return None; }
+    return Some(Self { points, knots, degree }); } }
+//      Uniform knots, one unit apart (the curve starts and ends away from the end points)
+impl<P: CurvePoint> BSpline<P>      { pub fn uniform(points: Vec<P>, degree: usize) -> Option<Self> {
+    let knots = (0..points.len() + degree + 1).map(|i| from_usize::<P::Scalar>(i) - from_usize(degree)).collect();
+    return Self::new(points, knots, degree); } }
+//      Uniform inside, with the end knots repeated so the curve starts and ends at the end points
+impl<P: CurvePoint> BSpline<P>      { pub fn clamped(points: Vec<P>, degree: usize) -> Option<Self> {
+    if points.len() <= degree { return None; }
+    let spans = points.len() - degree;
+    let knots = (0..points.len() + degree + 1).map(|i| from_usize::<P::Scalar>(usize::min(i.saturating_sub(degree), spans))).collect();
+    return Self::new(points, knots, degree); } }
+
+// Field Interpretation
+impl<P: CurvePoint> Bezier<P>       { pub fn points(&self)        -> &[P] { return &self.points; } }
+impl<P: CurvePoint> Bezier<P>       { pub fn degree(&self)        -> usize { return self.points.len() - 1; } }
+impl<P: CurvePoint> Hermite<P>      { pub fn start(&self)         -> P { return self.p0; } }
+impl<P: CurvePoint> Hermite<P>      { pub fn start_tangent(&self) -> P { return self.m0; } }
+impl<P: CurvePoint> Hermite<P>      { pub fn end(&self)           -> P { return self.p1; } }
+impl<P: CurvePoint> Hermite<P>      { pub fn end_tangent(&self)   -> P { return self.m1; } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn points(&self)        -> &[P] { return &self.points; } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn alpha(&self)         -> P::Scalar { return self.alpha; } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn segments(&self)      -> usize { return self.points.len().saturating_sub(3); } }
+impl<P: CurvePoint> BSpline<P>      { pub fn points(&self)        -> &[P] { return &self.points; } }
+impl<P: CurvePoint> BSpline<P>      { pub fn knots(&self)         -> &[P::Scalar] { return &self.knots; } }
+impl<P: CurvePoint> BSpline<P>      { pub fn degree(&self)        -> usize { return self.degree; } }
+impl<T: Scalar> ArcLengthTable<T>   { pub fn parameters(&self)    -> &[T] { return &self.parameters; } }
+impl<T: Scalar> ArcLengthTable<T>   { pub fn lengths(&self)       -> &[T] { return &self.lengths; } }
+impl<T: Scalar> ArcLengthTable<T>   { pub fn length(&self)        -> T { return self.lengths[self.lengths.len() - 1]; } }
+
+// Conversion Methods
+//      Hermite tangents are three times the Bezier handles
+impl<P: CurvePoint> Hermite<P>      { pub fn to_bezier(&self)     -> Bezier<P> {
+    let third = P::Scalar::ONE / from_usize(3);
+    return Bezier::cubic(self.p0, self.p0 + self.m0.scale(third), self.p1 - self.m1.scale(third), self.p1); } }
+impl<P: CurvePoint> Bezier<P>       { pub fn to_hermite(&self)    -> Option<Hermite<P>> {
+    if self.degree() != 3 { return None; }
+    let three = from_usize(3);
+    let p = &self.points;
+    return Some(Hermite::new(p[0], (p[1] - p[0]).scale(three), p[3], (p[3] - p[2]).scale(three))); } }
+//      Segment i runs from points[i + 1] to points[i + 2]. The tangents come from the non-uniform knot spacing,
+//      after Yuksel et al., "Parameterization and applications of Catmull-Rom curves": https://doi.org/10.1016/j.cad.2010.08.008
+impl<P: CurvePoint> CatmullRom<P>   { pub fn segment(&self, i: usize) -> Hermite<P> {
+    let p = &self.points[i..i + 4];
+    let knot = |a: &P, b: &P| {
+        let d = P::dot(&(*b - *a), &(*b - *a)).sqrt().powf(self.alpha);
+        return if d > P::Scalar::EPSILON { d } else { P::Scalar::ONE }; };
+    let (d0, d1, d2) = (knot(&p[0], &p[1]), knot(&p[1], &p[2]), knot(&p[2], &p[3]));
+    let m1 = ((p[1] - p[0]).scale(P::Scalar::ONE / d0) - (p[2] - p[0]).scale(P::Scalar::ONE / (d0 + d1)) + (p[2] - p[1]).scale(P::Scalar::ONE / d1)).scale(d1);
+    let m2 = ((p[2] - p[1]).scale(P::Scalar::ONE / d1) - (p[3] - p[1]).scale(P::Scalar::ONE / (d1 + d2)) + (p[3] - p[2]).scale(P::Scalar::ONE / d2)).scale(d1);
+    return Hermite::new(p[1], m1, p[2], m2); } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn to_hermites(&self)   -> Vec<Hermite<P>> { return (0..self.segments()).map(|i| self.segment(i)).collect(); } }
+impl<P: CurvePoint> CatmullRom<P>   { pub fn to_beziers(&self)    -> Vec<Bezier<P>> { return (0..self.segments()).map(|i| self.segment(i).to_bezier()).collect(); } }
+//      One Bezier per non-empty knot span in the domain (every knot raised to full multiplicity)
+impl<P: CurvePoint> BSpline<P>      { pub fn to_beziers(&self)    -> Vec<Bezier<P>> {
+    let (a, b) = self.domain();
+    let mut distinct: Vec<P::Scalar> = self.knots.iter().cloned().filter(|u| *u >= a && *u <= b).collect();
+    distinct.dedup();
+    let mut s = self.clone();
+    for u in distinct.iter() { while s.multiplicity(*u) <= s.degree { s = s.insert_knot(*u); } }
+    let first = s.knots.iter().position(|u| *u == a).unwrap_or(0);
+    let order = self.degree + 1;
+    return (0..distinct.len() - 1).map(|j| Bezier { points: s.points[first + j * order..first + (j + 1) * order].to_vec() }).collect(); } }
+
+// Evaluation and derivatives
+//      Bezier: de Casteljau, with the derivative as the Bezier of the point differences (the hodograph)
+impl<P: CurvePoint> Bezier<P>       { pub fn derivative(&self)    -> Self {
+    if self.points.len() < 2 { return Self { points: vec![P::zero()] }; }
+    let n = from_usize::<P::Scalar>(self.degree());
+    return Self { points: self.points.windows(2).map(|w| (w[1] - w[0]).scale(n)).collect() }; } }
+impl<P: CurvePoint> Curve<P> for Bezier<P> {
+    fn domain(&self) -> (P::Scalar, P::Scalar) { return (P::Scalar::ZERO, P::Scalar::ONE); }
+    fn point_at(&self, t: P::Scalar) -> P {
+        let t = clamp(t, P::Scalar::ZERO, P::Scalar::ONE);
+        let mut work = self.points.clone();
+        for r in 1..work.len() {
+            for i in 0..work.len() - r { work[i] = lerp(&work[i], &work[i + 1], t); }
+        }
+        return work[0]; }
+    fn derivative_at(&self, t: P::Scalar) -> P { return self.derivative().point_at(t); }
+    fn second_derivative_at(&self, t: P::Scalar) -> P { return self.derivative().derivative().point_at(t); }
+    //  The end points and wherever the derivative crosses zero on an axis
+    fn bounds(&self) -> (P, P) {
+        let hodograph = self.derivative();
+        let mut candidates = vec![self.points[0], self.points[self.points.len() - 1]];
+        for n in 0..P::DIMENSION {
+            let c: Vec<P::Scalar> = hodograph.points.iter().map(|p| p.component(n)).collect();
+            let epsilon = c.iter().fold(P::Scalar::ZERO, |m, x| P::Scalar::max(m, x.abs())) * P::Scalar::EPSILON * from_usize(16);
+            let mut roots = Vec::new();
+            bernstein_roots(&c, P::Scalar::ZERO, P::Scalar::ONE, epsilon, &mut roots);
+            candidates.extend(roots.iter().map(|t| self.point_at(*t)));
+        }
+        return bounds_of(&candidates); }
+}
+//      Hermite: the cubic Hermite basis functions
+impl<P: CurvePoint> Curve<P> for Hermite<P> {
+    fn domain(&self) -> (P::Scalar, P::Scalar) { return (P::Scalar::ZERO, P::Scalar::ONE); }
+    fn point_at(&self, t: P::Scalar) -> P {
+        let t = clamp(t, P::Scalar::ZERO, P::Scalar::ONE);
+        let (t2, t3) = (t * t, t * t * t);
+        let (two, three) = (P::Scalar::TWO, from_usize::<P::Scalar>(3));
+        return self.p0.scale(two * t3 - three * t2 + P::Scalar::ONE) + self.m0.scale(t3 - two * t2 + t)
+            + self.p1.scale(three * t2 - two * t3) + self.m1.scale(t3 - t2); }
+    fn derivative_at(&self, t: P::Scalar) -> P {
+        let t = clamp(t, P::Scalar::ZERO, P::Scalar::ONE);
+        let (three, four, six) = (from_usize::<P::Scalar>(3), from_usize::<P::Scalar>(4), from_usize::<P::Scalar>(6));
+        return (self.p0 - self.p1).scale(six * t * t - six * t) + self.m0.scale(three * t * t - four * t + P::Scalar::ONE)
+            + self.m1.scale(three * t * t - P::Scalar::TWO * t); }
+    fn second_derivative_at(&self, t: P::Scalar) -> P {
+        let t = clamp(t, P::Scalar::ZERO, P::Scalar::ONE);
+        let (two, four, six, twelve) = (P::Scalar::TWO, from_usize::<P::Scalar>(4), from_usize::<P::Scalar>(6), from_usize::<P::Scalar>(12));
+        return (self.p0 - self.p1).scale(twelve * t - six) + self.m0.scale(six * t - four) + self.m1.scale(six * t - two); }
+    fn bounds(&self) -> (P, P) { return self.to_bezier().bounds(); }
+}
+//      Catmull-Rom: segment floor(t) at t - floor(t), so the derivatives need no rescaling
+impl<P: CurvePoint> CatmullRom<P>   { fn locate(&self, t: P::Scalar) -> (usize, P::Scalar) {
+    let t = clamp(t, P::Scalar::ZERO, from_usize(self.segments()));
+    let i = usize::min(to_usize(t.floor()), self.segments() - 1);
+    return (i, t - from_usize(i)); } }
+impl<P: CurvePoint> Curve<P> for CatmullRom<P> {
+    fn domain(&self) -> (P::Scalar, P::Scalar) { return (P::Scalar::ZERO, from_usize(self.segments())); }
+    fn point_at(&self, t: P::Scalar) -> P { let (i, u) = self.locate(t); return self.segment(i).point_at(u); }
+    fn derivative_at(&self, t: P::Scalar) -> P { let (i, u) = self.locate(t); return self.segment(i).derivative_at(u); }
+    fn second_derivative_at(&self, t: P::Scalar) -> P { let (i, u) = self.locate(t); return self.segment(i).second_derivative_at(u); }
+    fn bounds(&self) -> (P, P) {
+        let segments = self.to_hermites();
+        return segments.iter().skip(1).fold(segments[0].bounds(), |b, s| merge_bounds(&b, &s.bounds())); }
+}
+//      B-spline: de Boor's algorithm, with the derivative as a B-spline one degree lower
+impl<P: CurvePoint> BSpline<P>      { fn span(&self, t: P::Scalar) -> usize {
+    let last = self.points.len() - 1;
+    return (self.degree..=last).rev().find(|k| self.knots[*k] <= t).unwrap_or(self.degree); } }
+impl<P: CurvePoint> BSpline<P>      { pub fn multiplicity(&self, u: P::Scalar) -> usize { return self.knots.iter().filter(|k| **k == u).count(); } }
+impl<P: CurvePoint> BSpline<P>      { pub fn derivative(&self)    -> Self {
+    let p = self.degree;
+    if p == 0 { return Self { points: vec![P::zero(); self.points.len()], knots: self.knots.clone(), degree: 0 }; }
+    let points = (0..self.points.len() - 1).map(|i| {
+        let d = self.knots[i + p + 1] - self.knots[i + 1];
+        return if d > P::Scalar::ZERO { (self.points[i + 1] - self.points[i]).scale(from_usize::<P::Scalar>(p) / d) } else { P::zero() }; }).collect();
+    return Self { points, knots: self.knots[1..self.knots.len() - 1].to_vec(), degree: p - 1 }; } }
+impl<P: CurvePoint> Curve<P> for BSpline<P> {
+    fn domain(&self) -> (P::Scalar, P::Scalar) { return (self.knots[self.degree], self.knots[self.points.len()]); }
+    fn point_at(&self, t: P::Scalar) -> P {
+        let (a, b) = self.domain();
+        let (t, p) = (clamp(t, a, b), self.degree);
+        let k = self.span(t);
+        let mut d: Vec<P> = self.points[k - p..=k].to_vec();
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let (lo, hi) = (self.knots[j + k - p], self.knots[j + 1 + k - r]);
+                let alpha = if hi > lo { (t - lo) / (hi - lo) } else { P::Scalar::ZERO };
+                d[j] = lerp(&d[j - 1], &d[j], alpha);
+            }
+        }
+        return d[p]; }
+    fn derivative_at(&self, t: P::Scalar) -> P { return self.derivative().point_at(t); }
+    fn second_derivative_at(&self, t: P::Scalar) -> P { return self.derivative().derivative().point_at(t); }
+    fn bounds(&self) -> (P, P) {
+        let pieces = self.to_beziers();
+        return pieces.iter().skip(1).fold(pieces[0].bounds(), |b, s| merge_bounds(&b, &s.bounds())); }
+}
+
+// Splitting
+//      Bezier: the intermediate points of de Casteljau's algorithm
+impl<P: CurvePoint> Bezier<P>       { pub fn split(&self, t: P::Scalar) -> (Self, Self) {
+    let mut work = self.points.clone();
+    let (mut left, mut right) = (vec![work[0]], vec![work[work.len() - 1]]);
+    for r in 1..work.len() {
+        for i in 0..work.len() - r { work[i] = lerp(&work[i], &work[i + 1], t); }
+        left.push(work[0]);
+        right.push(work[work.len() - r - 1]);
+    }
+    right.reverse();
+    return (Self { points: left }, Self { points: right }); } }
+//      Hermite: both halves run over [0, 1] again, so their tangents shrink with them
+impl<P: CurvePoint> Hermite<P>      { pub fn split(&self, t: P::Scalar) -> (Self, Self) {
+    let (p, v) = (self.point_at(t), self.derivative_at(t));
+    let u = P::Scalar::ONE - t;
+    return (Self::new(self.p0, self.m0.scale(t), p, v.scale(t)), Self::new(p, v.scale(u), self.p1, self.m1.scale(u))); } }
+//      B-spline: Boehm's knot insertion adds a knot (inside the domain) without changing the curve
+impl<P: CurvePoint> BSpline<P>      { pub fn insert_knot(&self, u: P::Scalar) -> Self {
+    let p = self.degree;
+    let k = self.span(u);
+    let mut points = Vec::with_capacity(self.points.len() + 1);
+    for i in 0..=self.points.len() {
+        points.push(if i + p <= k { self.points[i] } else if i > k { self.points[i - 1] } else {
+            let d = self.knots[i + p] - self.knots[i];
+            let alpha = if d > P::Scalar::ZERO { (u - self.knots[i]) / d } else { P::Scalar::ZERO };
+            lerp(&self.points[i - 1], &self.points[i], alpha) });
+    }
+    let mut knots = self.knots.clone();
+    knots.insert(k + 1, u);
+    return Self { points, knots, degree: p }; } }
+//      With the knot at full multiplicity the spline falls apart into two
+impl<P: CurvePoint> BSpline<P>      { pub fn split(&self, t: P::Scalar) -> (Self, Self) {
+    let mut s = self.clone();
+    while s.multiplicity(t) <= s.degree { s = s.insert_knot(t); }
+    let first = s.knots.iter().position(|u| *u == t).unwrap_or(0);
+    return (Self { points: s.points[..first].to_vec(), knots: s.knots[..first + s.degree + 1].to_vec(), degree: s.degree },
+            Self { points: s.points[first..].to_vec(), knots: s.knots[first..].to_vec(), degree: s.degree }); } }
+
+// Arc length
+//      Each sample interval is integrated with 5-point Gauss-Legendre quadrature of the speed
+impl<T: Scalar> ArcLengthTable<T>   { pub fn new<P: CurvePoint<Scalar = T>, C: Curve<P> + ?Sized>(curve: &C, samples: usize) -> Self {
+    const NODES: [(f64, f64); 5] = [
+        (0.0, 0.5688888888888889), (-0.5384693101056831, 0.4786286704993665), (0.5384693101056831, 0.4786286704993665),
+        (-0.906179845938664, 0.2369268850561891), (0.906179845938664, 0.2369268850561891)];
+    let (a, b) = curve.domain();
+    let samples = usize::max(samples, 1);
+    let parameters: Vec<T> = (0..=samples).map(|i| a + (b - a) * from_usize(i) / from_usize(samples)).collect();
+    let mut lengths = vec![T::ZERO];
+    for w in parameters.windows(2) {
+        let (mid, half) = ((w[0] + w[1]) * T::HALF, (w[1] - w[0]) * T::HALF);
+        let speed = |t: T| { let v = curve.derivative_at(t); return P::dot(&v, &v).sqrt(); };
+        let segment = NODES.iter().fold(T::ZERO, |s, (x, weight)| s + T::from_f64(*weight) * speed(mid + half * T::from_f64(*x)));
+        lengths.push(lengths[lengths.len() - 1] + segment * half);
+    }
+    return Self { parameters, lengths }; } }
+//      Distance along the curve to parameter (and back), interpolated between samples
+impl<T: Scalar> ArcLengthTable<T>   { pub fn parameter_at(&self, s: T) -> T { return interpolate(&self.lengths, &self.parameters, s); } }
+impl<T: Scalar> ArcLengthTable<T>   { pub fn distance_at(&self, t: T)  -> T { return interpolate(&self.parameters, &self.lengths, t); } }
+//      n parameters (n >= 2), evenly spaced by distance from start to end
+impl<T: Scalar> ArcLengthTable<T>   { pub fn evenly_spaced(&self, n: usize) -> Vec<T> {
+    return (0..n).map(|i| self.parameter_at(self.length() * from_usize(i) / from_usize(usize::max(n, 2) - 1))).collect(); } }
+fn interpolate<T: Scalar>(from: &[T], to: &[T], x: T) -> T {
+    let last = from.len() - 1;
+    if x <= from[0] { return to[0]; }
+    if x >= from[last] { return to[last]; }
+    let (mut lo, mut hi) = (0, last);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if from[mid] <= x { lo = mid; } else { hi = mid; }
+    }
+    let span = from[hi] - from[lo];
+    let u = if span > T::ZERO { (x - from[lo]) / span } else { T::ZERO };
+    return to[lo] + (to[hi] - to[lo]) * u; }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bezier_evaluation_and_splitting() {
+        let bezier = Bezier::cubic(Vector2::new(0.0f64, 0.0), Vector2::new(0.0, 2.0), Vector2::new(2.0, 2.0), Vector2::new(2.0, 0.0));
+        assert!(bezier.point_at(0.5).approx_eq(&Vector2::new(1.0, 1.5)) && bezier.derivative_at(0.5).approx_eq(&Vector2::new(3.0, 0.0)));
+        assert!(bezier.second_derivative_at(0.0).approx_eq(&Vector2::new(12.0, -12.0)));
+        let (left, right) = bezier.split(0.25);
+        assert!(left.point_at(1.0).approx_eq(&bezier.point_at(0.25)) && right.point_at(0.5).approx_eq(&bezier.point_at(0.625)));
+        // Tight bounds: the top is at y = 1.5, well below the control points
+        let (min, max) = bezier.bounds();
+        assert!(min.approx_eq(&Vector2::zero()) && max.approx_eq(&Vector2::new(2.0, 1.5)));
+    }
+
+    #[test]
+    fn hermite_matches_bezier() {
+        let bezier = Bezier::cubic(Vector2::new(0.0f64, 0.0), Vector2::new(0.0, 2.0), Vector2::new(2.0, 2.0), Vector2::new(2.0, 0.0));
+        let hermite = bezier.to_hermite().unwrap();
+        assert!(hermite.start_tangent().approx_eq(&Vector2::new(0.0, 6.0)) && hermite.point_at(0.3).approx_eq(&bezier.point_at(0.3)));
+        let (_, second) = hermite.split(0.5);
+        assert!(second.point_at(0.5).approx_eq(&bezier.point_at(0.75)));
+    }
+
+    #[test]
+    fn catmull_rom_interpolates() {
+        let points = vec![Vector3::new(-1.0f64, 0.0, 0.0), Vector3::zero(), Vector3::new(1.0, 1.0, 0.0), Vector3::new(2.0, 0.0, 1.0), Vector3::new(4.0, 0.0, 1.0)];
+        let rom = CatmullRom::centripetal(points.clone()).unwrap();
+        assert_eq!(rom.domain(), (0.0, 2.0));
+        assert!(rom.point_at(1.0).approx_eq(&points[2]) && rom.point_at(2.0).approx_eq(&points[3]));
+        assert!(CatmullRom::uniform(points.clone()).unwrap().derivative_at(1.0).approx_eq(&((points[3] - points[1]) * 0.5)));
+    }
+
+    #[test]
+    fn too_few_points() {
+        let points = vec![Vector3::new(0.0f64, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0)];
+        assert!(Bezier::<Vector3<f64>>::new(Vec::new()).is_none());
+        let point = Bezier::from_slice(&points[..1]).unwrap();
+        assert_eq!(point.degree(), 0);
+        assert!(point.derivative_at(0.5).approx_eq(&Vector3::zero()));
+        assert!(CatmullRom::centripetal(points.clone()).is_none() && CatmullRom::uniform(Vec::<Vector3<f64>>::new()).is_none());
+        assert!(BSpline::clamped(points.clone(), 3).is_none() && BSpline::uniform(points.clone(), 5).is_none());
+        assert!(BSpline::new(points.clone(), vec![0.0, 0.0, 0.0, 1.0, 1.0], 2).is_none());
+        assert!(BSpline::clamped(points.clone(), 2).unwrap().point_at(1.0).approx_eq(&points[2]));
+    }
+
+    #[test]
+    fn clamped_b_splines() {
+        let points = vec![Vector3::new(-1.0f64, 0.0, 0.0), Vector3::zero(), Vector3::new(1.0, 1.0, 0.0), Vector3::new(2.0, 0.0, 1.0), Vector3::new(4.0, 0.0, 1.0)];
+        let spline = BSpline::clamped(points.clone(), 3).unwrap();
+        assert_eq!(spline.knots(), &[0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0, 2.0][..]);
+        assert!(spline.point_at(0.0).approx_eq(&points[0]) && spline.point_at(2.0).approx_eq(&points[4]));
+        assert!(spline.insert_knot(0.5).point_at(1.3).approx_eq(&spline.point_at(1.3)));
+        let (head, tail) = spline.split(1.5);
+        assert!(head.point_at(0.7).approx_eq(&spline.point_at(0.7)) && tail.point_at(1.8).approx_eq(&spline.point_at(1.8)));
+        let pieces = spline.to_beziers();
+        assert!(pieces.len() == 2 && pieces[1].point_at(0.5).approx_eq(&spline.point_at(1.5)));
+    }
+
+    #[test]
+    fn arc_length_and_closest_points() {
+        let quarter = Bezier::cubic(Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 0.5523, 0.0), Vector3::new(0.5523, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let table = quarter.arc_length_table(32);
+        assert!((table.length() - std::f64::consts::FRAC_PI_2).abs() < 1e-3);
+        let middle = quarter.point_at_distance(&table, table.length() * 0.5);
+        assert!(middle.approx_eq_eps(&Vector3::new(1.0, 1.0, 0.0).normalization(), 1e-3, TestMode::AbsoluteCoordinates));
+        assert!((quarter.closest_parameter(&Vector3::new(3.0, 3.0, 0.0)) - 0.5).abs() < 1e-9);
+        let points = vec![Vector3::new(-1.0f64, 0.0, 0.0), Vector3::zero(), Vector3::new(1.0, 1.0, 0.0), Vector3::new(2.0, 0.0, 1.0), Vector3::new(4.0, 0.0, 1.0)];
+        assert!(BSpline::clamped(points.clone(), 3).unwrap().closest_point(&Vector3::new(10.0, 0.0, 1.0)).approx_eq(&points[4]));
+    }
+
+    #[test]
+    fn quaternion_keys_blend_component_wise() {
+        let turn = Bezier::linear(Quaternion::identity(), Quaternion::rotation(Deg(90.0), Vector3::up()));
+        assert!((turn.point_at(0.5).normalization() * Vector3::right()).approx_eq(&Vector3::new(1.0, 1.0, 0.0).normalization()));
+    }
+}
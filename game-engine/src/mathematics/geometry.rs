@@ -0,0 +1,448 @@
+// Geometric primitives (rays, planes and bounding volumes) built on the linalg types
+//      Guidance: Ericson, "Real-Time Collision Detection" (Morgan Kaufmann, 2005)
+use super::linalg::*;
+
+/*
+    Shape conventions:
+    - Everything lives in 3D, in whatever space the Vector3s are in (Z-up like the linalg prefabs, but nothing here cares).
+    - Ray directions are kept unit length, so a ray parameter t is a distance.
+    - A plane is n * p + d = 0 with a unit normal n, so n * p + d is the signed distance of p (positive on the normal side).
+    - Triangles wind counter-clockwise around their normal: n = (b - a) / (c - a).
+    - Frustum planes face inwards, so a point is inside when it is on the positive side of all six.
+    - Transforming by a Matrix4 expects an affine matrix (bottom row [0, 0, 0, 1]), except for planes and frusta,
+      which take any matrix and return None when it is not invertible. Spheres and capsules grow by the largest axis scale, so they stay round.
+      Oriented boxes keep their axes orthogonal only when the matrix has no shear (non-uniform scale after a rotation).
+*/
+
+// Ray casts, overlap tests, frustum culling and closest points
+pub mod intersection;
+pub use self::intersection::{ RayHit, RayHitf, RayHitd, Containment, closest_point_segment, closest_points_segments };
+
+// Convex-vs-convex collision through support functions (GJK distance and overlap, EPA penetration)
+pub mod gjk;
+pub use self::gjk::{ Support, ConvexHull, MinkowskiSum, Separation, Contact, gjk_intersects, gjk_distance, epa_penetration };
+pub use self::gjk::{ ConvexHullf, ConvexHulld, Separationf, Separationd, Contactf, Contactd };
+
+// Struct Definitions
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Ray<T: Scalar = f32>       { origin: Vector3<T>, direction: Vector3<T> }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Plane<T: Scalar = f32>     { normal: Vector3<T>, d: T }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Sphere<T: Scalar = f32>    { center: Vector3<T>, radius: T }
+//      Axis-aligned bounding box
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Aabb<T: Scalar = f32>      { min: Vector3<T>, max: Vector3<T> }
+//      Oriented bounding box (axes are unit length and orthogonal, half extents are measured along them)
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Obb<T: Scalar = f32>       { center: Vector3<T>, axes: [Vector3<T>; 3], half_extents: Vector3<T> }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Triangle<T: Scalar = f32>  { a: Vector3<T>, b: Vector3<T>, c: Vector3<T> }
+//      Every point within radius of the segment from a to b
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Capsule<T: Scalar = f32>   { a: Vector3<T>, b: Vector3<T>, radius: T }
+//      Planes in the order left, right, bottom, top, near, far
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Frustum<T: Scalar = f32>   { planes: [Plane<T>; 6] }
+
+// Precision aliases (the bare names default to f32)
+pub type Rayf           = Ray<f32>;             pub type Rayd           = Ray<f64>;
+pub type Planef         = Plane<f32>;           pub type Planed         = Plane<f64>;
+pub type Spheref        = Sphere<f32>;          pub type Sphered        = Sphere<f64>;
+pub type Aabbf          = Aabb<f32>;            pub type Aabbd          = Aabb<f64>;
+pub type Obbf           = Obb<f32>;             pub type Obbd           = Obb<f64>;
+pub type Trianglef      = Triangle<f32>;        pub type Triangled      = Triangle<f64>;
+pub type Capsulef       = Capsule<f32>;         pub type Capsuled       = Capsule<f64>;
+pub type Frustumf       = Frustum<f32>;         pub type Frustumd       = Frustum<f64>;
+
+// Element-wise helpers (the vector types only have the geometric products)
+fn min_elements<T: Scalar>(a: &Vector3<T>, b: &Vector3<T>) -> Vector3<T> { return Vector3::new(T::min(a.x(), b.x()), T::min(a.y(), b.y()), T::min(a.z(), b.z())); }
+fn max_elements<T: Scalar>(a: &Vector3<T>, b: &Vector3<T>) -> Vector3<T> { return Vector3::new(T::max(a.x(), b.x()), T::max(a.y(), b.y()), T::max(a.z(), b.z())); }
+//      Length of the longest transformed basis vector, which bounds how much m can stretch a distance
+fn max_scale<T: Scalar>(m: &Matrix4<T>) -> T {
+    let r = Matrix3::from_matrix4(m);
+    return T::max(r.column(0).magnitude_sqr(), T::max(r.column(1).magnitude_sqr(), r.column(2).magnitude_sqr())).sqrt(); }
+
+// Construction
+impl<T: Scalar> Ray<T>      { pub fn new(origin: Vector3<T>, direction: Vector3<T>)  -> Self { return Self { origin, direction: direction.normalization() }; } }
+impl<T: Scalar> Ray<T>      { pub fn from_points(from: Vector3<T>, to: Vector3<T>)   -> Self { return Self::new(from, to - from); } }
+//      The normal is normalized, and d scaled along with it
+impl<T: Scalar> Plane<T>    { pub fn new(normal: Vector3<T>, d: T)                   -> Self {
+    let s = T::ONE / normal.magnitude();
+    return Self { normal: normal * s, d: d * s }; } }
+impl<T: Scalar> Plane<T>    { pub fn from_point_normal(p: Vector3<T>, normal: Vector3<T>) -> Self {
+    let n = normal.normalization();
+    return Self { normal: n, d: -(n * p) }; } }
+//          Counter-clockwise around the normal (same winding as Triangle)
+impl<T: Scalar> Plane<T>    { pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+    return Self::from_point_normal(a, (b - a) / (c - a)); } }
+//          (a, b, c, d) as in ax + by + cz + d = 0
+impl<T: Scalar> Plane<T>    { pub fn from_vector4(v: Vector4<T>)                     -> Self { return Self::new(Vector3::new(v.x(), v.y(), v.z()), v.w()); } }
+impl<T: Scalar> Sphere<T>   { pub fn new(center: Vector3<T>, radius: T)              -> Self { return Self { center, radius }; } }
+//          Ritter's bounding sphere: a quick fit from two far apart points, grown to take in the rest (at most ~20% too large)
+//          https://en.wikipedia.org/wiki/Bounding_sphere#Ritter's_bounding_sphere
+impl<T: Scalar> Sphere<T>   { pub fn from_points(points: &[Vector3<T>])              -> Self {
+    if points.is_empty() { return Self::new(Vector3::zero(), -T::ONE); }
+    let farthest = |from: &Vector3<T>| -> Vector3<T> {
+        let mut best = points[0];
+        for p in points { if (*p - *from).magnitude_sqr() > (best - *from).magnitude_sqr() { best = *p; } }
+        return best; };
+    let a = farthest(&points[0]);
+    let b = farthest(&a);
+    let mut s = Self::new(Vector3::lerp(&a, &b, T::HALF), (b - a).magnitude() * T::HALF);
+    for p in points { s = s.include_point(p); }
+    return s; } }
+impl<T: Scalar> Aabb<T>     { pub fn new(a: Vector3<T>, b: Vector3<T>)                -> Self { return Self { min: min_elements(&a, &b), max: max_elements(&a, &b) }; } }
+impl<T: Scalar> Aabb<T>     { pub fn from_center_half_extents(center: Vector3<T>, half_extents: Vector3<T>) -> Self {
+    return Self::new(center - half_extents, center + half_extents); } }
+impl<T: Scalar> Aabb<T>     { pub fn from_points(points: &[Vector3<T>])              -> Self {
+    return points.iter().fold(Self::empty(), |b, p| b.include_point(p)); } }
+impl<T: Scalar> Obb<T>      { pub fn new(center: Vector3<T>, axes: [Vector3<T>; 3], half_extents: Vector3<T>) -> Self {
+    return Self { center, axes: [axes[0].normalization(), axes[1].normalization(), axes[2].normalization()], half_extents }; } }
+//          Axes are the columns of the rotation
+impl<T: Scalar> Obb<T>      { pub fn from_rotation(center: Vector3<T>, rotation: &Quaternion<T>, half_extents: Vector3<T>) -> Self {
+    let r = Matrix3::from_quaternion(rotation);
+    return Self::new(center, [r.column(0), r.column(1), r.column(2)], half_extents); } }
+impl<T: Scalar> Obb<T>      { pub fn from_aabb(b: &Aabb<T>)                           -> Self {
+    return Self { center: b.center(), axes: [Vector3::right(), Vector3::forth(), Vector3::up()], half_extents: b.half_extents() }; } }
+impl<T: Scalar> Triangle<T> { pub fn new(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self { return Self { a, b, c }; } }
+impl<T: Scalar> Capsule<T>  { pub fn new(a: Vector3<T>, b: Vector3<T>, radius: T)    -> Self { return Self { a, b, radius }; } }
+impl<T: Scalar> Frustum<T>  { pub fn new(planes: [Plane<T>; 6])                      -> Self { return Self { planes }; } }
+//          Gribb and Hartmann, "Fast Extraction of Viewing Frustum Planes from the World-View-Projection Matrix"
+//          https://www.gamedevs.org/uploads/fast-extraction-viewing-frustum-planes-from-world-view-projection-matrix.pdf
+//          Expects the clip space of the Matrix4 projections: -w <= x, y <= w, and -w <= z <= w or 0 <= z <= w for the
+//          depth range the projection maps to (see Matrix4::unproject). With a view-projection matrix the planes come out
+//          in world space, with a projection matrix alone in view space.
+//          With a [0, 1] range, the depth plane facing the way clip-space w grows (away from the camera) is the near one,
+//          like in unproject_ray. Orthographic projections give no such hint and count as reversed, like every [0, 1]
+//          projection Matrix4 makes. Infinite projections have no far plane, so far() repeats near(), which culls nothing more.
+impl<T: Scalar> Frustum<T>  { pub fn from_matrix(m: &Matrix4<T>, depth: DepthRange)  -> Self {
+    let (x, y, z, w) = (m.row(0), m.row(1), m.row(2), m.row(3));
+    let xyz = |v: &Vector4<T>| -> Vector3<T> { return Vector3::new(v.x(), v.y(), v.z()); };
+    let (near, far) = match depth {
+        DepthRange::NegativeOneToOne => (w + z, w - z),
+        DepthRange::ZeroToOne if xyz(&z) * xyz(&w) > T::ZERO => (z, w - z),
+        DepthRange::ZeroToOne => (w - z, z) };
+    let far = if xyz(&far).magnitude_sqr() <= T::EPSILON * xyz(&near).magnitude_sqr() { near } else { far };
+    return Self::new([
+        Plane::from_vector4(w + x), Plane::from_vector4(w - x),
+        Plane::from_vector4(w + y), Plane::from_vector4(w - y),
+        Plane::from_vector4(near),  Plane::from_vector4(far)]); } }
+
+// Field Interpretation
+impl<T: Scalar> Ray<T>      { pub fn origin(&self)            -> Vector3<T> { return self.origin; } }
+impl<T: Scalar> Ray<T>      { pub fn direction(&self)         -> Vector3<T> { return self.direction; } }
+impl<T: Scalar> Plane<T>    { pub fn normal(&self)            -> Vector3<T> { return self.normal; } }
+impl<T: Scalar> Plane<T>    { pub fn d(&self)                 -> T { return self.d; } }
+impl<T: Scalar> Sphere<T>   { pub fn center(&self)            -> Vector3<T> { return self.center; } }
+impl<T: Scalar> Sphere<T>   { pub fn radius(&self)            -> T { return self.radius; } }
+impl<T: Scalar> Aabb<T>     { pub fn min(&self)               -> Vector3<T> { return self.min; } }
+impl<T: Scalar> Aabb<T>     { pub fn max(&self)               -> Vector3<T> { return self.max; } }
+impl<T: Scalar> Obb<T>      { pub fn center(&self)            -> Vector3<T> { return self.center; } }
+impl<T: Scalar> Obb<T>      { pub fn axes(&self)              -> [Vector3<T>; 3] { return self.axes; } }
+impl<T: Scalar> Obb<T>      { pub fn axis(&self, n: usize)    -> Vector3<T> { return self.axes[n]; } }
+impl<T: Scalar> Obb<T>      { pub fn half_extents(&self)      -> Vector3<T> { return self.half_extents; } }
+impl<T: Scalar> Triangle<T> { pub fn a(&self)                 -> Vector3<T> { return self.a; } }
+impl<T: Scalar> Triangle<T> { pub fn b(&self)                 -> Vector3<T> { return self.b; } }
+impl<T: Scalar> Triangle<T> { pub fn c(&self)                 -> Vector3<T> { return self.c; } }
+impl<T: Scalar> Triangle<T> { pub fn vertices(&self)          -> [Vector3<T>; 3] { return [self.a, self.b, self.c]; } }
+impl<T: Scalar> Capsule<T>  { pub fn a(&self)                 -> Vector3<T> { return self.a; } }
+impl<T: Scalar> Capsule<T>  { pub fn b(&self)                 -> Vector3<T> { return self.b; } }
+impl<T: Scalar> Capsule<T>  { pub fn radius(&self)            -> T { return self.radius; } }
+impl<T: Scalar> Frustum<T>  { pub fn planes(&self)            -> [Plane<T>; 6] { return self.planes; } }
+impl<T: Scalar> Frustum<T>  { pub fn plane(&self, n: usize)   -> Plane<T> { return self.planes[n]; } }
+impl<T: Scalar> Frustum<T>  { pub fn left(&self)              -> Plane<T> { return self.planes[0]; } }
+impl<T: Scalar> Frustum<T>  { pub fn right(&self)             -> Plane<T> { return self.planes[1]; } }
+impl<T: Scalar> Frustum<T>  { pub fn bottom(&self)            -> Plane<T> { return self.planes[2]; } }
+impl<T: Scalar> Frustum<T>  { pub fn top(&self)               -> Plane<T> { return self.planes[3]; } }
+impl<T: Scalar> Frustum<T>  { pub fn near(&self)              -> Plane<T> { return self.planes[4]; } }
+impl<T: Scalar> Frustum<T>  { pub fn far(&self)               -> Plane<T> { return self.planes[5]; } }
+
+// Prefabrication
+//      The empty box (min at +infinity, max at -infinity), the identity for merge() and include_point()
+impl<T: Scalar> Aabb<T>     { pub fn empty()      -> Self {
+    return Self { min: Vector3::new(T::INFINITY, T::INFINITY, T::INFINITY), max: Vector3::new(-T::INFINITY, -T::INFINITY, -T::INFINITY) }; } }
+impl<T: Scalar> Sphere<T>   { pub fn unit()       -> Self { return Self::new(Vector3::zero(), T::ONE); } }
+impl<T: Scalar> Aabb<T>     { pub fn unit()       -> Self { return Self::new(-Vector3::one() * T::HALF, Vector3::one() * T::HALF); } }
+
+// Derived Quantities
+impl<T: Scalar> Ray<T>      { pub fn point_at(&self, t: T)    -> Vector3<T> { return self.origin + self.direction * t; } }
+impl<T: Scalar> Plane<T>    { pub fn signed_distance(&self, p: &Vector3<T>) -> T { return self.normal * (*p) + self.d; } }
+impl<T: Scalar> Plane<T>    { pub fn project(&self, p: &Vector3<T>)         -> Vector3<T> { return (*p) - self.normal * self.signed_distance(p); } }
+impl<T: Scalar> Plane<T>    { pub fn to_vector4(&self)        -> Vector4<T> { return Vector4::from_vector3(&self.normal, self.d); } }
+//          The same plane facing the other way
+impl<T: Scalar> Plane<T>    { pub fn flip(&self)              -> Self { return Self { normal: -self.normal, d: -self.d }; } }
+impl<T: Scalar> Neg for Plane<T> { type Output = Self; fn neg(self) -> Self { return self.flip(); } }
+impl<T: Scalar> Sphere<T>   { pub fn volume(&self)            -> T { return T::from_f64(4.0 / 3.0 * std::f64::consts::PI) * self.radius * self.radius * self.radius; } }
+impl<T: Scalar> Sphere<T>   { pub fn surface_area(&self)      -> T { return T::from_f64(4.0 * std::f64::consts::PI) * self.radius * self.radius; } }
+impl<T: Scalar> Aabb<T>     { pub fn center(&self)            -> Vector3<T> { return Vector3::lerp(&self.min, &self.max, T::HALF); } }
+impl<T: Scalar> Aabb<T>     { pub fn size(&self)              -> Vector3<T> { return self.max - self.min; } }
+impl<T: Scalar> Aabb<T>     { pub fn half_extents(&self)      -> Vector3<T> { return self.size() * T::HALF; } }
+impl<T: Scalar> Aabb<T>     { pub fn volume(&self)            -> T { let s = self.size(); return s.x() * s.y() * s.z(); } }
+impl<T: Scalar> Aabb<T>     { pub fn surface_area(&self)      -> T { let s = self.size(); return T::TWO * (s.x() * s.y() + s.y() * s.z() + s.z() * s.x()); } }
+//          Corner n takes max.x when bit 0 of n is set, max.y for bit 1 and max.z for bit 2
+impl<T: Scalar> Aabb<T>     { pub fn corners(&self)           -> [Vector3<T>; 8] {
+    let mut c = [self.min; 8];
+    for (n, p) in c.iter_mut().enumerate() {
+        *p = Vector3::new(
+            if n & 1 != 0 { self.max.x() } else { self.min.x() },
+            if n & 2 != 0 { self.max.y() } else { self.min.y() },
+            if n & 4 != 0 { self.max.z() } else { self.min.z() });
+    }
+    return c; } }
+impl<T: Scalar> Obb<T>      { pub fn volume(&self)            -> T { let h = self.half_extents * T::TWO; return h.x() * h.y() * h.z(); } }
+//          Same corner order as Aabb, along the box axes
+impl<T: Scalar> Obb<T>      { pub fn corners(&self)           -> [Vector3<T>; 8] {
+    let mut c = [self.center; 8];
+    for (n, p) in c.iter_mut().enumerate() {
+        for i in 0..3 {
+            let h = if n & (1 << i) != 0 { self.half_extents[i] } else { -self.half_extents[i] };
+            *p += self.axes[i] * h;
+        }
+    }
+    return c; } }
+//          Rotation taking the world axes to the box axes
+impl<T: Scalar> Obb<T>      { pub fn rotation(&self)          -> Matrix3<T> { return Matrix3::from_vector3(self.axes[0], self.axes[1], self.axes[2]).transpose(); } }
+impl<T: Scalar> Triangle<T> { pub fn normal(&self)            -> Vector3<T> { return ((self.b - self.a) / (self.c - self.a)).normalization(); } }
+impl<T: Scalar> Triangle<T> { pub fn area(&self)              -> T { return ((self.b - self.a) / (self.c - self.a)).magnitude() * T::HALF; } }
+impl<T: Scalar> Triangle<T> { pub fn centroid(&self)          -> Vector3<T> { return (self.a + self.b + self.c) * (T::ONE / T::from_f32(3.0)); } }
+impl<T: Scalar> Triangle<T> { pub fn plane(&self)             -> Plane<T> { return Plane::from_points(self.a, self.b, self.c); } }
+//          Barycentric coordinates (u, v, w) of p projected onto the triangle's plane, with p = u a + v b + w c
+//          Ericson, section 3.4
+impl<T: Scalar> Triangle<T> { pub fn barycentric(&self, p: &Vector3<T>) -> Vector3<T> {
+    let (e0, e1, e2) = (self.b - self.a, self.c - self.a, (*p) - self.a);
+    let (d00, d01, d11) = (e0 * e0, e0 * e1, e1 * e1);
+    let (d20, d21) = (e2 * e0, e2 * e1);
+    let denominator = d00 * d11 - d01 * d01;
+    let v = (d11 * d20 - d01 * d21) / denominator;
+    let w = (d00 * d21 - d01 * d20) / denominator;
+    return Vector3::new(T::ONE - v - w, v, w); } }
+impl<T: Scalar> Triangle<T> { pub fn from_barycentric(&self, uvw: &Vector3<T>) -> Vector3<T> {
+    return self.a * uvw.x() + self.b * uvw.y() + self.c * uvw.z(); } }
+impl<T: Scalar> Capsule<T>  { pub fn axis(&self)              -> Vector3<T> { return self.b - self.a; } }
+impl<T: Scalar> Capsule<T>  { pub fn height(&self)            -> T { return (self.b - self.a).magnitude() + self.radius * T::TWO; } }
+impl<T: Scalar> Capsule<T>  { pub fn volume(&self)            -> T {
+    let r = self.radius;
+    return T::from_f64(std::f64::consts::PI) * r * r * ((self.b - self.a).magnitude() + T::from_f64(4.0 / 3.0) * r); } }
+//          The eight corners of the frustum (same order as Aabb, over normalized device coordinates), from the same
+//          matrix and depth range the frustum was built from. None when the matrix is not invertible; the far corners
+//          of an infinite projection are not finite either.
+impl<T: Scalar> Frustum<T>  { pub fn corners(m: &Matrix4<T>, depth: DepthRange) -> Option<[Vector3<T>; 8]> {
+    let inverse = m.try_inverse()?;
+    let low = match depth { DepthRange::NegativeOneToOne => -T::ONE, DepthRange::ZeroToOne => T::ZERO };
+    let mut c = [Vector3::zero(); 8];
+    for (n, p) in c.iter_mut().enumerate() {
+        let ndc = |bit: usize, low: T| -> T { if n & bit != 0 { T::ONE } else { low } };
+        *p = inverse.transform_point(&Vector3::new(ndc(1, -T::ONE), ndc(2, -T::ONE), ndc(4, low)));
+    }
+    return Some(c); } }
+
+// Bounding Volumes
+//      Every shape's tight (or, for spheres and capsules, exact) axis-aligned box
+impl<T: Scalar> Sphere<T>   { pub fn to_aabb(&self)           -> Aabb<T> { return Aabb::from_center_half_extents(self.center, Vector3::one() * self.radius); } }
+impl<T: Scalar> Obb<T>      { pub fn to_aabb(&self)           -> Aabb<T> {
+    let mut h = Vector3::zero();
+    for i in 0..3 {
+        let a = self.axes[i] * self.half_extents[i];
+        h += Vector3::new(a.x().abs(), a.y().abs(), a.z().abs());
+    }
+    return Aabb::from_center_half_extents(self.center, h); } }
+impl<T: Scalar> Triangle<T> { pub fn to_aabb(&self)           -> Aabb<T> { return Aabb::from_points(&self.vertices()); } }
+impl<T: Scalar> Capsule<T>  { pub fn to_aabb(&self)           -> Aabb<T> {
+    let r = Vector3::one() * self.radius;
+    return Aabb::new(min_elements(&self.a, &self.b) - r, max_elements(&self.a, &self.b) + r); } }
+//      And the bounding spheres
+impl<T: Scalar> Aabb<T>     { pub fn to_sphere(&self)         -> Sphere<T> { return Sphere::new(self.center(), self.half_extents().magnitude()); } }
+impl<T: Scalar> Obb<T>      { pub fn to_sphere(&self)         -> Sphere<T> { return Sphere::new(self.center, self.half_extents.magnitude()); } }
+impl<T: Scalar> Capsule<T>  { pub fn to_sphere(&self)         -> Sphere<T> {
+    return Sphere::new(Vector3::lerp(&self.a, &self.b, T::HALF), (self.b - self.a).magnitude() * T::HALF + self.radius); } }
+//      Growing and merging
+impl<T: Scalar> Aabb<T>     { pub fn include_point(&self, p: &Vector3<T>) -> Self { return Self { min: min_elements(&self.min, p), max: max_elements(&self.max, p) }; } }
+impl<T: Scalar> Aabb<T>     { pub fn merge(a: &Self, b: &Self)  -> Self { return Self { min: min_elements(&a.min, &b.min), max: max_elements(&a.max, &b.max) }; } }
+impl<T: Scalar> Aabb<T>     { pub fn expand(&self, margin: T)   -> Self {
+    let m = Vector3::one() * margin;
+    return Self { min: self.min - m, max: self.max + m }; } }
+//          The overlap of two boxes (empty when they are apart)
+impl<T: Scalar> Aabb<T>     { pub fn intersection(a: &Self, b: &Self) -> Self { return Self { min: max_elements(&a.min, &b.min), max: min_elements(&a.max, &b.max) }; } }
+//          A sphere with a negative radius is empty
+impl<T: Scalar> Sphere<T>   { pub fn include_point(&self, p: &Vector3<T>) -> Self {
+    if self.radius < T::ZERO { return Self::new(*p, T::ZERO); }
+    let d = ((*p) - self.center).magnitude();
+    if d <= self.radius { return *self; }
+    let r = (self.radius + d) * T::HALF;
+    let center = self.center + ((*p) - self.center) * ((r - self.radius) / d);
+    // Round-off can leave p a hair outside, which would fail contains_point()
+    return Self::new(center, T::max(r, ((*p) - center).magnitude()) * (T::ONE + T::EPSILON)); } }
+//          The smallest sphere around both
+impl<T: Scalar> Sphere<T>   { pub fn merge(a: &Self, b: &Self)  -> Self {
+    if a.radius < T::ZERO { return *b; }
+    if b.radius < T::ZERO { return *a; }
+    let offset = b.center - a.center;
+    let d = offset.magnitude();
+    if d + b.radius <= a.radius { return *a; }
+    if d + a.radius <= b.radius { return *b; }
+    let r = (d + a.radius + b.radius) * T::HALF;
+    return Self::new(a.center + offset * ((r - a.radius) / d), r); } }
+
+// Transformation
+//      Planes transform by the inverse transpose, so they stay planes under non-uniform scale
+//      https://www.songho.ca/opengl/gl_normaltransform.html
+impl<T: Scalar> Ray<T>      { pub fn transform(&self, m: &Matrix4<T>)     -> Self { return Self::new(m.transform_point(&self.origin), m.transform_direction(&self.direction)); } }
+impl<T: Scalar> Plane<T>    { pub fn transform(&self, m: &Matrix4<T>)     -> Option<Self> { return m.try_inverse().map(|inverse| Self::from_vector4(self.to_vector4() * inverse)); } }
+impl<T: Scalar> Sphere<T>   { pub fn transform(&self, m: &Matrix4<T>)     -> Self { return Self::new(m.transform_point(&self.center), self.radius * max_scale(m)); } }
+//          Arvo, "Transforming Axis-Aligned Bounding Boxes" (Graphics Gems): the new half extents are |M| times the old ones
+impl<T: Scalar> Aabb<T>     { pub fn transform(&self, m: &Matrix4<T>)     -> Self {
+    let r = Matrix3::from_matrix4(m);
+    let a = Matrix3::new(
+        r[(0, 0)].abs(),    r[(0, 1)].abs(),    r[(0, 2)].abs(),
+        r[(1, 0)].abs(),    r[(1, 1)].abs(),    r[(1, 2)].abs(),
+        r[(2, 0)].abs(),    r[(2, 1)].abs(),    r[(2, 2)].abs());
+    return Self::from_center_half_extents(m.transform_point(&self.center()), a * self.half_extents()); } }
+impl<T: Scalar> Obb<T>      { pub fn transform(&self, m: &Matrix4<T>)     -> Self {
+    let mut axes = self.axes;
+    let mut h = self.half_extents;
+    for i in 0..3 {
+        let a = m.transform_direction(&self.axes[i]);
+        let s = a.magnitude();
+        axes[i] = a * (T::ONE / s);
+        h[i] *= s;
+    }
+    return Self { center: m.transform_point(&self.center), axes, half_extents: h }; } }
+impl<T: Scalar> Triangle<T> { pub fn transform(&self, m: &Matrix4<T>)     -> Self {
+    return Self::new(m.transform_point(&self.a), m.transform_point(&self.b), m.transform_point(&self.c)); } }
+impl<T: Scalar> Capsule<T>  { pub fn transform(&self, m: &Matrix4<T>)     -> Self {
+    return Self::new(m.transform_point(&self.a), m.transform_point(&self.b), self.radius * max_scale(m)); } }
+impl<T: Scalar> Frustum<T>  { pub fn transform(&self, m: &Matrix4<T>)     -> Option<Self> {
+    let inverse = m.try_inverse()?;
+    let mut planes = self.planes;
+    for p in planes.iter_mut() { *p = Plane::from_vector4(p.to_vector4() * inverse); }
+    return Some(Self { planes }); } }
+//      The same through a Transform, and as m * shape
+macro_rules! impl_shape_transform { ($($S:ident => $O:ty),*) => { $(
+    impl<T: Scalar> $S<T> { pub fn transform_by(&self, t: &Transform<T>) -> $O { return self.transform(&t.to_matrix4()); } }
+    impl<T: Scalar> Mul<$S<T>> for Matrix4<T>   { type Output = $O; fn mul(self, s: $S<T>) -> $O { return s.transform(&self); } }
+    impl<T: Scalar> Mul<$S<T>> for Transform<T> { type Output = $O; fn mul(self, s: $S<T>) -> $O { return s.transform_by(&self); } }
+)* } }
+impl_shape_transform!(Ray => Ray<T>, Sphere => Sphere<T>, Aabb => Aabb<T>, Obb => Obb<T>, Triangle => Triangle<T>, Capsule => Capsule<T>);
+impl_shape_transform!(Plane => Option<Plane<T>>, Frustum => Option<Frustum<T>>);
+
+// Precision conversion
+impl<T: Scalar> Ray<T>      { pub fn cast<U: Scalar>(&self) -> Ray<U>      { return Ray { origin: self.origin.cast(), direction: self.direction.cast() }; } }
+impl<T: Scalar> Plane<T>    { pub fn cast<U: Scalar>(&self) -> Plane<U>    { return Plane { normal: self.normal.cast(), d: U::from_f64(self.d.to_f64()) }; } }
+impl<T: Scalar> Sphere<T>   { pub fn cast<U: Scalar>(&self) -> Sphere<U>   { return Sphere::new(self.center.cast(), U::from_f64(self.radius.to_f64())); } }
+impl<T: Scalar> Aabb<T>     { pub fn cast<U: Scalar>(&self) -> Aabb<U>     { return Aabb { min: self.min.cast(), max: self.max.cast() }; } }
+impl<T: Scalar> Obb<T>      { pub fn cast<U: Scalar>(&self) -> Obb<U>      {
+    return Obb { center: self.center.cast(), axes: [self.axes[0].cast(), self.axes[1].cast(), self.axes[2].cast()], half_extents: self.half_extents.cast() }; } }
+impl<T: Scalar> Triangle<T> { pub fn cast<U: Scalar>(&self) -> Triangle<U> { return Triangle::new(self.a.cast(), self.b.cast(), self.c.cast()); } }
+impl<T: Scalar> Capsule<T>  { pub fn cast<U: Scalar>(&self) -> Capsule<U>  { return Capsule::new(self.a.cast(), self.b.cast(), U::from_f64(self.radius.to_f64())); } }
+impl<T: Scalar> Frustum<T>  { pub fn cast<U: Scalar>(&self) -> Frustum<U>  {
+    let p = &self.planes;
+    return Frustum::new([p[0].cast(), p[1].cast(), p[2].cast(), p[3].cast(), p[4].cast(), p[5].cast()]); } }
+impl From<Ray<f32>> for Ray<f64>            { fn from(s: Ray<f32>) -> Self { return s.cast(); } }
+impl From<Plane<f32>> for Plane<f64>        { fn from(s: Plane<f32>) -> Self { return s.cast(); } }
+impl From<Sphere<f32>> for Sphere<f64>      { fn from(s: Sphere<f32>) -> Self { return s.cast(); } }
+impl From<Aabb<f32>> for Aabb<f64>          { fn from(s: Aabb<f32>) -> Self { return s.cast(); } }
+impl From<Obb<f32>> for Obb<f64>            { fn from(s: Obb<f32>) -> Self { return s.cast(); } }
+impl From<Triangle<f32>> for Triangle<f64>  { fn from(s: Triangle<f32>) -> Self { return s.cast(); } }
+impl From<Capsule<f32>> for Capsule<f64>    { fn from(s: Capsule<f32>) -> Self { return s.cast(); } }
+impl From<Frustum<f32>> for Frustum<f64>    { fn from(s: Frustum<f32>) -> Self { return s.cast(); } }
+
+// Utilities
+impl<T: Scalar> Ray<T>      { pub fn to_string(&self) -> String { return format!("{{ o: {}, d: {} }}", self.origin.to_string(), self.direction.to_string()); } }
+impl<T: Scalar> Plane<T>    { pub fn to_string(&self) -> String { return format!("{{ n: {}, d: {} }}", self.normal.to_string(), self.d); } }
+impl<T: Scalar> Sphere<T>   { pub fn to_string(&self) -> String { return format!("{{ c: {}, r: {} }}", self.center.to_string(), self.radius); } }
+impl<T: Scalar> Aabb<T>     { pub fn to_string(&self) -> String { return format!("{{ min: {}, max: {} }}", self.min.to_string(), self.max.to_string()); } }
+impl<T: Scalar> Obb<T>      { pub fn to_string(&self) -> String {
+    return format!("{{ c: {}, axes: [{}, {}, {}], h: {} }}", self.center.to_string(),
+        self.axes[0].to_string(), self.axes[1].to_string(), self.axes[2].to_string(), self.half_extents.to_string()); } }
+impl<T: Scalar> Triangle<T> { pub fn to_string(&self) -> String { return format!("{{ {}, {}, {} }}", self.a.to_string(), self.b.to_string(), self.c.to_string()); } }
+impl<T: Scalar> Capsule<T>  { pub fn to_string(&self) -> String { return format!("{{ a: {}, b: {}, r: {} }}", self.a.to_string(), self.b.to_string(), self.radius); } }
+
+// Queries
+impl<T: Scalar> Plane<T>    { pub fn contains_point(&self, p: &Vector3<T>) -> bool { return self.signed_distance(p).abs() <= T::TOLERANCE; } }
+impl<T: Scalar> Sphere<T>   { pub fn contains_point(&self, p: &Vector3<T>) -> bool { return ((*p) - self.center).magnitude_sqr() <= self.radius * self.radius; } }
+impl<T: Scalar> Aabb<T>     { pub fn contains_point(&self, p: &Vector3<T>) -> bool {
+    return (0..3).all(|i| p[i] >= self.min[i] && p[i] <= self.max[i]); } }
+impl<T: Scalar> Aabb<T>     { pub fn contains(&self, b: &Self) -> bool {
+    return (0..3).all(|i| b.min[i] >= self.min[i] && b.max[i] <= self.max[i]); } }
+impl<T: Scalar> Aabb<T>     { pub fn is_empty(&self)          -> bool { return (0..3).any(|i| self.min[i] > self.max[i]); } }
+impl<T: Scalar> Obb<T>      { pub fn contains_point(&self, p: &Vector3<T>) -> bool {
+    let d = (*p) - self.center;
+    return (0..3).all(|i| (d * self.axes[i]).abs() <= self.half_extents[i]); } }
+impl<T: Scalar> Capsule<T>  { pub fn contains_point(&self, p: &Vector3<T>) -> bool {
+    let ab = self.b - self.a;
+    let t = if ab.magnitude_sqr() > T::ZERO { T::max(T::ZERO, T::min(T::ONE, (((*p) - self.a) * ab) / ab.magnitude_sqr())) } else { T::ZERO };
+    return ((*p) - (self.a + ab * t)).magnitude_sqr() <= self.radius * self.radius; } }
+impl<T: Scalar> Frustum<T>  { pub fn contains_point(&self, p: &Vector3<T>) -> bool { return self.planes.iter().all(|plane| plane.signed_distance(p) >= T::ZERO); } }
+impl<T: Scalar> Plane<T>    { pub fn approx_eq(&self, p: &Self) -> bool { return self.normal.approx_eq(&p.normal) && (self.d - p.d).abs() <= T::TOLERANCE; } }
+impl<T: Scalar> Sphere<T>   { pub fn approx_eq(&self, s: &Self) -> bool { return self.center.approx_eq(&s.center) && (self.radius - s.radius).abs() <= T::TOLERANCE; } }
+impl<T: Scalar> Aabb<T>     { pub fn approx_eq(&self, b: &Self) -> bool { return self.min.approx_eq(&b.min) && self.max.approx_eq(&b.max); } }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn planes_triangles_and_rays() {
+        let p = Plane::from_points(Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 1.0));
+        assert!(p.normal().approx_eq(&Vector3::up()) && p.signed_distance(&Vector3::new(5.0, 5.0, 3.0)) == 2.0);
+        assert!(p.project(&Vector3::new(2.0, 3.0, -4.0)).approx_eq(&Vector3::new(2.0, 3.0, 1.0)));
+
+        let t = Triangle::new(Vector3::zero(), Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+        let uvw = t.barycentric(&Vector3::new(0.5, 1.0, 0.0));
+        assert!(uvw.approx_eq(&Vector3::new(0.25, 0.25, 0.5)) && t.from_barycentric(&uvw).approx_eq(&Vector3::new(0.5, 1.0, 0.0)));
+        assert_eq!(t.area(), 2.0);
+
+        let r = Ray::from_points(Vector3::zero(), Vector3::new(0.0, 0.0, 4.0));
+        assert!(r.point_at(2.0).approx_eq(&Vector3::new(0.0, 0.0, 2.0)));
+    }
+
+    #[test]
+    fn bounding_volumes_and_merging() {
+        let points = [Vector3::new(1.0, -2.0, 0.0), Vector3::new(-1.0, 4.0, 2.0), Vector3::new(0.0, 0.0, -3.0)];
+        let b = Aabb::from_points(&points);
+        assert!(b.approx_eq(&Aabb::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(1.0, 4.0, 2.0))));
+        let s = Sphere::from_points(&points);
+        assert!(points.iter().all(|p| s.contains_point(p)));
+        let merged = Sphere::merge(&Sphere::new(Vector3::zero(), 1.0), &Sphere::new(Vector3::new(4.0, 0.0, 0.0), 1.0));
+        assert!(merged.approx_eq(&Sphere::new(Vector3::new(2.0, 0.0, 0.0), 3.0)));
+        assert!(Aabb::merge(&Aabb::empty(), &b).approx_eq(&b) && Aabbd::empty().is_empty());
+        assert!(Capsule::new(Vector3::zero(), Vector3::up(), 0.5).to_aabb().contains_point(&Vector3::new(0.5, 0.5, 1.5)));
+    }
+
+    #[test]
+    fn transformation_agrees_with_the_corners() {
+        let b = Aabb::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(1.0, 4.0, 2.0));
+        let m = Transform::new(Vector3::new(1.0, 2.0, 3.0), Quaternion::rotation(Deg(30.0), Vector3::new(1.0, 1.0, 0.0).normalization()), Vector3::new(2.0, 1.0, 1.0)).to_matrix4();
+        let corners: Vec<Vector3<f64>> = b.corners().iter().map(|c| m.transform_point(c)).collect();
+        assert!((m * b).approx_eq(&Aabb::from_points(&corners)));
+        let o = Obb::from_aabb(&b).transform(&Matrix4::from_quaternion(&Quaternion::rotation(Deg(45.0), Vector3::up())));
+        assert!(o.to_aabb().approx_eq(&Aabb::from_points(&o.corners())));
+        let p = Plane::from_point_normal(Vector3::up(), Vector3::up());
+        let q = Vector3::new(0.5, 0.5, 1.0);
+        assert!((m * p).unwrap().contains_point(&m.transform_point(&q)));
+        // Planes and frusta need the inverse, which a flattening matrix does not have
+        let flat = Matrix4::scale_vector(Vector3::new(1.0, 1.0, 0.0));
+        assert!((flat * p).is_none() && Frustum::from_matrix(&m, DepthRange::NegativeOneToOne).transform(&flat).is_none());
+    }
+
+    #[test]
+    fn frustum_from_view_projection() {
+        let view_projection = Matrix4::perspective(Deg(90.0), 1.0, 1.0, 10.0) * Matrix4::look_at_rh(Vector3::zero(), Vector3::forth(), Vector3::up());
+        let f = Frustum::from_matrix(&view_projection, DepthRange::NegativeOneToOne);
+        assert!(f.contains_point(&Vector3::new(0.0, 5.0, 0.0)) && !f.contains_point(&Vector3::new(0.0, 0.5, 0.0)));
+        assert!(!f.contains_point(&Vector3::new(6.0, 5.0, 0.0)) && !f.contains_point(&Vector3::new(0.0, 11.0, 0.0)));
+        assert!(f.near().normal().approx_eq(&Vector3::forth()) && (f.near().d() + 1.0).abs() < 1e-9);
+        let corners = Frustum::corners(&view_projection, DepthRange::NegativeOneToOne).unwrap();
+        assert!(corners[0].approx_eq(&Vector3::new(-1.0, 1.0, -1.0)) && corners[7].approx_eq(&Vector3::new(10.0, 10.0, 10.0)));
+        assert!(Frustum::corners(&Matrix4::scale_vector(Vector3::new(1.0, 1.0, 0.0)), DepthRange::NegativeOneToOne).is_none());
+    }
+
+    #[test]
+    fn frustum_from_reversed_z() {
+        // The same volume as with the OpenGL depth range, with the near plane at depth 1
+        let view = Matrix4::look_at_rh(Vector3::zero(), Vector3::forth(), Vector3::up());
+        let reversed = Matrix4::perspective_reversed_z(Deg(90.0), 1.0, 1.0, 10.0) * view;
+        let f = Frustum::from_matrix(&reversed, DepthRange::ZeroToOne);
+        let g = Frustum::from_matrix(&(Matrix4::perspective(Deg(90.0), 1.0, 1.0, 10.0) * view), DepthRange::NegativeOneToOne);
+        assert!((0..6).all(|n| f.plane(n).approx_eq(&g.plane(n))));
+        assert!(f.contains_point(&Vector3::new(0.0, 5.0, 0.0)) && !f.contains_point(&Vector3::new(0.0, 0.5, 0.0)) && !f.contains_point(&Vector3::new(0.0, 11.0, 0.0)));
+        let corners = Frustum::corners(&reversed, DepthRange::ZeroToOne).unwrap();
+        assert!(corners[0].approx_eq(&Vector3::new(-10.0, 10.0, -10.0)) && corners[7].approx_eq(&Vector3::new(1.0, 1.0, 1.0)));
+        // Without a far plane, nothing is too far away
+        let infinite = Frustum::from_matrix(&(Matrix4::perspective_infinite_reversed_z(Deg(90.0), 1.0, 1.0) * view), DepthRange::ZeroToOne);
+        assert!(infinite.near().approx_eq(&f.near()) && infinite.far() == infinite.near() && infinite.contains_point(&Vector3::new(0.0, 1.0e6, 0.0)));
+    }
+}
@@ -0,0 +1,385 @@
+// Convex collision detection through support functions: GJK for overlap and distance, EPA for penetration
+//      Guidance: van den Bergen, "Collision Detection in Interactive 3D Environments" (Morgan Kaufmann, 2003)
+//      GJK walkthrough: https://caseymuratori.com/blog_0003
+//      EPA: https://dyn4j.org/2010/05/epa-expanding-polytope-algorithm/
+use super::*;
+
+/*
+    Convex conventions:
+    - A shape only has to answer one question: which of its points lies furthest along a direction (support()).
+      Directions need not be unit length, and ties may be broken any way.
+    - Both algorithms work on the Minkowski difference A - B. The shapes overlap when it contains the origin.
+    - Touching counts as overlapping.
+    - Contact normals point from A towards B: moving B by normal * depth (or A by the opposite) separates them.
+    - Curved shapes (spheres, capsules) are approached by polytopes, so distances and depths on them are accurate
+      to about the tolerance rather than exact. EPA converges slowly on them, and a deep overlap of two curved
+      shapes can run out of iterations. The contact is then marked as not converged, and its normal is found by
+      searching directions for the shallowest way out instead. Its depth always separates the shapes along its
+      normal, but may be a little more than the least.
+*/
+
+// Struct Definitions
+pub trait Support<T: Scalar> { fn support(&self, direction: &Vector3<T>) -> Vector3<T>; }
+//      The convex hull of a point cloud (the points need not be on the hull)
+#[derive(Debug, Clone, PartialEq)] pub struct ConvexHull<T: Scalar = f32> { points: Vec<Vector3<T>> }
+//      Every a + b, for sweeping and rounding shapes (a box plus a sphere is a rounded box)
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct MinkowskiSum<A, B> { a: A, b: B }
+//      Closest points between separated shapes
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Separation<T: Scalar = f32> { distance: T, point_a: Vector3<T>, point_b: Vector3<T> }
+//      Deepest points of overlapping shapes (point_a is inside B and point_b inside A, point_a - point_b = normal * depth)
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Contact<T: Scalar = f32> { normal: Vector3<T>, depth: T, point_a: Vector3<T>, point_b: Vector3<T>, converged: bool }
+
+// Precision aliases (the bare names default to f32)
+pub type ConvexHullf    = ConvexHull<f32>;      pub type ConvexHulld    = ConvexHull<f64>;
+pub type Separationf    = Separation<f32>;      pub type Separationd    = Separation<f64>;
+pub type Contactf       = Contact<f32>;         pub type Contactd       = Contact<f64>;
+
+// Construction
+//      None for no points (an empty hull has no support)
+impl<T: Scalar> ConvexHull<T> { pub fn new(points: Vec<Vector3<T>>)      -> Option<Self> { return if points.is_empty() { None } else { Some(Self { points }) }; } }
+impl<T: Scalar> ConvexHull<T> { pub fn from_slice(points: &[Vector3<T>]) -> Option<Self> { return Self::new(points.to_vec()); } }
+impl<A, B> MinkowskiSum<A, B> { pub fn new(a: A, b: B) -> Self { return Self { a, b }; } }
+
+// Field Interpretation
+impl<T: Scalar> ConvexHull<T> { pub fn points(&self)      -> &[Vector3<T>] { return &self.points; } }
+impl<A, B> MinkowskiSum<A, B> { pub fn a(&self)           -> &A { return &self.a; } }
+impl<A, B> MinkowskiSum<A, B> { pub fn b(&self)           -> &B { return &self.b; } }
+impl<T: Scalar> Separation<T> { pub fn distance(&self)    -> T { return self.distance; } }
+impl<T: Scalar> Separation<T> { pub fn point_a(&self)     -> Vector3<T> { return self.point_a; } }
+impl<T: Scalar> Separation<T> { pub fn point_b(&self)     -> Vector3<T> { return self.point_b; } }
+//          Unit direction from A to B
+impl<T: Scalar> Separation<T> { pub fn normal(&self)      -> Vector3<T> { return (self.point_b - self.point_a) * (T::ONE / self.distance); } }
+impl<T: Scalar> Contact<T>    { pub fn normal(&self)      -> Vector3<T> { return self.normal; } }
+impl<T: Scalar> Contact<T>    { pub fn depth(&self)       -> T { return self.depth; } }
+impl<T: Scalar> Contact<T>    { pub fn point_a(&self)     -> Vector3<T> { return self.point_a; } }
+impl<T: Scalar> Contact<T>    { pub fn point_b(&self)     -> Vector3<T> { return self.point_b; } }
+//          False when EPA ran out of iterations (or its polytope tore) and the normal was searched for instead
+impl<T: Scalar> Contact<T>    { pub fn converged(&self)   -> bool { return self.converged; } }
+
+// Support Functions
+fn unit_or_any<T: Scalar>(d: &Vector3<T>) -> Vector3<T> {
+    let m = d.magnitude();
+    return if m > T::ZERO { (*d) * (T::ONE / m) } else { Vector3::right() }; }
+//      A unit vector perpendicular to d, from the axis least aligned with it
+fn perpendicular<T: Scalar>(d: &Vector3<T>) -> Vector3<T> {
+    let axes = [Vector3::right(), Vector3::forth(), Vector3::up()];
+    let least = axes.iter().fold(axes[0], |m, a| if ((*a) * (*d)).abs() < (m * (*d)).abs() { *a } else { m });
+    return ((*d) / least).normalization(); }
+impl<T: Scalar> Support<T> for Vector3<T>    { fn support(&self, _: &Vector3<T>) -> Vector3<T> { return *self; } }
+impl<T: Scalar> Support<T> for Sphere<T>     { fn support(&self, d: &Vector3<T>) -> Vector3<T> { return self.center() + unit_or_any(d) * self.radius(); } }
+impl<T: Scalar> Support<T> for Aabb<T>       { fn support(&self, d: &Vector3<T>) -> Vector3<T> {
+    let (min, max) = (self.min(), self.max());
+    return Vector3::new(
+        if d.x() >= T::ZERO { max.x() } else { min.x() },
+        if d.y() >= T::ZERO { max.y() } else { min.y() },
+        if d.z() >= T::ZERO { max.z() } else { min.z() }); } }
+impl<T: Scalar> Support<T> for Obb<T>        { fn support(&self, d: &Vector3<T>) -> Vector3<T> {
+    let mut p = self.center();
+    for i in 0..3 {
+        let h = self.half_extents()[i];
+        p += self.axis(i) * (if (*d) * self.axis(i) >= T::ZERO { h } else { -h });
+    }
+    return p; } }
+impl<T: Scalar> Support<T> for Capsule<T>    { fn support(&self, d: &Vector3<T>) -> Vector3<T> {
+    let end = if (*d) * self.a() > (*d) * self.b() { self.a() } else { self.b() };
+    return end + unit_or_any(d) * self.radius(); } }
+impl<T: Scalar> Support<T> for Triangle<T>   { fn support(&self, d: &Vector3<T>) -> Vector3<T> { return furthest(&self.vertices(), d); } }
+impl<T: Scalar> Support<T> for ConvexHull<T> { fn support(&self, d: &Vector3<T>) -> Vector3<T> { return furthest(&self.points, d); } }
+impl<T: Scalar, A: Support<T>, B: Support<T>> Support<T> for MinkowskiSum<A, B> { fn support(&self, d: &Vector3<T>) -> Vector3<T> {
+    return self.a.support(d) + self.b.support(d); } }
+fn furthest<T: Scalar>(points: &[Vector3<T>], d: &Vector3<T>) -> Vector3<T> {
+    let mut best = points[0];
+    for p in points.iter() { if (*p) * (*d) > best * (*d) { best = *p; } }
+    return best; }
+
+// Minkowski Difference
+//      A vertex of A - B, remembering the points of A and B it came from (for the closest and contact points)
+#[derive(Debug, Copy, Clone)] struct Vertex<T: Scalar> { w: Vector3<T>, a: Vector3<T>, b: Vector3<T> }
+fn support_difference<T: Scalar, A: Support<T>, B: Support<T>>(a: &A, b: &B, d: &Vector3<T>) -> Vertex<T> {
+    let (pa, pb) = (a.support(d), b.support(&(-(*d))));
+    return Vertex { w: pa - pb, a: pa, b: pb }; }
+//      The same barycentric weights applied to the source points
+fn source_points<T: Scalar>(vertices: &[Vertex<T>], weights: &[T]) -> (Vector3<T>, Vector3<T>) {
+    let (mut pa, mut pb) = (Vector3::zero(), Vector3::zero());
+    for (v, l) in vertices.iter().zip(weights.iter()) { pa += v.a * (*l); pb += v.b * (*l); }
+    return (pa, pb); }
+const MAX_ITERATIONS: usize = 64;
+
+// GJK
+//      The closest point of the simplex to the origin, shrinking the simplex to the vertices that support it
+//      (Johnson's sub-algorithm by Voronoi regions). Returns the point, or None when the tetrahedron holds the origin.
+fn closest_on_simplex<T: Scalar>(simplex: &mut Vec<Vertex<T>>, weights: &mut Vec<T>) -> Option<Vector3<T>> {
+    let origin = Vector3::zero();
+    match simplex.len() {
+        1 => { *weights = vec![T::ONE]; }
+        2 => {
+            let (a, b) = (simplex[0].w, simplex[1].w);
+            let ab = b - a;
+            let t = if ab * ab > T::ZERO { T::max(T::ZERO, T::min(T::ONE, -(a * ab) / (ab * ab))) } else { T::ZERO };
+            if t <= T::ZERO { simplex.truncate(1); *weights = vec![T::ONE]; }
+            else if t >= T::ONE { simplex.remove(0); *weights = vec![T::ONE]; }
+            else { *weights = vec![T::ONE - t, t]; }
+        }
+        3 => {
+            let uvw = Triangle::new(simplex[0].w, simplex[1].w, simplex[2].w).closest_point_barycentric(&origin);
+            let (kept, l): (Vec<Vertex<T>>, Vec<T>) = simplex.iter().zip([uvw.x(), uvw.y(), uvw.z()].iter())
+                .filter(|(_, l)| **l > T::ZERO).map(|(v, l)| (*v, *l)).unzip();
+            *simplex = kept;
+            *weights = l;
+        }
+        _ => {
+            // Faces with the origin on their outer side (away from the opposite vertex) are candidates
+            let faces = [[0, 1, 2, 3], [0, 1, 3, 2], [0, 2, 3, 1], [1, 2, 3, 0]];
+            let mut best: Option<(T, Vec<Vertex<T>>, Vec<T>)> = None;
+            for f in faces.iter() {
+                let (a, b, c, d) = (simplex[f[0]].w, simplex[f[1]].w, simplex[f[2]].w, simplex[f[3]].w);
+                let n = (b - a) / (c - a);
+                let (side_origin, side_opposite) = (n * (origin - a), n * (d - a));
+                if side_origin * side_opposite >= T::ZERO && side_opposite != T::ZERO { continue; }
+                let mut face = vec![simplex[f[0]], simplex[f[1]], simplex[f[2]]];
+                let mut l = Vec::new();
+                let p = closest_on_simplex(&mut face, &mut l)?;
+                if !matches!(best, Some((distance, _, _)) if p * p >= distance) { best = Some((p * p, face, l)); }
+            }
+            let (_, face, l) = best?;
+            *simplex = face;
+            *weights = l;
+        }
+    }
+    let mut p = Vector3::zero();
+    for (v, l) in simplex.iter().zip(weights.iter()) { p += v.w * (*l); }
+    return Some(p); }
+//      Runs GJK until the simplex holds the origin (Err, with the simplex for EPA) or stops getting closer (Ok)
+fn gjk<T: Scalar, A: Support<T>, B: Support<T>>(a: &A, b: &B) -> Result<Separation<T>, Vec<Vertex<T>>> {
+    let first = support_difference(a, b, &Vector3::right());
+    let mut simplex = vec![first];
+    let mut weights = vec![T::ONE];
+    let mut v = first.w;
+    for _ in 0..MAX_ITERATIONS {
+        let v_sqr = v * v;
+        if v_sqr <= T::TOLERANCE * T::TOLERANCE { return Err(simplex); }
+        let w = support_difference(a, b, &(-v));
+        // No point of A - B lies closer to the origin along v than the simplex already does
+        if v_sqr - v * w.w <= T::TOLERANCE * v_sqr || simplex.iter().any(|s| s.w == w.w) { break; }
+        simplex.push(w);
+        match closest_on_simplex(&mut simplex, &mut weights) {
+            Some(p) => v = p,
+            None => return Err(simplex),
+        }
+    }
+    let (pa, pb) = source_points(&simplex, &weights);
+    return Ok(Separation { distance: v.magnitude(), point_a: pa, point_b: pb }); }
+pub fn gjk_intersects<T: Scalar, A: Support<T>, B: Support<T>>(a: &A, b: &B) -> bool { return gjk(a, b).is_err(); }
+//      None when the shapes overlap
+pub fn gjk_distance<T: Scalar, A: Support<T>, B: Support<T>>(a: &A, b: &B) -> Option<Separation<T>> { return gjk(a, b).ok(); }
+
+// EPA
+//      Grows GJK's final simplex (which may be a point, segment or triangle when the origin lies on its boundary)
+//      into a tetrahedron around the origin by searching along directions it does not span yet
+fn inflate<T: Scalar, A: Support<T>, B: Support<T>>(a: &A, b: &B, simplex: &mut Vec<Vertex<T>>) -> bool {
+    let axes = [Vector3::right(), Vector3::forth(), Vector3::up()];
+    if simplex.len() == 1 {
+        for d in axes.iter().flat_map(|d| vec![*d, -(*d)]) {
+            let v = support_difference(a, b, &d);
+            if (v.w - simplex[0].w).magnitude_sqr() > T::TOLERANCE { simplex.push(v); break; }
+        }
+    }
+    if simplex.len() == 2 {
+        let line = simplex[1].w - simplex[0].w;
+        let mut d = perpendicular(&line);
+        let turn = Quaternion::rotation(Deg(T::from_f32(60.0)), line.normalization());
+        for _ in 0..6 {
+            let v = support_difference(a, b, &d);
+            if ((v.w - simplex[0].w) / line).magnitude_sqr() > T::TOLERANCE { simplex.push(v); break; }
+            d = turn.rotate(&d);
+        }
+    }
+    if simplex.len() == 3 {
+        let n = (simplex[1].w - simplex[0].w) / (simplex[2].w - simplex[0].w);
+        for d in [n, -n].iter() {
+            let v = support_difference(a, b, d);
+            if (n * (v.w - simplex[0].w)).abs() > T::TOLERANCE { simplex.push(v); break; }
+        }
+    }
+    return simplex.len() == 4; }
+struct Face<T: Scalar> { v: [usize; 3], normal: Vector3<T>, distance: T }
+//      Counter-clockwise seen from outside
+fn face<T: Scalar>(vertices: &[Vertex<T>], i: usize, j: usize, k: usize) -> Face<T> {
+    let a = vertices[i].w;
+    let n = ((vertices[j].w - a) / (vertices[k].w - a)).normalization();
+    return Face { v: [i, j, k], normal: n, distance: n * a }; }
+fn closest_face<T: Scalar>(faces: &[Face<T>]) -> usize {
+    return (0..faces.len()).fold(0, |m, f| if faces[f].distance < faces[m].distance { f } else { m }); }
+//      Expands the polytope towards the boundary of A - B, always at the face closest to the origin,
+//      until that face is (within tolerance) on the boundary. Its normal and distance are the contact normal and depth.
+pub fn epa_penetration<T: Scalar, A: Support<T>, B: Support<T>>(a: &A, b: &B) -> Option<Contact<T>> {
+    let mut vertices = gjk(a, b).err()?;
+    if !inflate(a, b, &mut vertices) {
+        // Flat overlap: A - B has no volume around the origin, so the shapes only touch. The normal is the one of the
+        // flat simplex (either side), any perpendicular of a segment, or right() when A - B is a single point.
+        let normal = match vertices.len() {
+            3 => ((vertices[1].w - vertices[0].w) / (vertices[2].w - vertices[0].w)).normalization(),
+            2 => perpendicular(&(vertices[1].w - vertices[0].w)),
+            _ => Vector3::right(),
+        };
+        return Some(Contact { normal, depth: T::ZERO, point_a: vertices[0].a, point_b: vertices[0].b, converged: true });
+    }
+    // Wind the tetrahedron outwards
+    if ((vertices[1].w - vertices[0].w) / (vertices[2].w - vertices[0].w)) * (vertices[3].w - vertices[0].w) > T::ZERO { vertices.swap(1, 2); }
+    let mut faces = vec![face(&vertices, 0, 1, 2), face(&vertices, 0, 3, 1), face(&vertices, 0, 2, 3), face(&vertices, 1, 3, 2)];
+    // Curved shapes only converge linearly, so aim for half the digits (much tighter, and the new faces become
+    // slivers with unreliable normals)
+    let epsilon = T::sqrt(T::EPSILON) * T::max(T::ONE, vertices.iter().fold(T::ZERO, |m, v| T::max(m, v.w.magnitude())));
+    let (mut closest, mut converged) = (0, false);
+    for _ in 0..MAX_ITERATIONS * 2 {
+        closest = closest_face(&faces);
+        let (n, distance) = (faces[closest].normal, faces[closest].distance);
+        let w = support_difference(a, b, &n);
+        if w.w * n - distance <= epsilon { converged = true; break; }
+        // Remove the faces that see the new vertex, flooding out from the closest one so the hole stays in one piece
+        // (faces the vertex only just misses go too, or boxes with their many coplanar points tear the polytope).
+        // The edges around the hole (the horizon) then get stitched to the new vertex.
+        let mut removed = vec![false; faces.len()];
+        let mut stack = vec![closest];
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        let mut torn = false;
+        removed[closest] = true;
+        while let Some(f) = stack.pop() {
+            let v = faces[f].v;
+            for (i, j) in [(v[0], v[1]), (v[1], v[2]), (v[2], v[0])].iter() {
+                // An edge without a neighbour means rounding tore the polytope: stop with what it has
+                let neighbour = match (0..faces.len()).find(|g| { let u = faces[*g].v; return (u[0] == *j && u[1] == *i) || (u[1] == *j && u[2] == *i) || (u[2] == *j && u[0] == *i); }) {
+                    Some(g) => g,
+                    None => { torn = true; break; }
+                };
+                if removed[neighbour] { continue; }
+                if faces[neighbour].normal * (w.w - vertices[faces[neighbour].v[0]].w) > -epsilon {
+                    removed[neighbour] = true;
+                    stack.push(neighbour);
+                } else {
+                    horizon.push((*i, *j));
+                }
+            }
+            if torn { break; }
+        }
+        if torn { break; }
+        vertices.push(w);
+        let new = vertices.len() - 1;
+        let mut kept: Vec<Face<T>> = faces.into_iter().zip(removed.iter()).filter(|(_, r)| !**r).map(|(f, _)| f).collect();
+        for (i, j) in horizon.iter() { kept.push(face(&vertices, *i, *j, new)); }
+        faces = kept;
+    }
+    let f = &faces[closest];
+    if !converged {
+        let (normal, w) = shallowest(a, b, &f.normal);
+        return Some(Contact { normal, depth: w.w * normal, point_a: w.a, point_b: w.b, converged }); }
+    // Contact points from where the origin projects onto the closest face
+    let (fa, fb, fc) = (vertices[f.v[0]], vertices[f.v[1]], vertices[f.v[2]]);
+    let uvw = Triangle::new(fa.w, fb.w, fc.w).barycentric(&(f.normal * f.distance));
+    let (pa, pb) = source_points(&[fa, fb, fc], &[uvw.x(), uvw.y(), uvw.z()]);
+    return Some(Contact { normal: f.normal, depth: f.distance, point_a: pa, point_b: pb, converged }); }
+//      Compass search over unit directions n, from a starting one, for the least support distance of A - B along n.
+//      Moving B that far along n separates the shapes, so the least of them is the penetration depth.
+fn shallowest<T: Scalar, A: Support<T>, B: Support<T>>(a: &A, b: &B, start: &Vector3<T>) -> (Vector3<T>, Vertex<T>) {
+    let (mut n, mut w) = (*start, support_difference(a, b, start));
+    let mut step = T::from_f32(0.25);
+    for _ in 0..MAX_ITERATIONS * 4 {
+        if step < T::sqrt(T::EPSILON) { break; }
+        let u = perpendicular(&n);
+        let v = n / u;
+        let mut best: Option<(Vector3<T>, Vertex<T>)> = None;
+        for d in [u, -u, v, -v].iter() {
+            let m = (n + (*d) * step).normalization();
+            let x = support_difference(a, b, &m);
+            let lowest = match best { Some((bn, bw)) => bw.w * bn, None => w.w * n };
+            if x.w * m < lowest { best = Some((m, x)); }
+        }
+        match best { Some((m, x)) => { n = m; w = x; } None => step *= T::HALF }
+    }
+    return (n, w); }
+
+// Precision conversion
+impl<T: Scalar> ConvexHull<T> { pub fn cast<U: Scalar>(&self) -> ConvexHull<U> { return ConvexHull { points: self.points.iter().map(|p| p.cast()).collect() }; } }
+impl<T: Scalar> Separation<T> { pub fn cast<U: Scalar>(&self) -> Separation<U> {
+    return Separation { distance: U::from_f64(self.distance.to_f64()), point_a: self.point_a.cast(), point_b: self.point_b.cast() }; } }
+impl<T: Scalar> Contact<T>    { pub fn cast<U: Scalar>(&self) -> Contact<U> {
+    return Contact { normal: self.normal.cast(), depth: U::from_f64(self.depth.to_f64()), point_a: self.point_a.cast(), point_b: self.point_b.cast(), converged: self.converged }; } }
+impl From<ConvexHull<f32>> for ConvexHull<f64> { fn from(h: ConvexHull<f32>) -> Self { return h.cast(); } }
+impl From<Separation<f32>> for Separation<f64> { fn from(s: Separation<f32>) -> Self { return s.cast(); } }
+impl From<Contact<f32>> for Contact<f64>       { fn from(c: Contact<f32>) -> Self { return c.cast(); } }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separation_of_polytopes_and_spheres() {
+        // Exact on polytopes, to about the tolerance on curved shapes
+        let (b, s) = (Aabb::new(Vector3::new(-1.0f64, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0)), Sphere::new(Vector3::new(4.0, 0.0, 0.0), 1.0));
+        let sep = gjk_distance(&b, &s).unwrap();
+        assert!((sep.distance() - 2.0).abs() < 1e-6 && sep.normal().approx_eq_eps(&Vector3::right(), 1e-6, TestMode::AbsoluteCoordinates));
+        assert!(sep.point_a().approx_eq_eps(&Vector3::new(1.0, 0.0, 0.0), 1e-6, TestMode::AbsoluteCoordinates));
+        let corner = Aabb::new(Vector3::new(2.0, 3.0, 1.5), Vector3::new(4.0, 4.0, 4.0));
+        let sep = gjk_distance(&b, &corner).unwrap();
+        assert!((sep.distance() - 5.25f64.sqrt()).abs() < 1e-12);
+        assert!(sep.point_a().approx_eq(&Vector3::new(1.0, 1.0, 1.0)) && sep.point_b().approx_eq(&Vector3::new(2.0, 3.0, 1.5)));
+    }
+
+    #[test]
+    fn overlap() {
+        let b = Aabb::new(Vector3::new(-1.0f64, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+        assert!(!gjk_intersects(&b, &Sphere::new(Vector3::new(4.0, 0.0, 0.0), 1.0)));
+        assert!(gjk_intersects(&b, &Sphere::new(Vector3::new(1.5, 0.0, 0.0), 1.0)));
+        assert!(gjk_distance(&b, &Vector3::new(0.5, 0.5, 0.5)).is_none());
+    }
+
+    #[test]
+    fn penetration() {
+        // Exact on boxes
+        let b = Aabb::new(Vector3::new(-1.0f64, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+        let o = Obb::from_aabb(&Aabb::new(Vector3::new(0.5, -0.5, -0.5), Vector3::new(2.5, 0.5, 0.5)));
+        let contact = epa_penetration(&b, &o).unwrap();
+        assert!((contact.depth() - 0.5).abs() < 1e-12 && contact.normal().approx_eq(&Vector3::right()));
+        assert!((contact.point_a() - contact.point_b()).approx_eq(&(contact.normal() * contact.depth())));
+        assert!(epa_penetration(&b, &Sphere::new(Vector3::new(4.0, 0.0, 0.0), 1.0)).is_none());
+        let spheres = epa_penetration(&Sphere::new(Vector3::zero(), 1.0), &Sphere::new(Vector3::new(0.0, 1.5, 0.0), 1.0)).unwrap();
+        assert!((spheres.depth() - 0.5).abs() < 1e-4 && spheres.normal().approx_eq_eps(&Vector3::forth(), 1e-3, TestMode::AbsoluteCoordinates));
+    }
+
+    #[test]
+    fn deep_penetration_of_curved_shapes() {
+        let a = Sphere::new(Vector3::zero(), 1.0);
+        for (b, depth, normal) in [(Sphere::new(Vector3::new(0.0, 0.1, 0.0), 1.0), 1.9, Some(Vector3::forth())), (Sphere::new(Vector3::zero(), 1.0), 2.0, None)].iter() {
+            let contact = epa_penetration(&a, b).unwrap();
+            assert!((contact.depth() - depth).abs() < 1e-6, "{}", contact.depth());
+            if let Some(n) = normal { assert!(contact.normal().approx_eq_eps(n, 1e-4, TestMode::AbsoluteCoordinates)); }
+            // Pushing B out along the normal by the depth separates the shapes
+            let pushed = Sphere::new(b.center() + contact.normal() * (contact.depth() + 1e-6), 1.0);
+            assert!(!gjk_intersects(&a, &pushed));
+        }
+        let capsule = Capsule::new(Vector3::new(-2.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0), 1.0);
+        let contact = epa_penetration(&capsule, &Sphere::new(Vector3::new(0.5, 0.0, 0.3), 1.0)).unwrap();
+        assert!((contact.depth() - 1.7).abs() < 1e-6 && contact.normal().approx_eq_eps(&Vector3::up(), 1e-3, TestMode::AbsoluteCoordinates));
+    }
+
+    #[test]
+    fn touching_shapes() {
+        // Flat boxes meeting face to face give a flat A - B, with the face normal
+        let a = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+        let b = Aabb::new(Vector3::new(0.5, 0.5, 0.0), Vector3::new(2.0, 2.0, 0.0));
+        let contact = epa_penetration(&a, &b).unwrap();
+        assert!(contact.depth() == 0.0 && contact.converged() && (contact.normal() / Vector3::up()).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn point_clouds_and_minkowski_sums() {
+        // A box plus a sphere is a rounded box
+        let b = Aabb::new(Vector3::new(-1.0f64, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+        let hull = ConvexHull::new(vec![Vector3::new(3.0, 0.0, 0.0), Vector3::new(5.0, 1.0, 0.0), Vector3::new(5.0, -1.0, 0.0), Vector3::new(4.0, 0.0, 2.0), Vector3::new(4.5, 0.0, 0.5)]).unwrap();
+        assert!(ConvexHulld::new(Vec::new()).is_none());
+        assert!((gjk_distance(&b, &hull).unwrap().distance() - 2.0).abs() < 1e-12);
+        let rounded = MinkowskiSum::new(b, Sphere::new(Vector3::zero(), 0.5));
+        assert!((gjk_distance(&rounded, &hull).unwrap().distance() - 1.5).abs() < 1e-6);
+        assert!(gjk_intersects(&MinkowskiSum::new(b, Sphere::new(Vector3::zero(), 2.5)), &hull));
+    }
+}
@@ -0,0 +1,393 @@
+// Intersection and closest-point queries between the geometric primitives
+//      Guidance: Ericson, "Real-Time Collision Detection", chapters 4 (bounding volumes) and 5 (basic primitive tests)
+use super::*;
+
+/*
+    Query conventions:
+    - Ray casts return the first hit at a distance t >= 0 along the ray (Some(hit)), or None.
+      A ray starting inside a solid (sphere, box, capsule) hits its far side from within, at the exit point.
+    - Hit normals are unit length and point out of solids. Planes and triangles report their own normal
+      (both sides are hit, so a back face has normal * direction > 0).
+    - Barycentrics (u, v, w) weigh the triangle corners (a, b, c). Other shapes report zero.
+    - Overlap tests count touching as overlapping.
+*/
+
+// Struct Definitions
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct RayHit<T: Scalar = f32> { distance: T, point: Vector3<T>, normal: Vector3<T>, barycentric: Vector3<T> }
+//      Where a shape lies relative to a frustum (Intersecting may be returned for shapes just outside a corner)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub enum Containment { Outside, Intersecting, Inside }
+
+// Precision aliases (the bare names default to f32)
+pub type RayHitf        = RayHit<f32>;          pub type RayHitd        = RayHit<f64>;
+
+// Construction
+impl<T: Scalar> RayHit<T> { fn new(ray: &Ray<T>, distance: T, normal: Vector3<T>) -> Self {
+    return Self { distance, point: ray.point_at(distance), normal, barycentric: Vector3::zero() }; } }
+
+// Field Interpretation
+impl<T: Scalar> RayHit<T> { pub fn distance(&self)        -> T { return self.distance; } }
+impl<T: Scalar> RayHit<T> { pub fn point(&self)           -> Vector3<T> { return self.point; } }
+impl<T: Scalar> RayHit<T> { pub fn normal(&self)          -> Vector3<T> { return self.normal; } }
+impl<T: Scalar> RayHit<T> { pub fn barycentric(&self)     -> Vector3<T> { return self.barycentric; } }
+
+// Utilities
+fn clamp<T: Scalar>(a: T, low: T, high: T) -> T { return T::max(low, T::min(high, a)); }
+//      Roots of a t^2 + 2 b t + c = 0 in increasing order (the halved b saves a few multiplications)
+fn solve_quadratic<T: Scalar>(a: T, b: T, c: T) -> Option<(T, T)> {
+    let discriminant = b * b - a * c;
+    if discriminant < T::ZERO || a == T::ZERO { return None; }
+    let s = T::sqrt(discriminant);
+    return Some(((-b - s) / a, (-b + s) / a)); }
+//      Slab test against the box [-h, h] for a ray in the box's frame. Returns the distance and the axis (with sign) crossed.
+//      https://tavianator.com/2011/ray_box.html
+fn slabs<T: Scalar>(origin: &Vector3<T>, direction: &Vector3<T>, h: &Vector3<T>) -> Option<(T, Vector3<T>)> {
+    let (mut t_enter, mut t_exit) = (-T::INFINITY, T::INFINITY);
+    let (mut n_enter, mut n_exit) = (Vector3::zero(), Vector3::zero());
+    for i in 0..3 {
+        if direction[i].abs() <= T::EPSILON {
+            if origin[i].abs() > h[i] { return None; }
+            continue;
+        }
+        let inverse = T::ONE / direction[i];
+        let (mut t0, mut t1) = ((-h[i] - origin[i]) * inverse, (h[i] - origin[i]) * inverse);
+        // Entering through the face facing against the ray
+        let mut n = Vector3::zero();
+        n[i] = -T::signum(direction[i]);
+        if t0 > t1 { std::mem::swap(&mut t0, &mut t1); }
+        if t0 > t_enter { t_enter = t0; n_enter = n; }
+        if t1 < t_exit { t_exit = t1; n_exit = -n; }
+        if t_enter > t_exit { return None; }
+    }
+    if t_exit < T::ZERO { return None; }
+    return if t_enter >= T::ZERO { Some((t_enter, n_enter)) } else { Some((t_exit, n_exit)) }; }
+
+// Ray Casts
+impl<T: Scalar> Ray<T> { pub fn intersect_plane(&self, p: &Plane<T>) -> Option<RayHit<T>> {
+    let denominator = p.normal() * self.direction();
+    if denominator.abs() <= T::EPSILON { return None; }
+    let t = -p.signed_distance(&self.origin()) / denominator;
+    if t < T::ZERO { return None; }
+    return Some(RayHit::new(self, t, p.normal())); } }
+impl<T: Scalar> Ray<T> { pub fn intersect_sphere(&self, s: &Sphere<T>) -> Option<RayHit<T>> {
+    let m = self.origin() - s.center();
+    let (t0, t1) = solve_quadratic(T::ONE, m * self.direction(), m * m - s.radius() * s.radius())?;
+    if t1 < T::ZERO { return None; }
+    let t = if t0 >= T::ZERO { t0 } else { t1 };
+    return Some(RayHit::new(self, t, (self.point_at(t) - s.center()) * (T::ONE / s.radius()))); } }
+impl<T: Scalar> Ray<T> { pub fn intersect_aabb(&self, b: &Aabb<T>) -> Option<RayHit<T>> {
+    let (t, n) = slabs(&(self.origin() - b.center()), &self.direction(), &b.half_extents())?;
+    return Some(RayHit::new(self, t, n)); } }
+//          The slab test in the box's frame
+impl<T: Scalar> Ray<T> { pub fn intersect_obb(&self, b: &Obb<T>) -> Option<RayHit<T>> {
+    let (o, d, axes) = (self.origin() - b.center(), self.direction(), b.axes());
+    let local = |v: &Vector3<T>| -> Vector3<T> { return Vector3::new((*v) * axes[0], (*v) * axes[1], (*v) * axes[2]); };
+    let (t, n) = slabs(&local(&o), &local(&d), &b.half_extents())?;
+    return Some(RayHit::new(self, t, axes[0] * n.x() + axes[1] * n.y() + axes[2] * n.z())); } }
+//          Möller and Trumbore, "Fast, Minimum Storage Ray/Triangle Intersection"
+//          https://cadxfem.org/inf/Fast%20MinimumStorage%20RayTriangle%20Intersection.pdf
+impl<T: Scalar> Ray<T> { pub fn intersect_triangle(&self, tri: &Triangle<T>) -> Option<RayHit<T>> {
+    let (e1, e2) = (tri.b() - tri.a(), tri.c() - tri.a());
+    let p = self.direction() / e2;
+    let det = e1 * p;
+    // Parallel when det (|e1| |e2| |d| times a sine) is small next to the lengths, so tiny triangles still hit
+    if det.abs() <= T::EPSILON * e1.magnitude() * e2.magnitude() * self.direction().magnitude() { return None; }
+    let inverse = T::ONE / det;
+    let s = self.origin() - tri.a();
+    let v = (s * p) * inverse;
+    if v < T::ZERO || v > T::ONE { return None; }
+    let q = s / e1;
+    let w = (self.direction() * q) * inverse;
+    if w < T::ZERO || v + w > T::ONE { return None; }
+    let t = (e2 * q) * inverse;
+    if t < T::ZERO { return None; }
+    let mut hit = RayHit::new(self, t, (e1 / e2).normalization());
+    hit.barycentric = Vector3::new(T::ONE - v - w, v, w);
+    return Some(hit); } }
+//          Every root on the capsule's surface (the cylinder between the ends, and each end's sphere beyond its end),
+//          keeping the nearest one ahead of the ray. Works from inside as well.
+impl<T: Scalar> Ray<T> { pub fn intersect_capsule(&self, c: &Capsule<T>) -> Option<RayHit<T>> {
+    let (o, d, r) = (self.origin(), self.direction(), c.radius());
+    let axis = c.axis();
+    let length_sqr = axis * axis;
+    let mut best: Option<(T, Vector3<T>)> = None;
+    let mut consider = |t: T, normal: Vector3<T>| {
+        if t >= T::ZERO && !matches!(best, Some((b, _)) if t >= b) { best = Some((t, normal)); } };
+    // Cylinder body: |(m + t d) - ((m + t d) * axis / |axis|^2) axis| = r
+    if length_sqr > T::ZERO {
+        let m = o - c.a();
+        let (md, ma, da) = (m * d, m * axis, d * axis);
+        let a = length_sqr - da * da;
+        let b = length_sqr * md - ma * da;
+        let k = length_sqr * (m * m - r * r) - ma * ma;
+        if let Some((t0, t1)) = solve_quadratic(a, b, k) {
+            for t in [t0, t1].iter() {
+                let s = (ma + *t * da) / length_sqr;
+                if s >= T::ZERO && s <= T::ONE {
+                    let p = self.point_at(*t);
+                    consider(*t, (p - (c.a() + axis * s)).normalization());
+                }
+            }
+        }
+    }
+    // End caps
+    for (center, outward) in [(c.a(), -T::ONE), (c.b(), T::ONE)].iter() {
+        let m = o - *center;
+        if let Some((t0, t1)) = solve_quadratic(T::ONE, m * d, m * m - r * r) {
+            for t in [t0, t1].iter() {
+                let p = self.point_at(*t);
+                if ((p - *center) * axis) * *outward >= T::ZERO { consider(*t, (p - *center) * (T::ONE / r)); }
+            }
+        }
+    }
+    return best.map(|(t, n)| RayHit::new(self, t, n)); } }
+
+// Closest Points
+//      On the segment from a to b
+pub fn closest_point_segment<T: Scalar>(p: &Vector3<T>, a: &Vector3<T>, b: &Vector3<T>) -> Vector3<T> {
+    let ab = (*b) - (*a);
+    let length_sqr = ab * ab;
+    if length_sqr <= T::ZERO { return *a; }
+    return (*a) + ab * clamp((((*p) - (*a)) * ab) / length_sqr, T::ZERO, T::ONE); }
+//      Closest points between segments p1-q1 and p2-q2 (Ericson, section 5.1.9)
+pub fn closest_points_segments<T: Scalar>(p1: &Vector3<T>, q1: &Vector3<T>, p2: &Vector3<T>, q2: &Vector3<T>) -> (Vector3<T>, Vector3<T>) {
+    let (d1, d2, r) = ((*q1) - (*p1), (*q2) - (*p2), (*p1) - (*p2));
+    let (a, e, f) = (d1 * d1, d2 * d2, d2 * r);
+    let (s, t);
+    if a <= T::EPSILON && e <= T::EPSILON { return (*p1, *p2); }
+    if a <= T::EPSILON {
+        s = T::ZERO;
+        t = clamp(f / e, T::ZERO, T::ONE);
+    } else {
+        let c = d1 * r;
+        if e <= T::EPSILON {
+            t = T::ZERO;
+            s = clamp(-c / a, T::ZERO, T::ONE);
+        } else {
+            let b = d1 * d2;
+            let denominator = a * e - b * b;
+            // Parallel segments: any s works, so pick the start
+            let s0 = if denominator > T::ZERO { clamp((b * f - c * e) / denominator, T::ZERO, T::ONE) } else { T::ZERO };
+            let t0 = (b * s0 + f) / e;
+            if t0 < T::ZERO { t = T::ZERO; s = clamp(-c / a, T::ZERO, T::ONE); }
+            else if t0 > T::ONE { t = T::ONE; s = clamp((b - c) / a, T::ZERO, T::ONE); }
+            else { t = t0; s = s0; }
+        }
+    }
+    return ((*p1) + d1 * s, (*p2) + d2 * t); }
+impl<T: Scalar> Sphere<T>   { pub fn closest_point(&self, p: &Vector3<T>) -> Vector3<T> {
+    let d = (*p) - self.center();
+    if d * d <= self.radius() * self.radius() { return *p; }
+    return self.center() + d.normalization() * self.radius(); } }
+impl<T: Scalar> Aabb<T>     { pub fn closest_point(&self, p: &Vector3<T>) -> Vector3<T> {
+    let (min, max) = (self.min(), self.max());
+    return Vector3::new(clamp(p.x(), min.x(), max.x()), clamp(p.y(), min.y(), max.y()), clamp(p.z(), min.z(), max.z())); } }
+impl<T: Scalar> Obb<T>      { pub fn closest_point(&self, p: &Vector3<T>) -> Vector3<T> {
+    let d = (*p) - self.center();
+    let mut q = self.center();
+    for i in 0..3 { q += self.axis(i) * clamp(d * self.axis(i), -self.half_extents()[i], self.half_extents()[i]); }
+    return q; } }
+impl<T: Scalar> Capsule<T>  { pub fn closest_point(&self, p: &Vector3<T>) -> Vector3<T> {
+    let c = closest_point_segment(p, &self.a(), &self.b());
+    return Sphere::new(c, self.radius()).closest_point(p); } }
+//      Barycentrics of the closest point on the triangle, by Voronoi regions (Ericson, section 5.1.5)
+impl<T: Scalar> Triangle<T> { pub fn closest_point_barycentric(&self, p: &Vector3<T>) -> Vector3<T> {
+    let (a, b, c) = (self.a(), self.b(), self.c());
+    let (ab, ac, ap) = (b - a, c - a, (*p) - a);
+    let (d1, d2) = (ab * ap, ac * ap);
+    if d1 <= T::ZERO && d2 <= T::ZERO { return Vector3::new(T::ONE, T::ZERO, T::ZERO); }
+    let bp = (*p) - b;
+    let (d3, d4) = (ab * bp, ac * bp);
+    if d3 >= T::ZERO && d4 <= d3 { return Vector3::new(T::ZERO, T::ONE, T::ZERO); }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+        let v = d1 / (d1 - d3);
+        return Vector3::new(T::ONE - v, v, T::ZERO); }
+    let cp = (*p) - c;
+    let (d5, d6) = (ab * cp, ac * cp);
+    if d6 >= T::ZERO && d5 <= d6 { return Vector3::new(T::ZERO, T::ZERO, T::ONE); }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+        let w = d2 / (d2 - d6);
+        return Vector3::new(T::ONE - w, T::ZERO, w); }
+    let va = d3 * d6 - d5 * d4;
+    if va <= T::ZERO && (d4 - d3) >= T::ZERO && (d5 - d6) >= T::ZERO {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return Vector3::new(T::ZERO, T::ONE - w, w); }
+    let d = T::ONE / (va + vb + vc);
+    let (v, w) = (vb * d, vc * d);
+    return Vector3::new(T::ONE - v - w, v, w); } }
+impl<T: Scalar> Triangle<T> { pub fn closest_point(&self, p: &Vector3<T>) -> Vector3<T> { return self.from_barycentric(&self.closest_point_barycentric(p)); } }
+
+// Overlap Tests
+impl<T: Scalar> Sphere<T>   { pub fn overlaps_sphere(&self, s: &Sphere<T>) -> bool {
+    let r = self.radius() + s.radius();
+    return (s.center() - self.center()).magnitude_sqr() <= r * r; } }
+impl<T: Scalar> Sphere<T>   { pub fn overlaps_plane(&self, p: &Plane<T>) -> bool { return p.signed_distance(&self.center()).abs() <= self.radius(); } }
+impl<T: Scalar> Sphere<T>   { pub fn overlaps_aabb(&self, b: &Aabb<T>) -> bool { return self.contains_point(&b.closest_point(&self.center())); } }
+impl<T: Scalar> Sphere<T>   { pub fn overlaps_obb(&self, b: &Obb<T>) -> bool { return self.contains_point(&b.closest_point(&self.center())); } }
+impl<T: Scalar> Sphere<T>   { pub fn overlaps_triangle(&self, t: &Triangle<T>) -> bool { return self.contains_point(&t.closest_point(&self.center())); } }
+impl<T: Scalar> Sphere<T>   { pub fn overlaps_capsule(&self, c: &Capsule<T>) -> bool {
+    return self.overlaps_sphere(&Sphere::new(closest_point_segment(&self.center(), &c.a(), &c.b()), c.radius())); } }
+impl<T: Scalar> Capsule<T>  { pub fn overlaps_capsule(&self, c: &Capsule<T>) -> bool {
+    let (p, q) = closest_points_segments(&self.a(), &self.b(), &c.a(), &c.b());
+    let r = self.radius() + c.radius();
+    return (q - p).magnitude_sqr() <= r * r; } }
+impl<T: Scalar> Aabb<T>     { pub fn overlaps_aabb(&self, b: &Aabb<T>) -> bool {
+    return (0..3).all(|i| self.min()[i] <= b.max()[i] && b.min()[i] <= self.max()[i]); } }
+//          The box's extent along the normal against the distance of its center
+impl<T: Scalar> Aabb<T>     { pub fn overlaps_plane(&self, p: &Plane<T>) -> bool {
+    let (h, n) = (self.half_extents(), p.normal());
+    let r = h.x() * n.x().abs() + h.y() * n.y().abs() + h.z() * n.z().abs();
+    return p.signed_distance(&self.center()).abs() <= r; } }
+impl<T: Scalar> Aabb<T>     { pub fn overlaps_obb(&self, b: &Obb<T>) -> bool { return Obb::from_aabb(self).overlaps_obb(b); } }
+//          Separating axis test over the 15 candidate axes: 3 + 3 face normals and 9 edge cross products (Ericson, section 4.4.1)
+//          An epsilon on the rotation keeps near-parallel edges from producing a zero cross product that separates nothing.
+impl<T: Scalar> Obb<T>      { pub fn overlaps_obb(&self, b: &Obb<T>) -> bool {
+    let (ea, eb) = (self.half_extents(), b.half_extents());
+    // b's axes in a's frame, and their absolute values
+    let mut r = [[T::ZERO; 3]; 3];
+    let mut abs_r = [[T::ZERO; 3]; 3];
+    for i in 0..3 { for j in 0..3 {
+        r[i][j] = self.axis(i) * b.axis(j);
+        abs_r[i][j] = r[i][j].abs() + T::EPSILON;
+    } }
+    let d = b.center() - self.center();
+    let t = [d * self.axis(0), d * self.axis(1), d * self.axis(2)];
+    // a's face normals
+    for i in 0..3 {
+        let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+        if t[i].abs() > ea[i] + rb { return false; }
+    }
+    // b's face normals
+    for j in 0..3 {
+        let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+        if (t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j]).abs() > ra + eb[j] { return false; }
+    }
+    // Edge cross products a_i x b_j
+    for i in 0..3 { for j in 0..3 {
+        let (i1, i2, j1, j2) = ((i + 1) % 3, (i + 2) % 3, (j + 1) % 3, (j + 2) % 3);
+        let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+        let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+        if (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb { return false; }
+    } }
+    return true; } }
+
+// Frustum Culling
+//      Per plane, the box corner furthest along the normal decides Outside and the nearest one Inside
+//      https://fgiesen.wordpress.com/2010/10/17/view-frustum-culling/
+impl<T: Scalar> Frustum<T>  { pub fn classify_aabb(&self, b: &Aabb<T>) -> Containment {
+    let (c, h) = (b.center(), b.half_extents());
+    let mut result = Containment::Inside;
+    for p in self.planes().iter() {
+        let n = p.normal();
+        let r = h.x() * n.x().abs() + h.y() * n.y().abs() + h.z() * n.z().abs();
+        let d = p.signed_distance(&c);
+        if d + r < T::ZERO { return Containment::Outside; }
+        if d - r < T::ZERO { result = Containment::Intersecting; }
+    }
+    return result; } }
+impl<T: Scalar> Frustum<T>  { pub fn classify_sphere(&self, s: &Sphere<T>) -> Containment {
+    let mut result = Containment::Inside;
+    for p in self.planes().iter() {
+        let d = p.signed_distance(&s.center());
+        if d < -s.radius() { return Containment::Outside; }
+        if d < s.radius() { result = Containment::Intersecting; }
+    }
+    return result; } }
+impl<T: Scalar> Frustum<T>  { pub fn overlaps_aabb(&self, b: &Aabb<T>) -> bool { return self.classify_aabb(b) != Containment::Outside; } }
+impl<T: Scalar> Frustum<T>  { pub fn overlaps_sphere(&self, s: &Sphere<T>) -> bool { return self.classify_sphere(s) != Containment::Outside; } }
+
+// Precision conversion
+impl<T: Scalar> RayHit<T> { pub fn cast<U: Scalar>(&self) -> RayHit<U> {
+    return RayHit { distance: U::from_f64(self.distance.to_f64()), point: self.point.cast(), normal: self.normal.cast(), barycentric: self.barycentric.cast() }; } }
+impl From<RayHit<f32>> for RayHit<f64> { fn from(h: RayHit<f32>) -> Self { return h.cast(); } }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_sphere_and_plane() {
+        let ray = Ray::new(Vector3::new(0.0f64, -5.0, 0.0), Vector3::forth());
+        let hit = ray.intersect_sphere(&Sphere::new(Vector3::zero(), 2.0)).unwrap();
+        assert!((hit.distance() - 3.0).abs() < 1e-12 && hit.normal().approx_eq(&Vector3::back()));
+        let inside = Ray::new(Vector3::zero(), Vector3::forth()).intersect_sphere(&Sphere::new(Vector3::zero(), 2.0)).unwrap();
+        assert!((inside.distance() - 2.0).abs() < 1e-12 && inside.normal().approx_eq(&Vector3::forth()));
+        assert!(ray.intersect_sphere(&Sphere::new(Vector3::new(3.0, 0.0, 0.0), 2.0)).is_none());
+
+        let hit = ray.intersect_plane(&Plane::from_point_normal(Vector3::new(0.0, 1.0, 0.0), Vector3::back())).unwrap();
+        assert!((hit.distance() - 6.0).abs() < 1e-12 && hit.point().approx_eq(&Vector3::new(0.0, 1.0, 0.0)));
+        assert!(Rayd::new(Vector3::zero(), Vector3::up()).intersect_plane(&Plane::from_point_normal(Vector3::forth(), Vector3::forth())).is_none());
+    }
+
+    #[test]
+    fn ray_boxes() {
+        let ray = Ray::new(Vector3::new(0.0f64, -5.0, 0.0), Vector3::forth());
+        let unit_box = Aabb::new(Vector3::new(-1.0f64, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+        let hit = ray.intersect_aabb(&unit_box).unwrap();
+        assert!((hit.distance() - 4.0).abs() < 1e-12 && hit.normal() == Vector3::back());
+        let o = Obb::from_rotation(Vector3::zero(), &Quaternion::rotation(Deg(45.0), Vector3::up()), Vector3::one());
+        let hit = ray.intersect_obb(&o).unwrap();
+        assert!((hit.distance() - (5.0 - 2.0f64.sqrt())).abs() < 1e-12);
+        assert!(hit.normal().approx_eq(&Vector3::new(-1.0, -1.0, 0.0).normalization()) || hit.normal().approx_eq(&Vector3::new(1.0, -1.0, 0.0).normalization()));
+    }
+
+    #[test]
+    fn ray_triangle_with_barycentrics() {
+        let ray = Ray::new(Vector3::new(0.0f64, -5.0, 0.0), Vector3::forth());
+        let t = Triangle::new(Vector3::new(-1.0, 0.0, -1.0), Vector3::new(1.0, 0.0, -1.0), Vector3::new(0.0, 0.0, 1.0));
+        let hit = ray.intersect_triangle(&t).unwrap();
+        assert!((hit.distance() - 5.0).abs() < 1e-12 && hit.normal().approx_eq(&Vector3::back()));
+        assert!(hit.barycentric().approx_eq(&Vector3::new(0.25, 0.25, 0.5)) && t.from_barycentric(&hit.barycentric()).approx_eq(&hit.point()));
+        assert!(Ray::new(Vector3::new(2.0, -5.0, 0.0), Vector3::forth()).intersect_triangle(&t).is_none());
+        // A triangle a billionth the size is hit all the same, and a ray in its plane misses
+        let tiny = Triangle::new(t.a() * 1e-9, t.b() * 1e-9, t.c() * 1e-9);
+        assert!(ray.intersect_triangle(&tiny).unwrap().barycentric().approx_eq(&Vector3::new(0.25, 0.25, 0.5)));
+        assert!(Ray::new(Vector3::zero(), Vector3::right()).intersect_triangle(&t).is_none());
+    }
+
+    #[test]
+    fn ray_capsule() {
+        let ray = Ray::new(Vector3::new(0.0f64, -5.0, 0.0), Vector3::forth());
+        let c = Capsule::new(Vector3::new(0.0, 0.0, -2.0), Vector3::new(0.0, 0.0, 2.0), 1.0);
+        let hit = ray.intersect_capsule(&c).unwrap();
+        assert!((hit.distance() - 4.0).abs() < 1e-12 && hit.normal().approx_eq(&Vector3::back()));
+        let hit = Ray::new(Vector3::new(0.0, 0.0, 10.0), Vector3::down()).intersect_capsule(&c).unwrap();
+        assert!((hit.distance() - 7.0).abs() < 1e-12 && hit.normal().approx_eq(&Vector3::up()));
+    }
+
+    #[test]
+    fn closest_points() {
+        let unit_box = Aabb::new(Vector3::new(-1.0f64, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+        assert!(closest_point_segment(&Vector3::new(5.0, 1.0, 0.0), &Vector3::zero(), &Vector3::new(2.0, 0.0, 0.0)).approx_eq(&Vector3::new(2.0, 0.0, 0.0)));
+        let (p, q) = closest_points_segments(&Vector3::new(-1.0, 0.0, 0.0), &Vector3::new(1.0, 0.0, 0.0), &Vector3::new(0.0, -1.0, 1.0), &Vector3::new(0.0, 1.0, 1.0));
+        assert!(p.approx_eq(&Vector3::zero()) && q.approx_eq(&Vector3::up()));
+        let t = Triangle::new(Vector3::new(-1.0, 0.0, -1.0), Vector3::new(1.0, 0.0, -1.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(t.closest_point_barycentric(&Vector3::new(0.0, 3.0, 5.0)), Vector3::new(0.0, 0.0, 1.0));
+        assert!(t.closest_point(&Vector3::new(0.0, 3.0, -2.0)).approx_eq(&Vector3::new(0.0, 0.0, -1.0)));
+        assert!(unit_box.closest_point(&Vector3::new(3.0, 0.5, -4.0)).approx_eq(&Vector3::new(1.0, 0.5, -1.0)));
+    }
+
+    #[test]
+    fn overlaps() {
+        let b = Aabb::new(Vector3::new(-1.0f64, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+        let o = Obb::from_rotation(Vector3::zero(), &Quaternion::rotation(Deg(45.0), Vector3::up()), Vector3::one());
+        let far = Obb::from_rotation(Vector3::new(3.0, 0.0, 0.0), &Quaternion::rotation(Deg(45.0), Vector3::up()), Vector3::one());
+        assert!(!o.overlaps_obb(&far) && o.overlaps_obb(&Obb::from_aabb(&b)) && b.overlaps_obb(&o));
+        assert!(!Aabb::new(Vector3::new(1.2, 1.2, 0.0), Vector3::new(2.0, 2.0, 1.0)).overlaps_aabb(&b));
+        assert!(!Obb::from_aabb(&Aabb::new(Vector3::new(1.2, 1.2, 0.0), Vector3::new(2.0, 2.0, 1.0))).overlaps_obb(&o));
+        assert!(Sphere::new(Vector3::new(2.0, 2.0, 0.0), 1.5).overlaps_aabb(&b) && !Sphere::new(Vector3::new(2.0, 2.0, 0.0), 1.4).overlaps_aabb(&b));
+        let c = Capsule::new(Vector3::new(0.0, 0.0, -2.0), Vector3::new(0.0, 0.0, 2.0), 1.0);
+        assert!(c.overlaps_capsule(&Capsule::new(Vector3::new(-3.0, 1.5, 0.0), Vector3::new(3.0, 1.5, 0.0), 0.5)));
+    }
+
+    #[test]
+    fn frustum_culling() {
+        let view_projection = Matrix4::perspective(Deg(90.0f64), 1.0, 1.0, 10.0) * Matrix4::look_at_rh(Vector3::zero(), Vector3::forth(), Vector3::up());
+        let f = Frustum::from_matrix(&view_projection, DepthRange::NegativeOneToOne);
+        assert_eq!(f.classify_aabb(&Aabb::from_center_half_extents(Vector3::new(0.0, 5.0, 0.0), Vector3::one())), Containment::Inside);
+        assert_eq!(f.classify_aabb(&Aabb::from_center_half_extents(Vector3::new(0.0, 10.0, 0.0), Vector3::one())), Containment::Intersecting);
+        assert_eq!(f.classify_aabb(&Aabb::from_center_half_extents(Vector3::new(0.0, -5.0, 0.0), Vector3::one())), Containment::Outside);
+        assert!(!f.overlaps_sphere(&Sphere::new(Vector3::new(8.0, 5.0, 0.0), 1.0)));
+    }
+}
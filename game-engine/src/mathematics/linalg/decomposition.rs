@@ -0,0 +1,321 @@
+// Matrix decompositions (LU, QR, Cholesky, symmetric eigen, SVD)
+//      Guidance: Golub & Van Loan, "Matrix Computations" (4th ed.), chapters 3, 4, 5, 8
+//      The cofactor/adjugate inverse in linalg loses precision quickly on badly scaled matrices.
+//      These work on the rows in place, with pivoting where it matters.
+use super::*;
+
+/*
+    Conventions:
+    - Factors are returned as ordinary matrices, so M * v and the products in linalg apply to them directly.
+    - Internally the work is done on [row][column] arrays (the transpose of the storage order), which keeps
+      the loops close to the textbook formulas.
+*/
+
+// Struct Definitions
+//      LU with partial pivoting:    P * M = L * U   (L unit lower triangular, U upper triangular, P a row permutation)
+//      L and U share one array (the unit diagonal of L is implied).
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Lu2<T: Scalar = f32> { lu: [[T; 2]; 2], permutation: [usize; 2], sign: T, singular: bool }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Lu3<T: Scalar = f32> { lu: [[T; 3]; 3], permutation: [usize; 3], sign: T, singular: bool }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Lu4<T: Scalar = f32> { lu: [[T; 4]; 4], permutation: [usize; 4], sign: T, singular: bool }
+
+// Precision aliases (the bare names default to f32)
+pub type Lu2f           = Lu2<f32>;             pub type Lu2d           = Lu2<f64>;
+pub type Lu3f           = Lu3<f32>;             pub type Lu3d           = Lu3<f64>;
+pub type Lu4f           = Lu4<f32>;             pub type Lu4d           = Lu4<f64>;
+
+macro_rules! impl_decompositions { ($($M:ident, $V:ident, $Lu:ident, $n:expr);*) => { $(
+    impl<T: Scalar> $M<T> {
+        fn to_rows(&self) -> [[T; $n]; $n] {
+            let mut a = [[T::ZERO; $n]; $n];
+            for r in 0..$n { for c in 0..$n { a[r][c] = self.e[c][r]; } }
+            return a; }
+        fn from_rows(a: &[[T; $n]; $n]) -> Self {
+            let mut m = Self::zero();
+            for r in 0..$n { for c in 0..$n { m.e[c][r] = a[r][c]; } }
+            return m; }
+    }
+
+    //      LU
+    //      https://en.wikipedia.org/wiki/LU_decomposition#LU_factorization_with_partial_pivoting
+    //      A pivot below EPSILON times the largest element marks the matrix as singular.
+    impl<T: Scalar> $M<T>    { pub fn decomposition_lu(&self)             -> $Lu<T> {
+        let mut a = self.to_rows();
+        let mut permutation = [0; $n];
+        for i in 0..$n { permutation[i] = i; }
+        let mut sign = T::ONE;
+        let mut singular = false;
+        let mut largest = T::ZERO;
+        for r in 0..$n { for c in 0..$n { largest = T::max(largest, a[r][c].abs()); } }
+        let threshold = T::EPSILON * largest;
+
+        for k in 0..$n {
+            // Partial pivoting: bring the largest remaining element of column k up to the diagonal
+            let mut p = k;
+            for i in (k + 1)..$n { if a[i][k].abs() > a[p][k].abs() { p = i; } }
+            if p != k {
+                a.swap(p, k);
+                permutation.swap(p, k);
+                sign = -sign;
+            }
+            if a[k][k].abs() <= threshold {
+                singular = true;
+                continue;
+            }
+            for i in (k + 1)..$n {
+                let f = a[i][k] / a[k][k];
+                a[i][k] = f;
+                for j in (k + 1)..$n { a[i][j] -= f * a[k][j]; }
+            }
+        }
+        return $Lu { lu: a, permutation, sign, singular }; } }
+    impl<T: Scalar> $Lu<T>   { pub fn l(&self)                            -> $M<T> {
+        let mut a = [[T::ZERO; $n]; $n];
+        for r in 0..$n { for c in 0..r { a[r][c] = self.lu[r][c]; } a[r][r] = T::ONE; }
+        return $M::from_rows(&a); } }
+    impl<T: Scalar> $Lu<T>   { pub fn u(&self)                            -> $M<T> {
+        let mut a = [[T::ZERO; $n]; $n];
+        for r in 0..$n { for c in r..$n { a[r][c] = self.lu[r][c]; } }
+        return $M::from_rows(&a); } }
+    //          Row i of P * M is row permutation()[i] of M
+    impl<T: Scalar> $Lu<T>   { pub fn permutation(&self)                  -> [usize; $n] { return self.permutation; } }
+    impl<T: Scalar> $Lu<T>   { pub fn p(&self)                            -> $M<T> {
+        let mut a = [[T::ZERO; $n]; $n];
+        for r in 0..$n { a[r][self.permutation[r]] = T::ONE; }
+        return $M::from_rows(&a); } }
+    impl<T: Scalar> $Lu<T>   { pub fn is_singular(&self)                  -> bool { return self.singular; } }
+    impl<T: Scalar> $Lu<T>   { pub fn determinant(&self)                  -> T {
+        let mut d = self.sign;
+        for k in 0..$n { d *= self.lu[k][k]; }
+        return d; } }
+    //          Forward substitution with L, then back substitution with U
+    impl<T: Scalar> $Lu<T>   { pub fn solve(&self, b: &$V<T>)             -> Option<$V<T>> {
+        if self.singular { return None; }
+        let mut x = [T::ZERO; $n];
+        for i in 0..$n {
+            x[i] = b[self.permutation[i]];
+            for j in 0..i { x[i] -= self.lu[i][j] * x[j]; }
+        }
+        for i in (0..$n).rev() {
+            for j in (i + 1)..$n { x[i] -= self.lu[i][j] * x[j]; }
+            x[i] /= self.lu[i][i];
+        }
+        return Some($V::from(x)); } }
+    impl<T: Scalar> $Lu<T>   { pub fn inverse(&self)                      -> Option<$M<T>> {
+        if self.singular { return None; }
+        let mut m = $M::zero();
+        for c in 0..$n {
+            let mut e = $V::zero();
+            e[c] = T::ONE;
+            m.e[c] = <[T; $n]>::from(self.solve(&e)?);
+        }
+        return Some(m); } }
+    //          Shortcuts (factor once and keep the Lu when solving several right-hand sides)
+    impl<T: Scalar> $M<T>    { pub fn solve(&self, b: &$V<T>)             -> Option<$V<T>> { return self.decomposition_lu().solve(b); } }
+    impl<T: Scalar> $M<T>    { pub fn inverse_lu(&self)                   -> Option<Self> { return self.decomposition_lu().inverse(); } }
+    impl<T: Scalar> $M<T>    { pub fn determinant_lu(&self)               -> T { return self.decomposition_lu().determinant(); } }
+
+    //      QR by Householder reflections:    M = Q * R   (Q orthogonal, R upper triangular)
+    //      https://en.wikipedia.org/wiki/QR_decomposition#Using_Householder_reflections
+    impl<T: Scalar> $M<T>    { pub fn decomposition_qr(&self)             -> ($M<T>, $M<T>) {
+        let mut r = self.to_rows();
+        let mut q = $M::<T>::identity().to_rows();
+        for k in 0..($n - 1) {
+            // Reflect column k (from the diagonal down) onto the axis, away from its sign to avoid cancellation
+            let mut v = [T::ZERO; $n];
+            let mut norm = T::ZERO;
+            for i in k..$n { v[i] = r[i][k]; norm += v[i] * v[i]; }
+            let norm = T::sqrt(norm);
+            if norm == T::ZERO { continue; }
+            v[k] += if v[k] < T::ZERO { -norm } else { norm };
+            let mut v_sqr = T::ZERO;
+            for i in k..$n { v_sqr += v[i] * v[i]; }
+            let f = T::TWO / v_sqr;
+            // R <- H * R,  Q <- Q * H   (H = I - f * v * v^T)
+            for c in 0..$n {
+                let mut d = T::ZERO;
+                for i in k..$n { d += v[i] * r[i][c]; }
+                for i in k..$n { r[i][c] -= f * d * v[i]; }
+            }
+            for row in q.iter_mut() {
+                let mut d = T::ZERO;
+                for i in k..$n { d += row[i] * v[i]; }
+                for i in k..$n { row[i] -= f * d * v[i]; }
+            }
+        }
+        // Clean out the rounding noise below the diagonal
+        for i in 1..$n { for j in 0..i { r[i][j] = T::ZERO; } }
+        return ($M::from_rows(&q), $M::from_rows(&r)); } }
+
+    //      Cholesky:    M = L * L^T   (L lower triangular, expects a symmetric positive definite M)
+    //      https://en.wikipedia.org/wiki/Cholesky_decomposition#The_Cholesky_algorithm
+    //      None when M is not positive definite (only the lower triangle of M is read).
+    impl<T: Scalar> $M<T>    { pub fn decomposition_cholesky(&self)       -> Option<$M<T>> {
+        let a = self.to_rows();
+        let mut l = [[T::ZERO; $n]; $n];
+        for j in 0..$n {
+            let mut d = a[j][j];
+            for k in 0..j { d -= l[j][k] * l[j][k]; }
+            if d <= T::ZERO { return None; }
+            l[j][j] = T::sqrt(d);
+            for i in (j + 1)..$n {
+                let mut s = a[i][j];
+                for k in 0..j { s -= l[i][k] * l[j][k]; }
+                l[i][j] = s / l[j][j];
+            }
+        }
+        return Some($M::from_rows(&l)); } }
+)* } }
+impl_decompositions!(Matrix2, Vector2, Lu2, 2; Matrix3, Vector3, Lu3, 3; Matrix4, Vector4, Lu4, 4);
+
+// Symmetric eigen-decomposition (cyclic Jacobi):    M = V * diag(λ) * V^T
+//      https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm
+//      Expects a symmetric M (inertia tensors, covariance matrices). Eigenvalues are sorted in decreasing order,
+//      and the eigenvectors are the matching columns of V (orthonormal, with det(V) = 1).
+macro_rules! impl_eigen { ($($M:ident, $V:ident, $n:expr);*) => { $(
+    impl<T: Scalar> $M<T>    { pub fn eigen_symmetric(&self)              -> ($V<T>, $M<T>) {
+        let mut a = self.to_rows();
+        let mut v = $M::<T>::identity().to_rows();
+        for _ in 0..50 {
+            let mut off = T::ZERO;
+            let mut diagonal = T::ZERO;
+            for p in 0..$n {
+                diagonal += a[p][p] * a[p][p];
+                for q in (p + 1)..$n { off += a[p][q] * a[p][q]; }
+            }
+            if off <= T::EPSILON * T::EPSILON * diagonal || off == T::ZERO { break; }
+            for p in 0..$n { for q in (p + 1)..$n {
+                if a[p][q] == T::ZERO { continue; }
+                // Rotation in the (p, q) plane that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (T::TWO * a[p][q]);
+                let t = theta.signum() / (theta.abs() + T::sqrt(theta * theta + T::ONE));
+                let c = T::ONE / T::sqrt(t * t + T::ONE);
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                for k in 0..$n {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            } }
+        }
+        let mut order = [0; $n];
+        for i in 0..$n { order[i] = i; }
+        order.sort_by(|&i, &j| a[j][j].partial_cmp(&a[i][i]).unwrap_or(std::cmp::Ordering::Equal));
+        let mut values = [T::ZERO; $n];
+        let mut vectors = $M::zero();
+        for (c, &i) in order.iter().enumerate() {
+            values[c] = a[i][i];
+            for r in 0..$n { vectors.e[c][r] = v[r][i]; }
+        }
+        if vectors.determinant() < T::ZERO { for r in 0..$n { vectors.e[$n - 1][r] = -vectors.e[$n - 1][r]; } }
+        return ($V::from(values), vectors); } }
+
+    //      Singular values by one-sided Jacobi (Hestenes): columns of M * V are rotated in pairs until they are
+    //      orthogonal, and their lengths are then the singular values. Working on M itself rather than M^T * M keeps
+    //      the small ones accurate (M^T * M squares the condition number).
+    //      Returns the singular values (decreasing), M * V and V (a rotation), their columns in the same order.
+    //      Demmel & Veselić, "Jacobi's method is more accurate than QR": https://doi.org/10.1137/0613074
+    impl<T: Scalar> $M<T>    { fn singular_jacobi(&self)                   -> ($V<T>, Self, Self) {
+        // Storage is column-major, so a[i] and v[i] are columns
+        let mut a = self.e;
+        let mut v = $M::<T>::identity().e;
+        for _ in 0..32 {
+            let mut rotated = false;
+            for p in 0..$n { for q in (p + 1)..$n {
+                let (mut alpha, mut beta, mut gamma) = (T::ZERO, T::ZERO, T::ZERO);
+                for k in 0..$n {
+                    alpha += a[p][k] * a[p][k];
+                    beta  += a[q][k] * a[q][k];
+                    gamma += a[p][k] * a[q][k];
+                }
+                if gamma.abs() <= T::EPSILON * T::sqrt(alpha * beta) { continue; }
+                rotated = true;
+                // Rotation in the (p, q) plane that makes columns p and q orthogonal
+                let zeta = (beta - alpha) / (T::TWO * gamma);
+                let t = zeta.signum() / (zeta.abs() + T::sqrt(zeta * zeta + T::ONE));
+                let c = T::ONE / T::sqrt(t * t + T::ONE);
+                let s = t * c;
+                for column in [&mut a, &mut v].iter_mut() { for k in 0..$n {
+                    let (ckp, ckq) = (column[p][k], column[q][k]);
+                    column[p][k] = c * ckp - s * ckq;
+                    column[q][k] = s * ckp + c * ckq;
+                } }
+            } }
+            if !rotated { break; }
+        }
+        let mut norms = [T::ZERO; $n];
+        for i in 0..$n { norms[i] = a[i].iter().fold(T::ZERO, |sum, &x| sum + x * x).sqrt(); }
+        let mut order = [0; $n];
+        for i in 0..$n { order[i] = i; }
+        order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(std::cmp::Ordering::Equal));
+        let mut values = [T::ZERO; $n];
+        let (mut b, mut vectors) = ($M::zero(), $M::zero());
+        for (c, &i) in order.iter().enumerate() {
+            values[c] = norms[i];
+            b.e[c] = a[i];
+            vectors.e[c] = v[i];
+        }
+        if vectors.determinant() < T::ZERO { for r in 0..$n {
+            vectors.e[$n - 1][r] = -vectors.e[$n - 1][r];
+            b.e[$n - 1][r] = -b.e[$n - 1][r];
+        } }
+        return ($V::from(values), b, vectors); } }
+
+    //      Condition number in the 2-norm (largest over smallest singular value). 1 for rotations, infinity for
+    //      singular matrices. log10 of it is roughly the number of decimal digits lost when solving with M.
+    impl<T: Scalar> $M<T>    { pub fn condition_number(&self)             -> T {
+        let (values, _, _) = self.singular_jacobi();
+        let (largest, smallest) = (values[0], values[$n - 1]);
+        if smallest <= T::ZERO { return T::INFINITY; }
+        return largest / smallest; } }
+    //      Moore-Penrose pseudo-inverse, M+ = V * diag(1 / σ) * U^T = V * diag(1 / σ²) * (M * V)^T.
+    //      Singular values below epsilon times the largest are treated as zero, so for a singular M this gives the
+    //      least-squares solution of smallest magnitude instead of infinities. Equals the inverse for invertible M.
+    impl<T: Scalar> $M<T>    { pub fn pseudo_inverse(&self)               -> Self { return self.pseudo_inverse_eps(T::TOLERANCE); } }
+    impl<T: Scalar> $M<T>    { pub fn pseudo_inverse_eps(&self, epsilon: T) -> Self {
+        let (values, b, v) = self.singular_jacobi();
+        let mut d = $M::zero();
+        for i in 0..$n {
+            if values[i] > epsilon * values[0] { d.e[i][i] = T::ONE / (values[i] * values[i]); }
+        }
+        return v * d * b.transpose(); } }
+)* } }
+impl_eigen!(Matrix2, Vector2, 2; Matrix3, Vector3, 3; Matrix4, Vector4, 4);
+
+// Singular value decomposition:    M = U * diag(σ) * V^T
+//      V and M * V from one-sided Jacobi (see singular_jacobi), then U by Gram-Schmidt on the columns of M * V.
+//      Singular values are non-negative and sorted in decreasing order. V is a rotation, U is orthogonal
+//      (a reflection when det(M) < 0). Rank-deficient matrices get an arbitrary orthonormal completion of U.
+//      https://en.wikipedia.org/wiki/Singular_value_decomposition#One-sided_Jacobi_algorithm
+impl<T: Scalar> Matrix3<T>    { pub fn decomposition_svd(&self)            -> (Matrix3<T>, Vector3<T>, Matrix3<T>) {
+    let (_, b, v) = self.singular_jacobi();
+    let (b0, b1, b2) = (b.column(0), b.column(1), b.column(2));
+    let tolerance = T::TOLERANCE * T::max(b0.magnitude(), T::ONE);
+
+    let s0 = b0.magnitude();
+    let u0 = if s0 > tolerance { b0 / s0 } else { Vector3::right() };
+    let r1 = b1 - u0 * (u0 * b1);
+    let s1 = r1.magnitude();
+    let u1 = if s1 > tolerance { r1 / s1 } else { any_orthogonal(&u0) };
+    let mut u2 = u0 / u1;
+    let mut s2 = u2 * b2;
+    if s2 < T::ZERO {
+        u2 = -u2;
+        s2 = -s2;
+    }
+    return (Matrix3::from_vector3(u0, u1, u2).transpose(), Vector3::new(s0, s1, s2), v); } }
+//      Unit vector orthogonal to a unit vector (crossed with the axis it is least aligned with)
+fn any_orthogonal<T: Scalar>(u: &Vector3<T>) -> Vector3<T> {
+    let axis = if u.x.abs() < u.y.abs() && u.x.abs() < u.z.abs() { Vector3::right() }
+        else if u.y.abs() < u.z.abs() { Vector3::forth() }
+        else { Vector3::up() };
+    return ((*u) / axis).normalization(); }
@@ -0,0 +1,241 @@
+// Heap-allocated vectors and matrices of any size (constraint solvers, least-squares fitting)
+//      Same conventions as the fixed-size types: column-major storage, new()/from_rows() read row by row,
+//      M * v treats v as a column vector, * between vectors is the dot product,
+//      unary [-] on a matrix is the transpose and unary [!] the inverse.
+//      Shapes are not known at compile time: solving, determinants and inverses return None for non-square matrices,
+//      and the other operators panic on mismatched sizes.
+//      Not Copy, so the operators are implemented on references too (&a + &b avoids the clones).
+use super::*;
+
+// Struct Definitions
+#[derive(Debug, Clone, PartialEq)] pub struct VectorN<T: Scalar = f32> { e: Vec<T> }
+#[derive(Debug, Clone, PartialEq)] pub struct MatrixN<T: Scalar = f32> { rows: usize, cols: usize, e: Vec<T> }
+
+// Precision aliases (the bare names default to f32)
+pub type VectorNf       = VectorN<f32>;         pub type VectorNd       = VectorN<f64>;
+pub type MatrixNf       = MatrixN<f32>;         pub type MatrixNd       = MatrixN<f64>;
+
+// Construction
+impl<T: Scalar> VectorN<T> { pub fn new(n: usize)                                -> Self { return Self { e: vec![T::ZERO; n] }; } }
+impl<T: Scalar> VectorN<T> { pub fn from_slice(a: &[T])                          -> Self { return Self { e: a.to_vec() }; } }
+impl<T: Scalar> MatrixN<T> { pub fn new(rows: usize, cols: usize)                -> Self { return Self { rows, cols, e: vec![T::ZERO; rows * cols] }; } }
+//      Elements listed row by row, like Matrix2..4::new()
+impl<T: Scalar> MatrixN<T> { pub fn from_rows(rows: usize, cols: usize, a: &[T]) -> Self {
+    assert_eq!(a.len(), rows * cols, "MatrixN::from_rows: expected {} elements, got {}", rows * cols, a.len());
+    let mut m = Self::new(rows, cols);
+    for r in 0..rows { for c in 0..cols { m[(r, c)] = a[r * cols + c]; } }
+    return m; } }
+impl<T: Scalar> MatrixN<T> { pub fn from_cols(rows: usize, cols: usize, a: &[T]) -> Self {
+    assert_eq!(a.len(), rows * cols, "MatrixN::from_cols: expected {} elements, got {}", rows * cols, a.len());
+    return Self { rows, cols, e: a.to_vec() }; } }
+impl<T: Scalar> MatrixN<T> { pub fn from_diagonal(v: &VectorN<T>)                -> Self {
+    let mut m = Self::new(v.len(), v.len());
+    for i in 0..v.len() { m[(i, i)] = v[i]; }
+    return m; } }
+
+// Prefabrication
+impl<T: Scalar> VectorN<T> { pub fn zero(n: usize)                               -> Self { return Self::new(n); } }
+impl<T: Scalar> VectorN<T> { pub fn one(n: usize)                                -> Self { return Self { e: vec![T::ONE; n] }; } }
+impl<T: Scalar> MatrixN<T> { pub fn zero(rows: usize, cols: usize)               -> Self { return Self::new(rows, cols); } }
+impl<T: Scalar> MatrixN<T> { pub fn identity(n: usize)                           -> Self { return Self::from_diagonal(&VectorN::one(n)); } }
+
+// Field Interpretation
+impl<T: Scalar> VectorN<T> { pub fn len(&self)                    -> usize { return self.e.len(); } }
+impl<T: Scalar> VectorN<T> { pub fn is_empty(&self)               -> bool { return self.e.is_empty(); } }
+impl<T: Scalar> MatrixN<T> { pub fn rows(&self)                   -> usize { return self.rows; } }
+impl<T: Scalar> MatrixN<T> { pub fn cols(&self)                   -> usize { return self.cols; } }
+impl<T: Scalar> MatrixN<T> { pub fn is_square(&self)              -> bool { return self.rows == self.cols; } }
+impl<T: Scalar> MatrixN<T> { pub fn element(&self, r: usize, c: usize)   -> T { return self[(r, c)]; } }
+impl<T: Scalar> MatrixN<T> { pub fn set_element(&mut self, r: usize, c: usize, a: T) { self[(r, c)] = a; } }
+impl<T: Scalar> MatrixN<T> { pub fn row(&self, n: usize)          -> VectorN<T> { return VectorN { e: (0..self.cols).map(|c| self[(n, c)]).collect() }; } }
+impl<T: Scalar> MatrixN<T> { pub fn column(&self, n: usize)       -> VectorN<T> { return VectorN::from_slice(&self.e[n * self.rows..(n + 1) * self.rows]); } }
+impl<T: Scalar> MatrixN<T> { pub fn set_row(&mut self, n: usize, v: &VectorN<T>) { for c in 0..self.cols { self[(n, c)] = v[c]; } } }
+impl<T: Scalar> MatrixN<T> { pub fn set_column(&mut self, n: usize, v: &VectorN<T>) { let rows = self.rows; self.e[n * rows..(n + 1) * rows].copy_from_slice(&v.e); } }
+impl<T: Scalar> VectorN<T> { pub fn as_slice(&self)               -> &[T] { return &self.e; } }
+impl<T: Scalar> MatrixN<T> { pub fn as_slice(&self)               -> &[T] { return &self.e; } }
+impl<T: Scalar> MatrixN<T> { pub fn as_ptr(&self)                 -> *const T { return self.e.as_ptr(); } }
+//          [] syntax (vectors as v[i], matrices as m[(row, column)])
+impl<T: Scalar> Index<usize> for VectorN<T>             { type Output = T; fn index(&self, n: usize) -> &T { return &self.e[n]; } }
+impl<T: Scalar> IndexMut<usize> for VectorN<T>          { fn index_mut(&mut self, n: usize) -> &mut T { return &mut self.e[n]; } }
+impl<T: Scalar> Index<(usize, usize)> for MatrixN<T>    { type Output = T; fn index(&self, (r, c): (usize, usize)) -> &T {
+    assert!(r < self.rows && c < self.cols, "MatrixN index ({}, {}) out of range for {}x{}", r, c, self.rows, self.cols);
+    return &self.e[c * self.rows + r]; } }
+impl<T: Scalar> IndexMut<(usize, usize)> for MatrixN<T> { fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+    assert!(r < self.rows && c < self.cols, "MatrixN index ({}, {}) out of range for {}x{}", r, c, self.rows, self.cols);
+    return &mut self.e[c * self.rows + r]; } }
+//          Iteration (matrices in storage order: column by column)
+impl<T: Scalar> VectorN<T> { pub fn iter(&self)                   -> std::slice::Iter<'_, T> { return self.e.iter(); } }
+impl<T: Scalar> VectorN<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<'_, T> { return self.e.iter_mut(); } }
+impl<T: Scalar> MatrixN<T> { pub fn iter(&self)                   -> std::slice::Iter<'_, T> { return self.e.iter(); } }
+impl<T: Scalar> MatrixN<T> { pub fn iter_mut(&mut self)           -> std::slice::IterMut<'_, T> { return self.e.iter_mut(); } }
+//          Sparse-friendly iteration: only the non-zero elements, as (index, value) and (row, column, value)
+impl<T: Scalar> VectorN<T> { pub fn iter_nonzero(&self)           -> impl Iterator<Item = (usize, T)> + '_ {
+    return self.e.iter().enumerate().filter(|(_, &a)| a != T::ZERO).map(|(i, &a)| (i, a)); } }
+impl<T: Scalar> MatrixN<T> { pub fn iter_nonzero(&self)           -> impl Iterator<Item = (usize, usize, T)> + '_ {
+    let rows = self.rows;
+    return self.e.iter().enumerate().filter(|(_, &a)| a != T::ZERO).map(move |(i, &a)| (i % rows, i / rows, a)); } }
+impl<T: Scalar> MatrixN<T> { pub fn count_nonzero(&self)          -> usize { return self.e.iter().filter(|&&a| a != T::ZERO).count(); } }
+
+// Conversion Methods
+impl<T: Scalar> From<Vec<T>>       for VectorN<T> { fn from(e: Vec<T>)        -> Self { return Self { e }; } }
+impl<T: Scalar> From<VectorN<T>>   for Vec<T>     { fn from(v: VectorN<T>)    -> Self { return v.e; } }
+impl<T: Scalar> From<Vector2<T>>   for VectorN<T> { fn from(v: Vector2<T>)    -> Self { return Self { e: vec![v.x, v.y] }; } }
+impl<T: Scalar> From<Vector3<T>>   for VectorN<T> { fn from(v: Vector3<T>)    -> Self { return Self { e: vec![v.x, v.y, v.z] }; } }
+impl<T: Scalar> From<Vector4<T>>   for VectorN<T> { fn from(v: Vector4<T>)    -> Self { return Self { e: vec![v.x, v.y, v.z, v.w] }; } }
+impl<T: Scalar> From<Matrix2<T>>   for MatrixN<T> { fn from(m: Matrix2<T>)    -> Self { return Self::from_cols(2, 2, m.as_slice()); } }
+impl<T: Scalar> From<Matrix3<T>>   for MatrixN<T> { fn from(m: Matrix3<T>)    -> Self { return Self::from_cols(3, 3, m.as_slice()); } }
+impl<T: Scalar> From<Matrix4<T>>   for MatrixN<T> { fn from(m: Matrix4<T>)    -> Self { return Self::from_cols(4, 4, m.as_slice()); } }
+impl<T: Scalar> VectorN<T> { pub fn cast<U: Scalar>(&self)        -> VectorN<U> { return VectorN { e: self.e.iter().map(|&a| U::from_f64(a.to_f64())).collect() }; } }
+impl<T: Scalar> MatrixN<T> { pub fn cast<U: Scalar>(&self)        -> MatrixN<U> {
+    return MatrixN { rows: self.rows, cols: self.cols, e: self.e.iter().map(|&a| U::from_f64(a.to_f64())).collect() }; } }
+
+// Transpose (also implemented for unary operator [-])
+impl<T: Scalar> MatrixN<T> { pub fn transpose(&self)              -> Self {
+    let mut m = Self::new(self.cols, self.rows);
+    for r in 0..self.rows { for c in 0..self.cols { m[(c, r)] = self[(r, c)]; } }
+    return m; } }
+
+// Solving (LU with partial pivoting, as in linalg::decomposition)
+//      Factors into a row-major working copy. Returns the factors, the row permutation and the permutation sign,
+//      or None when the matrix is not square, or a pivot is below EPSILON times the largest element (singular).
+fn decomposition_lu<T: Scalar>(m: &MatrixN<T>) -> Option<(Vec<Vec<T>>, Vec<usize>, T)> {
+    if !m.is_square() { return None; }
+    let n = m.rows;
+    let mut a: Vec<Vec<T>> = (0..n).map(|r| (0..n).map(|c| m[(r, c)]).collect()).collect();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    let mut sign = T::ONE;
+    let largest = m.e.iter().fold(T::ZERO, |l, &x| T::max(l, x.abs()));
+    let threshold = T::EPSILON * largest;
+    for k in 0..n {
+        let mut p = k;
+        for i in (k + 1)..n { if a[i][k].abs() > a[p][k].abs() { p = i; } }
+        if p != k {
+            a.swap(p, k);
+            permutation.swap(p, k);
+            sign = -sign;
+        }
+        if a[k][k].abs() <= threshold { return None; }
+        let (pivot, below) = a.split_at_mut(k + 1);
+        let pivot = &pivot[k];
+        for row in below.iter_mut() {
+            let f = row[k] / pivot[k];
+            row[k] = f;
+            for (x, &u) in row[(k + 1)..].iter_mut().zip(pivot[(k + 1)..].iter()) { *x -= f * u; }
+        }
+    }
+    return Some((a, permutation, sign)); }
+fn solve_lu<T: Scalar>(lu: &[Vec<T>], permutation: &[usize], b: &VectorN<T>) -> VectorN<T> {
+    let n = lu.len();
+    let mut x = VectorN::new(n);
+    for i in 0..n {
+        x[i] = b[permutation[i]];
+        for j in 0..i { let xj = x[j]; x[i] -= lu[i][j] * xj; }
+    }
+    for i in (0..n).rev() {
+        for j in (i + 1)..n { let xj = x[j]; x[i] -= lu[i][j] * xj; }
+        x[i] /= lu[i][i];
+    }
+    return x; }
+impl<T: Scalar> MatrixN<T> { pub fn solve(&self, b: &VectorN<T>)  -> Option<VectorN<T>> {
+    assert_eq!(b.len(), self.rows, "MatrixN::solve: right-hand side has {} elements for {} rows", b.len(), self.rows);
+    let (lu, permutation, _) = decomposition_lu(self)?;
+    return Some(solve_lu(&lu, &permutation, b)); } }
+//      Zero when singular, None when not square
+impl<T: Scalar> MatrixN<T> { pub fn determinant(&self)            -> Option<T> {
+    if !self.is_square() { return None; }
+    return match decomposition_lu(self) {
+        Some((lu, _, sign)) => Some((0..self.rows).fold(sign, |d, k| d * lu[k][k])),
+        None                => Some(T::ZERO),
+    }; } }
+//      None when singular or not square (like Matrix2..4::inverse_lu)
+impl<T: Scalar> MatrixN<T> { pub fn inverse(&self)                -> Option<Self> {
+    let n = self.rows;
+    let (lu, permutation, _) = decomposition_lu(self)?;
+    let mut m = Self::new(n, n);
+    for c in 0..n {
+        let mut e = VectorN::new(n);
+        e[c] = T::ONE;
+        m.set_column(c, &solve_lu(&lu, &permutation, &e));
+    }
+    return Some(m); } }
+//      Least squares (minimizes |M * x - b| for a tall M), through the normal equations M^T * M * x = M^T * b.
+//      Squares the condition number, which is fine for the well-posed fits this is meant for.
+impl<T: Scalar> MatrixN<T> { pub fn solve_least_squares(&self, b: &VectorN<T>) -> Option<VectorN<T>> {
+    let t = self.transpose();
+    return (&t * self).solve(&(&t * b)); } }
+
+// Utilities
+impl<T: Scalar> VectorN<T> { pub fn to_string(&self)              -> String {
+    return format!("[{}]", self.e.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(", ")); } }
+impl<T: Scalar> MatrixN<T> { pub fn to_string(&self)              -> String {
+    return format!("[{}]", (0..self.rows).map(|r| self.row(r).to_string()).collect::<Vec<_>>().join(", ")); } }
+
+// Arithmetic
+//      Element-wise operators for owned values and references (the owned versions reuse the left-hand allocation)
+macro_rules! impl_elementwise { ($($S:ident: $check:ident),*) => { $(
+    impl<'a, T: Scalar> Add<&'a $S<T>> for &'a $S<T>   { type Output = $S<T>; fn add(self, a: &'a $S<T>) -> $S<T> { let mut b = self.clone(); b += a; return b; } }
+    impl<'a, T: Scalar> Sub<&'a $S<T>> for &'a $S<T>   { type Output = $S<T>; fn sub(self, a: &'a $S<T>) -> $S<T> { let mut b = self.clone(); b -= a; return b; } }
+    impl<T: Scalar> Add<$S<T>> for $S<T>               { type Output = Self; fn add(mut self, a: Self) -> Self { self += &a; return self; } }
+    impl<T: Scalar> Sub<$S<T>> for $S<T>               { type Output = Self; fn sub(mut self, a: Self) -> Self { self -= &a; return self; } }
+    impl<'a, T: Scalar> AddAssign<&'a $S<T>> for $S<T> { fn add_assign(&mut self, a: &'a $S<T>) {
+        $check(self, a);
+        for (x, &y) in self.e.iter_mut().zip(a.e.iter()) { *x += y; } } }
+    impl<'a, T: Scalar> SubAssign<&'a $S<T>> for $S<T> { fn sub_assign(&mut self, a: &'a $S<T>) {
+        $check(self, a);
+        for (x, &y) in self.e.iter_mut().zip(a.e.iter()) { *x -= y; } } }
+    impl<T: Scalar> AddAssign<$S<T>> for $S<T>         { fn add_assign(&mut self, a: Self) { *self += &a; } }
+    impl<T: Scalar> SubAssign<$S<T>> for $S<T>         { fn sub_assign(&mut self, a: Self) { *self -= &a; } }
+    impl<'a, T: Scalar> Mul<T> for &'a $S<T>           { type Output = $S<T>; fn mul(self, s: T) -> $S<T> { let mut b = self.clone(); b *= s; return b; } }
+    impl<'a, T: Scalar> Div<T> for &'a $S<T>           { type Output = $S<T>; fn div(self, s: T) -> $S<T> { let mut b = self.clone(); b /= s; return b; } }
+    impl<T: Scalar> Mul<T> for $S<T>                   { type Output = Self; fn mul(mut self, s: T) -> Self { self *= s; return self; } }
+    impl<T: Scalar> Div<T> for $S<T>                   { type Output = Self; fn div(mut self, s: T) -> Self { self /= s; return self; } }
+    impl<T: Scalar> MulAssign<T> for $S<T>             { fn mul_assign(&mut self, s: T) { for x in self.e.iter_mut() { *x *= s; } } }
+    impl<T: Scalar> DivAssign<T> for $S<T>             { fn div_assign(&mut self, s: T) { for x in self.e.iter_mut() { *x /= s; } } }
+    impl Mul<$S<f32>> for f32                          { type Output = $S<f32>; fn mul(self, a: $S<f32>) -> $S<f32> { return a * self; } }
+    impl Mul<$S<f64>> for f64                          { type Output = $S<f64>; fn mul(self, a: $S<f64>) -> $S<f64> { return a * self; } }
+)* } }
+fn check_vectors<T: Scalar>(a: &VectorN<T>, b: &VectorN<T>) {
+    assert_eq!(a.len(), b.len(), "VectorN: length mismatch ({} and {})", a.len(), b.len()); }
+fn check_matrices<T: Scalar>(a: &MatrixN<T>, b: &MatrixN<T>) {
+    assert!(a.rows == b.rows && a.cols == b.cols, "MatrixN: size mismatch ({}x{} and {}x{})", a.rows, a.cols, b.rows, b.cols); }
+impl_elementwise!(VectorN: check_vectors, MatrixN: check_matrices);
+//      Negation (vectors), transpose and inverse (matrices), as on the fixed-size types
+impl<T: Scalar> Neg for VectorN<T>                     { type Output = Self; fn neg(mut self) -> Self { for x in self.e.iter_mut() { *x = -*x; } return self; } }
+impl<T: Scalar> Neg for MatrixN<T>                     { type Output = Self; fn neg(self) -> Self { return self.transpose(); } }
+impl<T: Scalar> Not for MatrixN<T>                     { type Output = Option<Self>; fn not(self) -> Option<Self> { return self.inverse(); } }
+//      Dot product
+impl<'a, T: Scalar> Mul<&'a VectorN<T>> for &'a VectorN<T> { type Output = T; fn mul(self, v: &'a VectorN<T>) -> T {
+    check_vectors(self, v);
+    return self.e.iter().zip(v.e.iter()).fold(T::ZERO, |d, (&a, &b)| d + a * b); } }
+impl<T: Scalar> Mul<VectorN<T>> for VectorN<T>         { type Output = T; fn mul(self, v: Self) -> T { return &self * &v; } }
+//      Matrix product and matrix-vector product (every element takes part, so NaN and infinity propagate)
+impl<'a, T: Scalar> Mul<&'a MatrixN<T>> for &'a MatrixN<T> { type Output = MatrixN<T>; fn mul(self, m: &'a MatrixN<T>) -> MatrixN<T> {
+    assert_eq!(self.cols, m.rows, "MatrixN: cannot multiply {}x{} by {}x{}", self.rows, self.cols, m.rows, m.cols);
+    let mut p = MatrixN::new(self.rows, m.cols);
+    for c in 0..m.cols { for k in 0..m.rows {
+        let b = m.e[c * m.rows + k];
+        for r in 0..self.rows { p.e[c * self.rows + r] += self.e[k * self.rows + r] * b; }
+    } }
+    return p; } }
+impl<T: Scalar> Mul<MatrixN<T>> for MatrixN<T>         { type Output = Self; fn mul(self, m: Self) -> Self { return &self * &m; } }
+impl<'a, T: Scalar> Mul<&'a VectorN<T>> for &'a MatrixN<T> { type Output = VectorN<T>; fn mul(self, v: &'a VectorN<T>) -> VectorN<T> {
+    assert_eq!(self.cols, v.len(), "MatrixN: cannot multiply {}x{} by a vector of {} elements", self.rows, self.cols, v.len());
+    let mut p = VectorN::new(self.rows);
+    for (c, &b) in v.e.iter().enumerate() {
+        for r in 0..self.rows { p.e[r] += self.e[c * self.rows + r] * b; }
+    }
+    return p; } }
+impl<T: Scalar> Mul<VectorN<T>> for MatrixN<T>         { type Output = VectorN<T>; fn mul(self, v: VectorN<T>) -> VectorN<T> { return &self * &v; } }
+
+// Geometry
+impl<T: Scalar> VectorN<T> { pub fn magnitude_sqr(&self)          -> T { return self * self; } }
+impl<T: Scalar> VectorN<T> { pub fn magnitude(&self)              -> T { return T::sqrt(self.magnitude_sqr()); } }
+impl<T: Scalar> VectorN<T> { pub fn normalization(&self)          -> Self { return self / self.magnitude(); } }
+
+// Queries
+impl<T: Scalar> VectorN<T> { pub fn approx_eq(&self, v: &Self)    -> bool { return self.approx_eq_eps(v, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+impl<T: Scalar> MatrixN<T> { pub fn approx_eq(&self, m: &Self)    -> bool { return self.approx_eq_eps(m, T::TOLERANCE, TestMode::AbsoluteCoordinates); } }
+impl<T: Scalar> VectorN<T> { pub fn approx_eq_eps(&self, v: &Self, epsilon: T, mode: TestMode) -> bool {
+    return self.len() == v.len() && approx_eq_elements(&self.e, &v.e, epsilon, mode); } }
+impl<T: Scalar> MatrixN<T> { pub fn approx_eq_eps(&self, m: &Self, epsilon: T, mode: TestMode) -> bool {
+    return self.rows == m.rows && self.cols == m.cols && approx_eq_elements(&self.e, &m.e, epsilon, mode); } }
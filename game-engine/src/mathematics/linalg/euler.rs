@@ -0,0 +1,135 @@
+// Euler angles (three rotations around coordinate axes, in a selectable order)
+//      Guidance: https://www.geometrictools.com/Documentation/EulerAngles.pdf
+//      Decomposition follows Shoemake, "Euler Angle Conversion" (Graphics Gems IV), generalized over the axis order.
+use super::*;
+
+/*
+    Order conventions:
+    - The order names the axes in the order the rotations are applied, around the fixed (world) axes.
+      XYZ rotates by a around x, then by b around y, then by c around z:   M = Rz(c) * Ry(b) * Rx(a)
+    - Read backwards, the same angles are rotations around the moving (local) axes: ZYX intrinsic = XYZ extrinsic.
+    - XYZ matches Quaternion::from_euler(x, y, z).
+    - Tait-Bryan orders use three different axes (b in [-pi/2, pi/2]),
+      proper Euler orders repeat the first axis (b in [0, pi]).
+    - Yaw, pitch and roll follow the Z-up prefabs: yaw around up (z), pitch around right (x), roll around forth (y),
+      applied roll first and yaw last (order YXZ).
+*/
+
+// Struct Definitions
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub enum EulerOrder {
+    // Tait-Bryan
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    // Proper Euler
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct EulerAngles<T: Scalar = f32> { a: Rad<T>, b: Rad<T>, c: Rad<T>, order: EulerOrder }
+
+// Precision aliases (the bare names default to f32)
+pub type EulerAnglesf   = EulerAngles<f32>;     pub type EulerAnglesd   = EulerAngles<f64>;
+
+// Order queries
+impl EulerOrder { pub fn all()                  -> [EulerOrder; 12] {
+    use self::EulerOrder::*;
+    return [XYZ, XZY, YXZ, YZX, ZXY, ZYX, XYX, XZX, YXY, YZY, ZXZ, ZYZ]; } }
+//      Axis indices (0 = x, 1 = y, 2 = z) in application order
+impl EulerOrder { pub fn axes(&self)            -> (usize, usize, usize) {
+    use self::EulerOrder::*;
+    return match self {
+        XYZ => (0, 1, 2),   XZY => (0, 2, 1),   YXZ => (1, 0, 2),   YZX => (1, 2, 0),   ZXY => (2, 0, 1),   ZYX => (2, 1, 0),
+        XYX => (0, 1, 0),   XZX => (0, 2, 0),   YXY => (1, 0, 1),   YZY => (1, 2, 1),   ZXZ => (2, 0, 2),   ZYZ => (2, 1, 2),
+    }; } }
+impl EulerOrder { pub fn is_proper(&self)       -> bool { let (i, _, k) = self.axes(); return i == k; } }
+impl EulerOrder { pub fn is_tait_bryan(&self)   -> bool { return !self.is_proper(); } }
+impl EulerOrder { pub fn to_string(&self)       -> String { return format!("{:?}", self); } }
+
+// Construction
+impl<T: Scalar> EulerAngles<T> { pub fn new<A: Into<Rad<T>>>(a: A, b: A, c: A, order: EulerOrder) -> Self {
+    return Self { a: a.into(), b: b.into(), c: c.into(), order }; } }
+impl<T: Scalar> EulerAngles<T> { pub fn zero(order: EulerOrder)                        -> Self {
+    return Self { a: Rad::zero(), b: Rad::zero(), c: Rad::zero(), order }; } }
+impl<T: Scalar> EulerAngles<T> { pub fn from_yaw_pitch_roll<A: Into<Rad<T>>>(yaw: A, pitch: A, roll: A) -> Self {
+    return Self::new(roll, pitch, yaw, EulerOrder::YXZ); } }
+
+// Read functions
+impl<T: Scalar> EulerAngles<T> { pub fn a(&self)                -> Rad<T> { return self.a; } }
+impl<T: Scalar> EulerAngles<T> { pub fn b(&self)                -> Rad<T> { return self.b; } }
+impl<T: Scalar> EulerAngles<T> { pub fn c(&self)                -> Rad<T> { return self.c; } }
+impl<T: Scalar> EulerAngles<T> { pub fn order(&self)            -> EulerOrder { return self.order; } }
+impl<T: Scalar> EulerAngles<T> { pub fn angles(&self)           -> (Rad<T>, Rad<T>, Rad<T>) { return (self.a, self.b, self.c); } }
+//      (yaw, pitch, roll), re-decomposed into order YXZ first if needed
+impl<T: Scalar> EulerAngles<T> { pub fn to_yaw_pitch_roll(&self) -> (Rad<T>, Rad<T>, Rad<T>) {
+    let e = self.to_order(EulerOrder::YXZ);
+    return (e.c, e.b, e.a); } }
+
+// Conversion Methods
+fn axis<T: Scalar>(n: usize) -> Vector3<T> {
+    return match n { 0 => Vector3::right(), 1 => Vector3::forth(), _ => Vector3::up() }; }
+impl<T: Scalar> EulerAngles<T> { pub fn to_quaternion(&self)    -> Quaternion<T> {
+    let (i, j, k) = self.order.axes();
+    return Quaternion::rotation(self.c, axis(k)) * Quaternion::rotation(self.b, axis(j)) * Quaternion::rotation(self.a, axis(i)); } }
+impl<T: Scalar> EulerAngles<T> { pub fn to_matrix3(&self)       -> Matrix3<T> { return Matrix3::from_quaternion(&self.to_quaternion()); } }
+impl<T: Scalar> EulerAngles<T> { pub fn to_matrix4(&self)       -> Matrix4<T> { return Matrix4::from_quaternion(&self.to_quaternion()); } }
+//      Same rotation, expressed in another order
+impl<T: Scalar> EulerAngles<T> { pub fn to_order(&self, order: EulerOrder) -> Self {
+    if order == self.order { return *self; }
+    return Self::from_matrix3(&self.to_matrix3(), order); } }
+//      Decomposition (expects a pure rotation matrix)
+//          Gimbal lock (b at +-pi/2 for Tait-Bryan, 0 or pi for proper Euler) leaves only a + c or a - c defined.
+//          Then c is set to zero and the whole rotation goes into a, so the result still rebuilds the same matrix.
+impl<T: Scalar> EulerAngles<T> { pub fn from_matrix3(m: &Matrix3<T>, order: EulerOrder) -> Self {
+    let (i, j, _) = order.axes();
+    let k = 3 - i - j;
+    // +1 when (i, j, k) is a cyclic permutation of (x, y, z), -1 otherwise
+    let s = if j == (i + 1) % 3 { T::ONE } else { -T::ONE };
+
+    let (a, b, c);
+    if order.is_proper() {
+        // M = Ri(c) * Rj(b) * Ri(a)
+        let sin_b = T::sqrt(m[(i, j)] * m[(i, j)] + m[(i, k)] * m[(i, k)]);
+        b = Rad::atan2(sin_b, m[(i, i)]);
+        if sin_b > T::TOLERANCE {
+            a = Rad::atan2(m[(i, j)], s * m[(i, k)]);
+            c = Rad::atan2(m[(j, i)], -s * m[(k, i)]);
+        } else {
+            a = Rad::atan2(-s * m[(j, k)], m[(j, j)]);
+            c = Rad::zero();
+        }
+    } else {
+        // M = Rk(c) * Rj(b) * Ri(a)
+        let cos_b = T::sqrt(m[(i, i)] * m[(i, i)] + m[(j, i)] * m[(j, i)]);
+        b = Rad::atan2(-s * m[(k, i)], cos_b);
+        if cos_b > T::TOLERANCE {
+            a = Rad::atan2(s * m[(k, j)], m[(k, k)]);
+            c = Rad::atan2(s * m[(j, i)], m[(i, i)]);
+        } else {
+            a = Rad::atan2(-s * m[(j, k)], m[(j, j)]);
+            c = Rad::zero();
+        }
+    }
+    return Self { a, b, c, order }; } }
+impl<T: Scalar> EulerAngles<T> { pub fn from_matrix4(m: &Matrix4<T>, order: EulerOrder) -> Self {
+    return Self::from_matrix3(&Matrix3::from_matrix4(m), order); } }
+impl<T: Scalar> EulerAngles<T> { pub fn from_quaternion(q: &Quaternion<T>, order: EulerOrder) -> Self {
+    return Self::from_matrix3(&Matrix3::from_quaternion(q), order); } }
+//      The same conversions from the other side
+impl<T: Scalar> Matrix3<T>    { pub fn from_euler_angles(e: &EulerAngles<T>)       -> Self { return e.to_matrix3(); } }
+impl<T: Scalar> Matrix4<T>    { pub fn from_euler_angles(e: &EulerAngles<T>)       -> Self { return e.to_matrix4(); } }
+impl<T: Scalar> Quaternion<T> { pub fn from_euler_angles(e: &EulerAngles<T>)       -> Self { return e.to_quaternion(); } }
+impl<T: Scalar> Matrix3<T>    { pub fn to_euler_angles(&self, order: EulerOrder)   -> EulerAngles<T> { return EulerAngles::from_matrix3(self, order); } }
+impl<T: Scalar> Matrix4<T>    { pub fn to_euler_angles(&self, order: EulerOrder)   -> EulerAngles<T> { return EulerAngles::from_matrix4(self, order); } }
+impl<T: Scalar> Quaternion<T> { pub fn to_euler_angles(&self, order: EulerOrder)   -> EulerAngles<T> { return EulerAngles::from_quaternion(self, order); } }
+//      Precision conversion
+impl<T: Scalar> EulerAngles<T> { pub fn cast<U: Scalar>(&self) -> EulerAngles<U> {
+    return EulerAngles::new(Rad(U::from_f64(self.a.0.to_f64())), Rad(U::from_f64(self.b.0.to_f64())), Rad(U::from_f64(self.c.0.to_f64())), self.order); } }
+impl From<EulerAngles<f32>> for EulerAngles<f64> { fn from(e: EulerAngles<f32>) -> Self { return e.cast(); } }
+
+// Utilities
+impl<T: Scalar> EulerAngles<T> { pub fn to_string(&self)        -> String {
+    return format!("{} ({}, {}, {})", self.order.to_string(), self.a.to_deg().to_string(), self.b.to_deg().to_string(), self.c.to_deg().to_string()); } }
+
+// Queries
+//      Compares the rotations, not the angles (different angles can describe the same rotation)
+impl<T: Scalar> EulerAngles<T> { pub fn approx_eq(&self, e: &Self) -> bool { return self.to_matrix3().approx_eq(&e.to_matrix3()); } }
+impl<T: Scalar> EulerAngles<T> { pub fn is_gimbal_locked(&self) -> bool {
+    return if self.order.is_proper() { self.b.sin().abs() <= T::TOLERANCE } else { self.b.cos().abs() <= T::TOLERANCE }; } }
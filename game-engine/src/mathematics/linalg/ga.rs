@@ -0,0 +1,214 @@
+// Geometric algebra for 2D and 3D (and the 4D wedge product)
+//      Guidance: https://marctenbosch.com/quaternions/
+//      Introduction: https://bivector.net/doc.html
+use super::*;
+
+/*
+    Basis conventions:
+    - e1, e2, e3, e4 map to x, y, z, w
+    - Bivectors are stored in lexicographic order (e12, e13, e23, ...)
+    - e1 * e1 = 1,  e1 * e2 = e12 = -e2 * e1
+    - Geometric product of two vectors:  ab = a * b + a ^ b
+*/
+
+// Struct Definitions
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Bivector2<T: Scalar = f32>  { xy: T }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Bivector3<T: Scalar = f32>  { xy: T, xz: T, yz: T }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Bivector4<T: Scalar = f32>  { xy: T, xz: T, xw: T, yz: T, yw: T, zw: T }
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Trivector3<T: Scalar = f32> { xyz: T }
+//      Rotor form:                  R = s + B -> { s, xy, xz, yz } ∈ R
+//      Rotation:                    v' = R * v * ~R,   R = cos(t / 2) - B * sin(t / 2)   (B is the unit plane of rotation)
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Rotor3<T: Scalar = f32>     { s: T, xy: T, xz: T, yz: T }
+
+// Precision aliases (the bare names default to f32)
+pub type Bivector2f     = Bivector2<f32>;       pub type Bivector2d     = Bivector2<f64>;
+pub type Bivector3f     = Bivector3<f32>;       pub type Bivector3d     = Bivector3<f64>;
+pub type Bivector4f     = Bivector4<f32>;       pub type Bivector4d     = Bivector4<f64>;
+pub type Trivector3f    = Trivector3<f32>;      pub type Trivector3d    = Trivector3<f64>;
+pub type Rotor3f        = Rotor3<f32>;          pub type Rotor3d        = Rotor3<f64>;
+
+// Construction
+impl<T: Scalar> Bivector2<T>  { pub fn new(a: T)                                    -> Self { return Self { xy: a }; } }
+impl<T: Scalar> Bivector3<T>  { pub fn new(a: T, b: T, c: T)                    -> Self { return Self { xy: a, xz: b, yz: c }; } }
+impl<T: Scalar> Bivector4<T>  { pub fn new(a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+    return Self { xy: a, xz: b, xw: c, yz: d, yw: e, zw: f }; } }
+impl<T: Scalar> Trivector3<T> { pub fn new(a: T)                                    -> Self { return Self { xyz: a }; } }
+impl<T: Scalar> Rotor3<T>     { pub fn new(a: T, b: Bivector3<T>)                      -> Self { return Self { s: a, xy: b.xy, xz: b.xz, yz: b.yz }; } }
+
+// Read functions
+impl<T: Scalar> Bivector2<T>  { pub fn xy(&self)          -> T { return self.xy; } }
+impl<T: Scalar> Bivector3<T>  { pub fn xy(&self)          -> T { return self.xy; } }
+impl<T: Scalar> Bivector3<T>  { pub fn xz(&self)          -> T { return self.xz; } }
+impl<T: Scalar> Bivector3<T>  { pub fn yz(&self)          -> T { return self.yz; } }
+impl<T: Scalar> Trivector3<T> { pub fn xyz(&self)         -> T { return self.xyz; } }
+impl<T: Scalar> Rotor3<T>     { pub fn scalar(&self)      -> T { return self.s; } }
+impl<T: Scalar> Rotor3<T>     { pub fn bivector(&self)    -> Bivector3<T> { return Bivector3::new(self.xy, self.xz, self.yz); } }
+
+// Prefabrication
+impl<T: Scalar> Bivector2<T>  { pub fn zero()             -> Self { return Self::new(T::ZERO); } }
+impl<T: Scalar> Bivector3<T>  { pub fn zero()             -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO); } }
+impl<T: Scalar> Bivector4<T>  { pub fn zero()             -> Self { return Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO, T::ZERO); } }
+impl<T: Scalar> Trivector3<T> { pub fn zero()             -> Self { return Self::new(T::ZERO); } }
+impl<T: Scalar> Rotor3<T>     { pub fn identity()         -> Self { return Self::new(T::ONE, Bivector3::zero()); } }
+impl<T: Scalar> Bivector3<T>  { pub fn xy_plane()         -> Self { return Self::new(T::ONE, T::ZERO, T::ZERO); } }
+impl<T: Scalar> Bivector3<T>  { pub fn xz_plane()         -> Self { return Self::new(T::ZERO, T::ONE, T::ZERO); } }
+impl<T: Scalar> Bivector3<T>  { pub fn yz_plane()         -> Self { return Self::new(T::ZERO, T::ZERO, T::ONE); } }
+
+//      Transformation Constructors
+//          Rotation by angle t in the plane b (b is expected to be a unit bivector)
+impl<T: Scalar> Rotor3<T>     { pub fn rotation<A: Into<Rad<T>>>(t: A, b: Bivector3<T>) -> Self {
+    let (sin, cos) = (t.into() * T::HALF).sin_cos();
+    return Self::new(cos, b * (-sin)); } }
+//          Rotation taking unit vector a onto unit vector b (R = 1 + ba, normalized).
+//          Opposite vectors leave no plane to rotate in, so any half turn through a will do.
+impl<T: Scalar> Rotor3<T>     { pub fn from_vectors(a: &Vector3<T>, b: &Vector3<T>) -> Self {
+    let (s, plane) = Vector3::product_geometric(b, a);
+    if T::ONE + s > T::TOLERANCE { return Self::new(T::ONE + s, plane).normalization(); }
+    let p = if a.x.abs() > a.z.abs() { Vector3::new(-a.y, a.x, T::ZERO) } else { Vector3::new(T::ZERO, -a.z, a.y) };
+    return Self::new(T::ZERO, (*a) ^ p.normalization()); } }
+
+// Conversion Methods
+//      Rotation around axis n is rotation in the plane dual to n (B = e123 * n), so the bivector parts
+//      map onto the quaternion as i = -yz, j = xz, k = -xy.
+impl<T: Scalar> Rotor3<T>     { pub fn from_quaternion(q: &Quaternion<T>) -> Self {
+    return Self::new(q.s, Bivector3::new(-q.k, q.j, -q.i)); } }
+impl<T: Scalar> Quaternion<T> { pub fn from_rotor3(r: &Rotor3<T>) -> Self {
+    return Self::new(r.s, -r.yz, r.xz, -r.xy); } }
+impl<T: Scalar> Matrix3<T>    { pub fn from_rotor3(r: &Rotor3<T>) -> Self {
+    return Self::from_quaternion(&Quaternion::from_rotor3(r)); } }
+impl<T: Scalar> Matrix4<T>    { pub fn from_rotor3(r: &Rotor3<T>) -> Self {
+    return Self::from_quaternion(&Quaternion::from_rotor3(r)); } }
+//      Hodge dual (normal vector of the plane, and the plane of a normal vector)
+impl<T: Scalar> Vector3<T>    { pub fn from_bivector3(b: &Bivector3<T>) -> Self { return Self::new(b.yz, -b.xz, b.xy); } }
+impl<T: Scalar> Bivector3<T>  { pub fn from_vector3(v: &Vector3<T>) -> Self { return Self::new(v.z, -v.y, v.x); } }
+//      Precision conversion (see linalg for the vector and matrix versions)
+fn cast<T: Scalar, U: Scalar>(a: T) -> U { return U::from_f64(a.to_f64()); }
+impl<T: Scalar> Bivector2<T>  { pub fn cast<U: Scalar>(&self) -> Bivector2<U>  { return Bivector2::new(cast(self.xy)); } }
+impl<T: Scalar> Bivector3<T>  { pub fn cast<U: Scalar>(&self) -> Bivector3<U>  { return Bivector3::new(cast(self.xy), cast(self.xz), cast(self.yz)); } }
+impl<T: Scalar> Bivector4<T>  { pub fn cast<U: Scalar>(&self) -> Bivector4<U>  {
+    return Bivector4::new(cast(self.xy), cast(self.xz), cast(self.xw), cast(self.yz), cast(self.yw), cast(self.zw)); } }
+impl<T: Scalar> Trivector3<T> { pub fn cast<U: Scalar>(&self) -> Trivector3<U> { return Trivector3::new(cast(self.xyz)); } }
+impl<T: Scalar> Rotor3<T>     { pub fn cast<U: Scalar>(&self) -> Rotor3<U>     { return Rotor3::new(cast(self.s), Bivector3::new(cast(self.xy), cast(self.xz), cast(self.yz))); } }
+impl From<Bivector2<f32>>  for Bivector2<f64>  { fn from(b: Bivector2<f32>)  -> Self { return b.cast(); } }
+impl From<Bivector3<f32>>  for Bivector3<f64>  { fn from(b: Bivector3<f32>)  -> Self { return b.cast(); } }
+impl From<Bivector4<f32>>  for Bivector4<f64>  { fn from(b: Bivector4<f32>)  -> Self { return b.cast(); } }
+impl From<Trivector3<f32>> for Trivector3<f64> { fn from(t: Trivector3<f32>) -> Self { return t.cast(); } }
+impl From<Rotor3<f32>>     for Rotor3<f64>     { fn from(r: Rotor3<f32>)     -> Self { return r.cast(); } }
+
+// Utilities
+impl<T: Scalar> Bivector2<T>  { pub fn to_string(&self)   -> String {
+    return format!("[{} e12]", self.xy); } }
+impl<T: Scalar> Bivector3<T>  { pub fn to_string(&self)   -> String {
+    return format!("[{} e12 + {} e13 + {} e23]", self.xy, self.xz, self.yz); } }
+impl<T: Scalar> Bivector4<T>  { pub fn to_string(&self)   -> String {
+    return format!("[{} e12 + {} e13 + {} e14 + {} e23 + {} e24 + {} e34]", self.xy, self.xz, self.xw, self.yz, self.yw, self.zw); } }
+impl<T: Scalar> Trivector3<T> { pub fn to_string(&self)   -> String {
+    return format!("[{} e123]", self.xyz); } }
+impl<T: Scalar> Rotor3<T>     { pub fn to_string(&self)   -> String {
+    return format!("[{} + {} e12 + {} e13 + {} e23]", self.s, self.xy, self.xz, self.yz); } }
+
+// Arithmetic
+impl<T: Scalar> Add<Bivector2<T>> for Bivector2<T> { type Output = Self; fn add(self, b: Self) -> Self {
+    return Self::new(self.xy + b.xy); } }
+impl<T: Scalar> Add<Bivector3<T>> for Bivector3<T> { type Output = Self; fn add(self, b: Self) -> Self {
+    return Self::new(self.xy + b.xy, self.xz + b.xz, self.yz + b.yz); } }
+impl<T: Scalar> Add<Bivector4<T>> for Bivector4<T> { type Output = Self; fn add(self, b: Self) -> Self {
+    return Self::new(self.xy + b.xy, self.xz + b.xz, self.xw + b.xw, self.yz + b.yz, self.yw + b.yw, self.zw + b.zw); } }
+impl<T: Scalar> Add<Trivector3<T>> for Trivector3<T> { type Output = Self; fn add(self, t: Self) -> Self {
+    return Self::new(self.xyz + t.xyz); } }
+impl<T: Scalar> Sub<Bivector2<T>> for Bivector2<T> { type Output = Self; fn sub(self, b: Self) -> Self {
+    return Self::new(self.xy - b.xy); } }
+impl<T: Scalar> Sub<Bivector3<T>> for Bivector3<T> { type Output = Self; fn sub(self, b: Self) -> Self {
+    return Self::new(self.xy - b.xy, self.xz - b.xz, self.yz - b.yz); } }
+impl<T: Scalar> Sub<Bivector4<T>> for Bivector4<T> { type Output = Self; fn sub(self, b: Self) -> Self {
+    return Self::new(self.xy - b.xy, self.xz - b.xz, self.xw - b.xw, self.yz - b.yz, self.yw - b.yw, self.zw - b.zw); } }
+impl<T: Scalar> Sub<Trivector3<T>> for Trivector3<T> { type Output = Self; fn sub(self, t: Self) -> Self {
+    return Self::new(self.xyz - t.xyz); } }
+impl<T: Scalar> Neg for Bivector2<T> { type Output = Self; fn neg(self) -> Self {
+    return Self::new(-self.xy); } }
+impl<T: Scalar> Neg for Bivector3<T> { type Output = Self; fn neg(self) -> Self {
+    return Self::new(-self.xy, -self.xz, -self.yz); } }
+impl<T: Scalar> Neg for Bivector4<T> { type Output = Self; fn neg(self) -> Self {
+    return Self::new(-self.xy, -self.xz, -self.xw, -self.yz, -self.yw, -self.zw); } }
+impl<T: Scalar> Neg for Trivector3<T> { type Output = Self; fn neg(self) -> Self {
+    return Self::new(-self.xyz); } }
+//      Reverse (~R = s - B), which is the inverse of a unit rotor
+impl<T: Scalar> Neg for Rotor3<T> { type Output = Self; fn neg(self) -> Self {
+    return self.reverse(); } }
+impl<T: Scalar> Mul<T> for Bivector2<T> { type Output = Self; fn mul(self, s: T) -> Self {
+    return Self::new(self.xy * s); } }
+impl<T: Scalar> Mul<T> for Bivector3<T> { type Output = Self; fn mul(self, s: T) -> Self {
+    return Self::new(self.xy * s, self.xz * s, self.yz * s); } }
+impl<T: Scalar> Mul<T> for Bivector4<T> { type Output = Self; fn mul(self, s: T) -> Self {
+    return Self::new(self.xy * s, self.xz * s, self.xw * s, self.yz * s, self.yw * s, self.zw * s); } }
+impl<T: Scalar> Mul<T> for Trivector3<T> { type Output = Self; fn mul(self, s: T) -> Self {
+    return Self::new(self.xyz * s); } }
+impl<T: Scalar> Mul<T> for Rotor3<T> { type Output = Self; fn mul(self, s: T) -> Self {
+    return Self { s: self.s * s, xy: self.xy * s, xz: self.xz * s, yz: self.yz * s }; } }
+//      Rotor composition (a * b applies b first, then a)
+impl<T: Scalar> Mul<Rotor3<T>> for Rotor3<T> { type Output = Self; fn mul(self, r: Self) -> Self {
+    return Self {
+        s:  self.s * r.s - self.xy * r.xy - self.xz * r.xz - self.yz * r.yz,
+        xy: self.xy * r.s + self.s * r.xy + self.yz * r.xz - self.xz * r.yz,
+        xz: self.xz * r.s + self.s * r.xz - self.yz * r.xy + self.xy * r.yz,
+        yz: self.yz * r.s + self.s * r.yz + self.xz * r.xy - self.xy * r.xz }; } }
+
+//      Wedge (outer) product: { a ^ b }
+impl<T: Scalar> BitXor<Vector2<T>> for Vector2<T> { type Output = Bivector2<T>; fn bitxor(self, v: Vector2<T>) -> Bivector2<T> {
+    return Bivector2::new(self.x * v.y - self.y * v.x); } }
+impl<T: Scalar> BitXor<Vector3<T>> for Vector3<T> { type Output = Bivector3<T>; fn bitxor(self, v: Vector3<T>) -> Bivector3<T> {
+    return Bivector3::new(
+        self.x * v.y - self.y * v.x,
+        self.x * v.z - self.z * v.x,
+        self.y * v.z - self.z * v.y); } }
+impl<T: Scalar> BitXor<Vector4<T>> for Vector4<T> { type Output = Bivector4<T>; fn bitxor(self, v: Vector4<T>) -> Bivector4<T> {
+    return Bivector4::new(
+        self.x * v.y - self.y * v.x,
+        self.x * v.z - self.z * v.x,
+        self.x * v.w - self.w * v.x,
+        self.y * v.z - self.z * v.y,
+        self.y * v.w - self.w * v.y,
+        self.z * v.w - self.w * v.z); } }
+//          Vectors and bivectors commute under the wedge product, and both give the oriented volume
+impl<T: Scalar> BitXor<Vector3<T>> for Bivector3<T> { type Output = Trivector3<T>; fn bitxor(self, v: Vector3<T>) -> Trivector3<T> {
+    return Trivector3::new(self.xy * v.z - self.xz * v.y + self.yz * v.x); } }
+impl<T: Scalar> BitXor<Bivector3<T>> for Vector3<T> { type Output = Trivector3<T>; fn bitxor(self, b: Bivector3<T>) -> Trivector3<T> {
+    return b ^ self; } }
+
+//      Geometric product of two vectors: (scalar, bivector)
+impl<T: Scalar> Vector2<T> { pub fn product_geometric(a: &Self, b: &Self) -> (T, Bivector2<T>) {
+    return ((*a) * (*b), (*a) ^ (*b)); } }
+impl<T: Scalar> Vector3<T> { pub fn product_geometric(a: &Self, b: &Self) -> (T, Bivector3<T>) {
+    return ((*a) * (*b), (*a) ^ (*b)); } }
+impl<T: Scalar> Vector4<T> { pub fn product_geometric(a: &Self, b: &Self) -> (T, Bivector4<T>) {
+    return ((*a) * (*b), (*a) ^ (*b)); } }
+
+// Geometry
+impl<T: Scalar> Bivector2<T>  { pub fn magnitude(&self)       -> T { return T::abs(self.xy); } }
+impl<T: Scalar> Bivector3<T>  { pub fn magnitude(&self)       -> T { return T::sqrt(self.magnitude_sqr()); } }
+impl<T: Scalar> Bivector4<T>  { pub fn magnitude(&self)       -> T { return T::sqrt(self.magnitude_sqr()); } }
+impl<T: Scalar> Rotor3<T>     { pub fn magnitude(&self)       -> T { return T::sqrt(self.magnitude_sqr()); } }
+impl<T: Scalar> Bivector3<T>  { pub fn magnitude_sqr(&self)   -> T { return self.xy * self.xy + self.xz * self.xz + self.yz * self.yz; } }
+impl<T: Scalar> Bivector4<T>  { pub fn magnitude_sqr(&self)   -> T {
+    return self.xy * self.xy + self.xz * self.xz + self.xw * self.xw + self.yz * self.yz + self.yw * self.yw + self.zw * self.zw; } }
+impl<T: Scalar> Rotor3<T>     { pub fn magnitude_sqr(&self)   -> T { return self.s * self.s + self.xy * self.xy + self.xz * self.xz + self.yz * self.yz; } }
+impl<T: Scalar> Bivector3<T>  { pub fn normalization(&self)   -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+impl<T: Scalar> Bivector4<T>  { pub fn normalization(&self)   -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+impl<T: Scalar> Rotor3<T>     { pub fn normalization(&self)   -> Self { let d = T::ONE / self.magnitude(); return (*self) * d; } }
+impl<T: Scalar> Rotor3<T>     { pub fn reverse(&self)         -> Self { return Self::new(self.s, -self.bivector()); } }
+
+//      Rotation (R * v * ~R, expanded; expects a unit rotor)
+impl<T: Scalar> Rotor3<T> { pub fn rotate(&self, v: &Vector3<T>) -> Vector3<T> {
+    // q = R * v (vector and trivector parts)
+    let qx = self.s * v.x + self.xy * v.y + self.xz * v.z;
+    let qy = self.s * v.y - self.xy * v.x + self.yz * v.z;
+    let qz = self.s * v.z - self.xz * v.x - self.yz * v.y;
+    let qxyz = self.xy * v.z - self.xz * v.y + self.yz * v.x;
+
+    // q * ~R (the trivector part cancels out)
+    return Vector3::new(
+        self.s * qx + self.xy * qy + self.xz * qz + self.yz * qxyz,
+        self.s * qy - self.xy * qx + self.yz * qz - self.xz * qxyz,
+        self.s * qz - self.xz * qx - self.yz * qy + self.xy * qxyz); } }
+impl<T: Scalar> Mul<Vector3<T>> for Rotor3<T> { type Output = Vector3<T>; fn mul(self, v: Vector3<T>) -> Vector3<T> {
+    return self.rotate(&v); } }
@@ -0,0 +1,144 @@
+// Translation, rotation and scale (TRS) as separate parts, for scene hierarchies and animation
+//      Guidance: https://gabormakesgames.com/blog_transforms.html
+use super::*;
+
+/*
+    Transform conventions:
+    - Points are scaled first, then rotated, then translated:   p' = t + r * (s * p),   M = T * R * S
+    - a * b applies b first, then a (same as the matrices).
+    - Rotation is a unit quaternion. Scale is per axis and may be negative (mirroring).
+    - Non-uniform scale under a rotation is not a TRS anymore (it shears), so compose() and inverse() are only
+      exact when the scale is uniform, or when the rotations keep the scale axes aligned. Go through Matrix4
+      when the shear matters.
+*/
+
+// Struct Definitions
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Transform<T: Scalar = f32> { translation: Vector3<T>, rotation: Quaternion<T>, scale: Vector3<T> }
+
+// Precision aliases (the bare names default to f32)
+pub type Transformf     = Transform<f32>;       pub type Transformd     = Transform<f64>;
+
+// Construction
+impl<T: Scalar> Transform<T> { pub fn new(t: Vector3<T>, r: Quaternion<T>, s: Vector3<T>)  -> Self { return Self { translation: t, rotation: r, scale: s }; } }
+impl<T: Scalar> Transform<T> { pub fn from_translation(t: Vector3<T>)                      -> Self { return Self::new(t, Quaternion::identity(), Vector3::one()); } }
+impl<T: Scalar> Transform<T> { pub fn from_rotation(r: Quaternion<T>)                      -> Self { return Self::new(Vector3::zero(), r, Vector3::one()); } }
+impl<T: Scalar> Transform<T> { pub fn from_scale(s: Vector3<T>)                            -> Self { return Self::new(Vector3::zero(), Quaternion::identity(), s); } }
+impl<T: Scalar> Transform<T> { pub fn from_scale_uniform(s: T)                             -> Self { return Self::from_scale(Vector3::new(s, s, s)); } }
+
+// Field Interpretation
+impl<T: Scalar> Transform<T> { pub fn translation(&self)                  -> Vector3<T> { return self.translation; } }
+impl<T: Scalar> Transform<T> { pub fn rotation(&self)                     -> Quaternion<T> { return self.rotation; } }
+impl<T: Scalar> Transform<T> { pub fn scale(&self)                        -> Vector3<T> { return self.scale; } }
+impl<T: Scalar> Transform<T> { pub fn set_translation(&mut self, t: Vector3<T>)   { self.translation = t; } }
+impl<T: Scalar> Transform<T> { pub fn set_rotation(&mut self, r: Quaternion<T>)   { self.rotation = r; } }
+impl<T: Scalar> Transform<T> { pub fn set_scale(&mut self, s: Vector3<T>)         { self.scale = s; } }
+
+// Prefabrication
+impl<T: Scalar> Transform<T> { pub fn identity()  -> Self { return Self::new(Vector3::zero(), Quaternion::identity(), Vector3::one()); } }
+
+// Conversion Methods
+impl<T: Scalar> Transform<T> { pub fn to_matrix4(&self)   -> Matrix4<T> {
+    let r = Matrix3::from_quaternion(&self.rotation);
+    let (s, t) = (self.scale, self.translation);
+    return Matrix4::new(
+        r.e[0][0] * s.x,    r.e[1][0] * s.y,    r.e[2][0] * s.z,    t.x,
+        r.e[0][1] * s.x,    r.e[1][1] * s.y,    r.e[2][1] * s.z,    t.y,
+        r.e[0][2] * s.x,    r.e[1][2] * s.y,    r.e[2][2] * s.z,    t.z,
+        T::ZERO,            T::ZERO,            T::ZERO,            T::ONE); } }
+impl<T: Scalar> Matrix4<T>    { pub fn from_transform(t: &Transform<T>) -> Self { return t.to_matrix4(); } }
+//      Decomposition of an affine matrix (the bottom row is expected to be [0, 0, 0, 1])
+//          The upper 3x3 is split into rotation * stretch (polar decomposition), and the diagonal of the stretch
+//          becomes the scale. Shear (the off-diagonal part of the stretch) has no place in a TRS and is dropped.
+//          A mirroring matrix gets a negative scale on all three axes, so the rotation stays proper.
+impl<T: Scalar> Transform<T> { pub fn from_matrix4(m: &Matrix4<T>) -> Self {
+    let (mut q, mut p) = Matrix3::from_matrix4(m).polar_decomposition();
+    if q.determinant() < T::ZERO {
+        q *= -T::ONE;
+        p *= -T::ONE;
+    }
+    return Self::new(Vector3::new(m.e[3][0], m.e[3][1], m.e[3][2]), Quaternion::from_matrix3(&q), p.diagonal()); } }
+impl<T: Scalar> Matrix4<T>    { pub fn to_transform(&self)        -> Transform<T> { return Transform::from_matrix4(self); } }
+//      Polar decomposition M = Q * P (Q orthogonal, P symmetric positive semi-definite), by Newton iteration
+//      Q <- (Q + Q^-T) / 2, which converges quadratically for non-singular M.
+//      Higham, "Computing the polar decomposition - with applications": https://doi.org/10.1137/0907079
+//      A singular M (a zero scale somewhere) has no inverse to iterate with, so its remaining axes are orthonormalized
+//      instead, and the collapsed ones filled in to make Q a rotation.
+impl<T: Scalar> Matrix3<T>    { pub fn polar_decomposition(&self) -> (Matrix3<T>, Matrix3<T>) {
+    let mut q = *self;
+    for _ in 0..32 {
+        let inverse = match q.try_inverse() { Some(i) => i, None => { q = orthonormalized(&q); break; } };
+        let next = (q + inverse.transpose()) * T::HALF;
+        let done = next.approx_eq_eps(&q, T::EPSILON * T::from_f32(16.0), TestMode::AbsoluteCoordinates);
+        q = next;
+        if done { break; }
+    }
+    return (q, q.transpose() * (*self)); } }
+//      Gram-Schmidt on the columns, longest first, dropping the ones that are (nearly) in the span of the others.
+//      A dropped column i becomes column i + 1 cross column i + 2 (or any perpendicular, if that is dropped too).
+fn orthonormalized<T: Scalar>(m: &Matrix3<T>) -> Matrix3<T> {
+    let columns = [m.column(0), m.column(1), m.column(2)];
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| columns[j].magnitude_sqr().partial_cmp(&columns[i].magnitude_sqr()).unwrap_or(std::cmp::Ordering::Equal));
+    let tolerance = T::TOLERANCE * columns[order[0]].magnitude();
+    if tolerance <= T::ZERO { return Matrix3::identity(); }
+    let mut axes: [Option<Vector3<T>>; 3] = [None; 3];
+    for &i in order.iter() {
+        let mut c = columns[i];
+        for a in axes.iter().flatten() { c -= (*a) * ((*a) * c); }
+        if c.magnitude() > tolerance { axes[i] = Some(c.normalization()); }
+    }
+    let perpendicular = |a: &Vector3<T>| -> Vector3<T> {
+        let least = if a.x.abs() <= a.y.abs() && a.x.abs() <= a.z.abs() { Vector3::right() } else if a.y.abs() <= a.z.abs() { Vector3::forth() } else { Vector3::up() };
+        return ((*a) / least).normalization(); };
+    for &i in order.iter() {
+        if axes[i].is_some() { continue; }
+        axes[i] = Some(match (axes[(i + 1) % 3], axes[(i + 2) % 3]) {
+            (Some(a), Some(b)) => a / b,
+            (Some(a), None) | (None, Some(a)) => perpendicular(&a),
+            (None, None) => unreachable!(),
+        });
+    }
+    let (x, y, z) = (axes[0].unwrap(), axes[1].unwrap(), axes[2].unwrap());
+    return Matrix3::new(
+        x.x,    y.x,    z.x,
+        x.y,    y.y,    z.y,
+        x.z,    y.z,    z.z); }
+//      Precision conversion
+impl<T: Scalar> Transform<T> { pub fn cast<U: Scalar>(&self) -> Transform<U> {
+    return Transform::new(self.translation.cast(), self.rotation.cast(), self.scale.cast()); } }
+impl From<Transform<f32>> for Transform<f64> { fn from(t: Transform<f32>) -> Self { return t.cast(); } }
+
+// Utilities
+impl<T: Scalar> Transform<T> { pub fn to_string(&self)    -> String {
+    return format!("{{ t: {}, r: {}, s: {} }}", self.translation.to_string(), self.rotation.to_string(), self.scale.to_string()); } }
+
+// Arithmetic
+fn mul_elements<T: Scalar>(a: &Vector3<T>, b: &Vector3<T>) -> Vector3<T> { return Vector3::new(a.x * b.x, a.y * b.y, a.z * b.z); }
+//      Composition (a * b applies b first, then a)
+impl<T: Scalar> Transform<T> { pub fn compose(a: &Self, b: &Self) -> Self {
+    return Self::new(a.transform_point(&b.translation), a.rotation * b.rotation, mul_elements(&a.scale, &b.scale)); } }
+impl<T: Scalar> Mul<Transform<T>> for Transform<T> { type Output = Self; fn mul(self, t: Self) -> Self { return Self::compose(&self, &t); } }
+impl<T: Scalar> MulAssign<Transform<T>> for Transform<T> { fn mul_assign(&mut self, t: Self) { *self = Self::compose(self, &t); } }
+//      Cheap inverse (no matrix inversion; see the conventions above for when it is exact)
+impl<T: Scalar> Transform<T> { pub fn inverse(&self)      -> Self {
+    let r = self.rotation.conjugate();
+    let s = Vector3::new(T::ONE / self.scale.x, T::ONE / self.scale.y, T::ONE / self.scale.z);
+    return Self::new(mul_elements(&s, &r.rotate(&(-self.translation))), r, s); } }
+impl<T: Scalar> Not for Transform<T> { type Output = Self; fn not(self) -> Self { return self.inverse(); } }
+
+// Inter-Struct Product
+impl<T: Scalar> Transform<T> { pub fn transform_point(&self, p: &Vector3<T>)      -> Vector3<T> {
+    return self.rotation.rotate(&mul_elements(&self.scale, p)) + self.translation; } }
+impl<T: Scalar> Transform<T> { pub fn transform_direction(&self, d: &Vector3<T>)  -> Vector3<T> {
+    return self.rotation.rotate(&mul_elements(&self.scale, d)); } }
+impl<T: Scalar> Mul<Vector3<T>> for Transform<T> { type Output = Vector3<T>; fn mul(self, p: Vector3<T>) -> Vector3<T> { return self.transform_point(&p); } }
+
+// Interpolation (lerp for translation and scale, slerp for rotation)
+impl<T: Scalar> Transform<T> { pub fn lerp(a: &Self, b: &Self, t: T) -> Self {
+    return Self::new(
+        Vector3::lerp(&a.translation, &b.translation, t),
+        Quaternion::slerp(&a.rotation, &b.rotation, t),
+        Vector3::lerp(&a.scale, &b.scale, t)); } }
+
+// Queries
+impl<T: Scalar> Transform<T> { pub fn approx_eq(&self, t: &Self) -> bool { return self.to_matrix4().approx_eq(&t.to_matrix4()); } }
@@ -0,0 +1,367 @@
+// Procedural noise: gradient (Perlin), simplex, value and cellular (Worley) noise, with fractal sums and domain warping
+//      Guidance: https://thebookofshaders.com/11/ and https://iquilezles.org/articles/fbm/
+//      Perlin, "Improving Noise": https://mrl.cs.nyu.edu/~perlin/paper445.pdf
+//      Gustavson, "Simplex noise demystified": https://weber.itn.liu.se/~stegu/simplexnoise/simplexnoise.pdf
+//      Worley, "A Cellular Texture Basis Function": https://doi.org/10.1145/237170.237267
+//      Perlin scale factors: the largest values are reached at the cell centre, where every corner offset is 1/2 per axis
+//      and weighted by 1/2^n. With each corner's gradient pointing at the centre, 2D (unit gradients) peaks at sqrt(2)/2,
+//      so it scales by sqrt(2), and 3D (edge gradients, two components of ±1) peaks at 1. The 4D bound of 3/2 needs all
+//      16 corners to line up, which a hash practically never does; 0.87 is the reciprocal of the largest value seen over
+//      millions of samples (about 1.15).
+use super::linalg::*;
+
+/*
+    Noise conventions:
+    - Every source samples 2D, 3D and 4D points (Vector2, Vector3, Vector4), with one lattice cell per unit.
+      Scale the input for bigger or smaller features.
+    - The same seed always gives the same noise, on every platform. Lattice points are hashed, not looked up in a
+      permutation table, so the noise does not repeat (until the i32 cell coordinates wrap).
+    - Gradient, simplex and value noise are 0 on average and stay within [-1, 1]. Gradient and simplex noise are 0 at
+      the lattice points.
+    - Cellular noise returns distances (0 at the feature points, and mostly below 1 for F1), or a per-cell value in [-1, 1].
+    - Fractal sums are normalized by the total amplitude: fBm stays within [-1, 1], ridged and turbulence within [0, 1].
+*/
+
+// Struct Definitions
+//      The points noise is sampled at
+pub trait NoisePoint<T: Scalar>: Copy {
+    const DIMENSION: usize;
+    // Coordinates, padded with zeroes past DIMENSION
+    fn coordinates(&self) -> [T; 4];
+    fn from_coordinates(c: &[T; 4]) -> Self;
+}
+pub trait Noise<T: Scalar, P: NoisePoint<T>> { fn sample(&self, p: &P) -> T; }
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub struct Perlin   { seed: u32 }
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub struct Simplex  { seed: u32 }
+//      Smoothly interpolated random values at the lattice points (blobbier than gradient noise)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub struct Value    { seed: u32 }
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub enum DistanceMetric { Euclidean, Manhattan, Chebyshev }
+//      What a cellular sample returns: distance to the nearest feature point (F1), to the second nearest (F2),
+//      their difference (cell borders), or a random value per cell (Voronoi regions)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub enum CellularReturn { Distance, Distance2, Difference, CellValue }
+//      One jittered feature point per lattice cell
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub struct Worley   { seed: u32, metric: DistanceMetric, output: CellularReturn }
+//      Everything about the nearest feature points (id is the nearest cell's hash, to key regions by)
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct CellSample<T: Scalar = f32> { f1: T, f2: T, id: u32 }
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub enum FractalKind { Fbm, Ridged, Turbulence }
+//      Octaves of a source, each lacunarity times the frequency and gain times the amplitude of the last
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Fractal<N, T: Scalar = f32> { source: N, kind: FractalKind, octaves: usize, lacunarity: T, gain: T }
+//      Samples the source at p + strength * (warp noise per axis), for swirling, eroded looking patterns
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct DomainWarp<N, W, T: Scalar = f32> { source: N, warp: W, strength: T }
+
+// Precision aliases (the bare names default to f32)
+pub type CellSamplef    = CellSample<f32>;      pub type CellSampled    = CellSample<f64>;
+
+// Sample points
+impl<T: Scalar> NoisePoint<T> for Vector2<T> { const DIMENSION: usize = 2;
+    fn coordinates(&self) -> [T; 4] { return [self.x(), self.y(), T::ZERO, T::ZERO]; }
+    fn from_coordinates(c: &[T; 4]) -> Self { return Vector2::new(c[0], c[1]); } }
+impl<T: Scalar> NoisePoint<T> for Vector3<T> { const DIMENSION: usize = 3;
+    fn coordinates(&self) -> [T; 4] { return [self.x(), self.y(), self.z(), T::ZERO]; }
+    fn from_coordinates(c: &[T; 4]) -> Self { return Vector3::new(c[0], c[1], c[2]); } }
+impl<T: Scalar> NoisePoint<T> for Vector4<T> { const DIMENSION: usize = 4;
+    fn coordinates(&self) -> [T; 4] { return [self.x(), self.y(), self.z(), self.w()]; }
+    fn from_coordinates(c: &[T; 4]) -> Self { return Vector4::new(c[0], c[1], c[2], c[3]); } }
+
+// Hashing
+//      Chris Wellons' lowbias32 integer hash: https://nullprogram.com/blog/2018/07/31/
+fn mix(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846c_a68b);
+    x ^= x >> 16;
+    return x; }
+fn hash(seed: u32, cell: &[i32]) -> u32 { return cell.iter().fold(mix(seed ^ 0x9e37_79b9), |h, c| mix(h.wrapping_add(*c as u32))); }
+//      Uniform in [0, 1) and [-1, 1)
+fn unit<T: Scalar>(h: u32) -> T { return T::from_f64((h >> 8) as f64 / (1u32 << 24) as f64); }
+fn signed_unit<T: Scalar>(h: u32) -> T { return unit::<T>(h) * T::TWO - T::ONE; }
+
+// Lattice helpers
+fn floor_cell<T: Scalar>(x: T) -> i32 { return x.floor().to_f64() as i32; }
+//      Perlin's quintic fade, 6t^5 - 15t^4 + 10t^3 (no second derivative jumps at the cell borders)
+fn fade<T: Scalar>(t: T) -> T { return t * t * t * (t * (t * T::from_f32(6.0) - T::from_f32(15.0)) + T::from_f32(10.0)); }
+//      Gradient dotted with the offset from its lattice point. 2D picks one of 8 unit directions, 3D and 4D one of the
+//      edge midpoints of the cube (12 and 32 directions), as in improved Perlin noise.
+fn gradient<T: Scalar>(h: u32, d: &[T]) -> T {
+    if d.len() == 2 {
+        let (c, s) = (T::from_f64(std::f64::consts::FRAC_1_SQRT_2), T::ZERO);
+        let (gx, gy) = match h & 7 {
+            0 => (T::ONE, s),   1 => (c, c),    2 => (s, T::ONE),   3 => (-c, c),
+            4 => (-T::ONE, s),  5 => (-c, -c),  6 => (s, -T::ONE),  _ => (c, -c),
+        };
+        return gx * d[0] + gy * d[1];
+    }
+    let skip = (h >> 8) as usize % d.len();
+    return (0..d.len()).filter(|k| *k != skip).fold(T::ZERO, |s, k| if h & (1 << k) == 0 { s + d[k] } else { s - d[k] }); }
+
+// Noise sources
+impl Perlin     { pub fn new(seed: u32) -> Self { return Self { seed }; } }
+impl Simplex    { pub fn new(seed: u32) -> Self { return Self { seed }; } }
+impl Value      { pub fn new(seed: u32) -> Self { return Self { seed }; } }
+impl Worley     { pub fn new(seed: u32) -> Self { return Self { seed, metric: DistanceMetric::Euclidean, output: CellularReturn::Distance }; } }
+impl Worley     { pub fn with_options(seed: u32, metric: DistanceMetric, output: CellularReturn) -> Self { return Self { seed, metric, output }; } }
+impl Perlin     { pub fn seed(&self) -> u32 { return self.seed; } }
+impl Simplex    { pub fn seed(&self) -> u32 { return self.seed; } }
+impl Value      { pub fn seed(&self) -> u32 { return self.seed; } }
+impl Worley     { pub fn seed(&self) -> u32 { return self.seed; } }
+impl Worley     { pub fn metric(&self) -> DistanceMetric { return self.metric; } }
+impl Worley     { pub fn output(&self) -> CellularReturn { return self.output; } }
+impl<T: Scalar> CellSample<T> { pub fn f1(&self) -> T { return self.f1; } }
+impl<T: Scalar> CellSample<T> { pub fn f2(&self) -> T { return self.f2; } }
+impl<T: Scalar> CellSample<T> { pub fn id(&self)  -> u32 { return self.id; } }
+
+//      Gradient noise: the fade-weighted blend of the 2^n corner gradients
+fn perlin<T: Scalar>(seed: u32, p: &[T]) -> T {
+    let n = p.len();
+    let (mut cell, mut f, mut u) = ([0i32; 4], [T::ZERO; 4], [T::ZERO; 4]);
+    for k in 0..n {
+        cell[k] = floor_cell(p[k]);
+        f[k] = p[k] - T::from_f64(cell[k] as f64);
+        u[k] = fade(f[k]);
+    }
+    let mut sum = T::ZERO;
+    for corner in 0..1usize << n {
+        let (mut c, mut d, mut weight) = (cell, f, T::ONE);
+        for k in 0..n {
+            if corner & (1 << k) != 0 {
+                c[k] += 1;
+                d[k] -= T::ONE;
+                weight *= u[k];
+            } else {
+                weight *= T::ONE - u[k];
+            }
+        }
+        sum += weight * gradient(hash(seed, &c[..n]), &d[..n]);
+    }
+    // Scaled by the reciprocal of the largest value (see Perlin scale factors above), and clamped in case a rarer one is larger still
+    let scale = match n { 2 => std::f64::consts::SQRT_2, 3 => 1.0, _ => 0.87 };
+    return T::min(T::max(sum * T::from_f64(scale), -T::ONE), T::ONE); }
+impl<T: Scalar, P: NoisePoint<T>> Noise<T, P> for Perlin { fn sample(&self, p: &P) -> T { return perlin(self.seed, &p.coordinates()[..P::DIMENSION]); } }
+
+//      Simplex noise: skew onto the lattice of simplices, then sum radially fading kernels around the n + 1 corners.
+//      Cheaper than gradient noise in higher dimensions (n + 1 corners instead of 2^n), and without its axis alignment.
+fn simplex<T: Scalar>(seed: u32, p: &[T]) -> T {
+    let n = p.len();
+    let root = T::sqrt(T::from_f64((n + 1) as f64));
+    let dimension = T::from_f64(n as f64);
+    let (skew, unskew) = ((root - T::ONE) / dimension, (T::ONE - T::ONE / root) / dimension);
+    let s = p.iter().fold(T::ZERO, |s, x| s + *x) * skew;
+    let (mut cell, mut x0) = ([0i32; 4], [T::ZERO; 4]);
+    for k in 0..n { cell[k] = floor_cell(p[k] + s); }
+    let t = T::from_f64(cell[..n].iter().map(|c| *c as f64).sum::<f64>()) * unskew;
+    for k in 0..n { x0[k] = p[k] - (T::from_f64(cell[k] as f64) - t); }
+    // Walk from the origin corner along the axes in order of decreasing offset
+    let mut order = [0usize, 1, 2, 3];
+    order[..n].sort_by(|a, b| x0[*b].partial_cmp(&x0[*a]).unwrap_or(std::cmp::Ordering::Equal));
+    let (mut sum, mut c, mut offset) = (T::ZERO, cell, [T::ZERO; 4]);
+    for corner in 0..=n {
+        if corner > 0 {
+            c[order[corner - 1]] += 1;
+            offset[order[corner - 1]] += T::ONE;
+        }
+        let mut d = [T::ZERO; 4];
+        for k in 0..n { d[k] = x0[k] - offset[k] + unskew * T::from_f64(corner as f64); }
+        let falloff = T::HALF - d[..n].iter().fold(T::ZERO, |s, x| s + *x * *x);
+        if falloff > T::ZERO { sum += falloff * falloff * falloff * falloff * gradient(hash(seed, &c[..n]), &d[..n]); }
+    }
+    let scale = match n { 2 => 99.2, 3 => 76.9, _ => 63.0 };
+    return T::min(T::max(sum * T::from_f64(scale), -T::ONE), T::ONE); }
+impl<T: Scalar, P: NoisePoint<T>> Noise<T, P> for Simplex { fn sample(&self, p: &P) -> T { return simplex(self.seed, &p.coordinates()[..P::DIMENSION]); } }
+
+//      Value noise: the fade-weighted blend of random corner values
+fn value<T: Scalar>(seed: u32, p: &[T]) -> T {
+    let n = p.len();
+    let (mut cell, mut u) = ([0i32; 4], [T::ZERO; 4]);
+    for k in 0..n {
+        cell[k] = floor_cell(p[k]);
+        u[k] = fade(p[k] - T::from_f64(cell[k] as f64));
+    }
+    let mut sum = T::ZERO;
+    for corner in 0..1usize << n {
+        let (mut c, mut weight) = (cell, T::ONE);
+        for k in 0..n {
+            if corner & (1 << k) != 0 { c[k] += 1; weight *= u[k]; } else { weight *= T::ONE - u[k]; }
+        }
+        sum += weight * signed_unit::<T>(hash(seed, &c[..n]));
+    }
+    return sum; }
+impl<T: Scalar, P: NoisePoint<T>> Noise<T, P> for Value { fn sample(&self, p: &P) -> T { return value(self.seed, &p.coordinates()[..P::DIMENSION]); } }
+
+//      Cellular noise: the feature points of the 3^n surrounding cells. With the points jittered over their whole cell,
+//      F2 can (rarely) sit further out than that, and comes out a little too large.
+fn cellular<T: Scalar>(seed: u32, metric: DistanceMetric, p: &[T]) -> CellSample<T> {
+    let n = p.len();
+    let mut cell = [0i32; 4];
+    for k in 0..n { cell[k] = floor_cell(p[k]); }
+    let mut best = CellSample { f1: T::INFINITY, f2: T::INFINITY, id: 0 };
+    for neighbour in 0..3usize.pow(n as u32) {
+        let mut c = cell;
+        let mut code = neighbour;
+        for k in c.iter_mut().take(n) {
+            *k += (code % 3) as i32 - 1;
+            code /= 3;
+        }
+        let h = hash(seed, &c[..n]);
+        let mut distance = T::ZERO;
+        for k in 0..n {
+            let feature = T::from_f64(c[k] as f64) + unit::<T>(mix(h.wrapping_add(k as u32 + 1)));
+            let d = (feature - p[k]).abs();
+            distance = match metric {
+                DistanceMetric::Euclidean => distance + d * d,
+                DistanceMetric::Manhattan => distance + d,
+                DistanceMetric::Chebyshev => T::max(distance, d),
+            };
+        }
+        if metric == DistanceMetric::Euclidean { distance = distance.sqrt(); }
+        if distance < best.f1 {
+            best = CellSample { f1: distance, f2: best.f1, id: h };
+        } else if distance < best.f2 {
+            best.f2 = distance;
+        }
+    }
+    return best; }
+impl Worley { pub fn cell<T: Scalar, P: NoisePoint<T>>(&self, p: &P) -> CellSample<T> { return cellular(self.seed, self.metric, &p.coordinates()[..P::DIMENSION]); } }
+impl<T: Scalar, P: NoisePoint<T>> Noise<T, P> for Worley { fn sample(&self, p: &P) -> T {
+    let cell = self.cell(p);
+    return match self.output {
+        CellularReturn::Distance => cell.f1,
+        CellularReturn::Distance2 => cell.f2,
+        CellularReturn::Difference => cell.f2 - cell.f1,
+        CellularReturn::CellValue => signed_unit(mix(cell.id)),
+    }; } }
+
+// Fractal sums
+impl<N, T: Scalar> Fractal<N, T> { pub fn new(source: N, kind: FractalKind, octaves: usize, lacunarity: T, gain: T) -> Self {
+    return Self { source, kind, octaves, lacunarity, gain }; } }
+//      The usual settings: double the frequency and halve the amplitude per octave
+impl<N, T: Scalar> Fractal<N, T> { pub fn fbm(source: N, octaves: usize)        -> Self { return Self::new(source, FractalKind::Fbm, octaves, T::TWO, T::HALF); } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn ridged(source: N, octaves: usize)     -> Self { return Self::new(source, FractalKind::Ridged, octaves, T::TWO, T::HALF); } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn turbulence(source: N, octaves: usize) -> Self { return Self::new(source, FractalKind::Turbulence, octaves, T::TWO, T::HALF); } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn source(&self)       -> &N { return &self.source; } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn kind(&self)         -> FractalKind { return self.kind; } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn octaves(&self)      -> usize { return self.octaves; } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn lacunarity(&self)   -> T { return self.lacunarity; } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn gain(&self)         -> T { return self.gain; } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn set_octaves(&mut self, octaves: usize)  { self.octaves = octaves; } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn set_lacunarity(&mut self, lacunarity: T) { self.lacunarity = lacunarity; } }
+impl<N, T: Scalar> Fractal<N, T> { pub fn set_gain(&mut self, gain: T)            { self.gain = gain; } }
+//      Each octave is also shifted, so the octaves do not all line up (and vanish together) at the lattice points.
+//      Ridged noise folds the source at zero into sharp crests, and lets each octave's crests gate the next octave
+//      (Musgrave's ridged multifractal, in Ebert et al., "Texturing and Modeling: A Procedural Approach").
+impl<T: Scalar, P: NoisePoint<T>, N: Noise<T, P>> Noise<T, P> for Fractal<N, T> { fn sample(&self, p: &P) -> T {
+    let (mut sum, mut total, mut amplitude, mut frequency, mut weight) = (T::ZERO, T::ZERO, T::ONE, T::ONE, T::ONE);
+    let c = p.coordinates();
+    for octave in 0..self.octaves {
+        let mut shifted = [T::ZERO; 4];
+        for k in 0..4 { shifted[k] = c[k] * frequency + T::from_f64(octave as f64 * 31.416 + k as f64 * 17.17); }
+        let x = self.source.sample(&P::from_coordinates(&shifted));
+        sum += amplitude * match self.kind {
+            FractalKind::Fbm => x,
+            FractalKind::Turbulence => x.abs(),
+            FractalKind::Ridged => {
+                let signal = (T::ONE - x.abs()) * (T::ONE - x.abs()) * weight;
+                weight = T::min(T::max(signal * T::TWO, T::ZERO), T::ONE);
+                signal
+            }
+        };
+        total += amplitude;
+        amplitude *= self.gain;
+        frequency *= self.lacunarity;
+    }
+    return if total > T::ZERO { sum / total } else { T::ZERO }; } }
+
+// Domain warping
+impl<N, W, T: Scalar> DomainWarp<N, W, T> { pub fn new(source: N, warp: W, strength: T) -> Self { return Self { source, warp, strength }; } }
+impl<N, W, T: Scalar> DomainWarp<N, W, T> { pub fn source(&self)     -> &N { return &self.source; } }
+impl<N, W, T: Scalar> DomainWarp<N, W, T> { pub fn warp(&self)       -> &W { return &self.warp; } }
+impl<N, W, T: Scalar> DomainWarp<N, W, T> { pub fn strength(&self)   -> T { return self.strength; } }
+impl<N, W, T: Scalar> DomainWarp<N, W, T> { pub fn set_strength(&mut self, strength: T) { self.strength = strength; } }
+//      The offset along each axis is the warp noise sampled at a different, far away place
+impl<N, W, T: Scalar> DomainWarp<N, W, T> { pub fn displacement<P: NoisePoint<T>>(&self, p: &P) -> P where W: Noise<T, P> {
+    let c = p.coordinates();
+    let mut d = [T::ZERO; 4];
+    for (k, dk) in d.iter_mut().enumerate().take(P::DIMENSION) {
+        let mut shifted = c;
+        for (j, s) in shifted.iter_mut().enumerate() { *s += T::from_f64((k + 1) as f64 * [37.31, 71.93, 13.17, 53.71][j]); }
+        *dk = self.warp.sample(&P::from_coordinates(&shifted)) * self.strength;
+    }
+    return P::from_coordinates(&d); } }
+impl<T: Scalar, P: NoisePoint<T>, N: Noise<T, P>, W: Noise<T, P>> Noise<T, P> for DomainWarp<N, W, T> { fn sample(&self, p: &P) -> T {
+    let (c, d) = (p.coordinates(), self.displacement(p).coordinates());
+    let mut warped = [T::ZERO; 4];
+    for k in 0..4 { warped[k] = c[k] + d[k]; }
+    return self.source.sample(&P::from_coordinates(&warped)); } }
+
+// Utilities
+impl DistanceMetric { pub fn to_string(&self)  -> String { return format!("{:?}", self); } }
+impl CellularReturn { pub fn to_string(&self)  -> String { return format!("{:?}", self); } }
+impl FractalKind    { pub fn to_string(&self)  -> String { return format!("{:?}", self); } }
+impl<T: Scalar> CellSample<T> { pub fn cast<U: Scalar>(&self) -> CellSample<U> { return CellSample { f1: U::from_f64(self.f1.to_f64()), f2: U::from_f64(self.f2.to_f64()), id: self.id }; } }
+impl From<CellSample<f32>> for CellSample<f64> { fn from(c: CellSample<f32>) -> Self { return c.cast(); } }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_noise() {
+        let points: Vec<Vector3<f64>> = (0..500).map(|i| Vector3::new(i as f64 * 0.731, i as f64 * -0.377 + 0.5, (i % 17) as f64 * 1.913)).collect();
+        assert!(points.iter().all(|p| Perlin::new(7).sample(p) == Perlin::new(7).sample(p)));
+        assert!(points.iter().any(|p| Perlin::new(7).sample(p) != Perlin::new(8).sample(p)));
+        assert!(points.iter().any(|p| Simplex::new(7).sample(p) != Simplex::new(8).sample(p)));
+        assert!(points.iter().any(|p| Value::new(7).sample(p) != Value::new(8).sample(p)));
+    }
+
+    #[test]
+    fn sources_stay_within_unit_range() {
+        let points: Vec<Vector3<f64>> = (0..500).map(|i| Vector3::new(i as f64 * 0.731, i as f64 * -0.377 + 0.5, (i % 17) as f64 * 1.913)).collect();
+        for p in points.iter() {
+            let flat = Vector2::new(p[0], p[1]);
+            let deep = Vector4::new(p[0], p[1], p[2], p[0] - p[1]);
+            for n in [Perlin::new(7).sample(p), Simplex::new(7).sample(p), Value::new(7).sample(p), Perlin::new(7).sample(&flat), Simplex::new(7).sample(&deep)].iter() {
+                assert!(n.abs() <= 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn gradient_noise_vanishes_on_the_lattice() {
+        assert_eq!(Perlin::new(7).sample(&Vector3::new(3.0, -2.0, 5.0)), 0.0);
+        assert_eq!(Simplex::new(7).sample(&Vector2::new(0.0f64, 0.0)), 0.0);
+        assert_eq!(Perlin::new(1).sample(&Vector4::new(1.0f64, 2.0, 3.0, 4.0)), 0.0);
+    }
+
+    #[test]
+    fn cellular_noise_sorts_its_two_nearest_features() {
+        let worley = Worley::new(3);
+        let difference = Worley::with_options(3, DistanceMetric::Euclidean, CellularReturn::Difference);
+        let points: Vec<Vector3<f64>> = (0..500).map(|i| Vector3::new(i as f64 * 0.731, i as f64 * -0.377 + 0.5, (i % 17) as f64 * 1.913)).collect();
+        for p in points.iter() {
+            let cell = worley.cell(p);
+            assert!(0.0 <= cell.f1() && cell.f1() <= cell.f2());
+            let d: f64 = difference.sample(p);
+            assert!((d - (cell.f2() - cell.f1())).abs() < 1e-12);
+            assert_eq!(worley.cell(&(*p + Vector3::new(1e-9, 0.0, 0.0))).id(), cell.id());
+        }
+    }
+
+    #[test]
+    fn fractal_sums_keep_their_ranges() {
+        let (fbm, ridged, turbulence) = (Fractal::fbm(Perlin::new(7), 5), Fractal::ridged(Simplex::new(7), 5), Fractal::turbulence(Perlin::new(7), 5));
+        let points: Vec<Vector3<f64>> = (0..500).map(|i| Vector3::new(i as f64 * 0.731, i as f64 * -0.377 + 0.5, (i % 17) as f64 * 1.913)).collect();
+        for p in points.iter() {
+            assert!(fbm.sample(p).abs() <= 1.0);
+            assert!((0.0..=1.0).contains(&ridged.sample(p)) && (0.0..=1.0).contains(&turbulence.sample(p)));
+        }
+    }
+
+    #[test]
+    fn domain_warping_moves_the_source() {
+        let perlin = Perlin::new(7);
+        let points: Vec<Vector3<f64>> = (0..500).map(|i| Vector3::new(i as f64 * 0.731, i as f64 * -0.377 + 0.5, (i % 17) as f64 * 1.913)).collect();
+        let warped = DomainWarp::new(perlin, Fractal::fbm(Simplex::new(7), 3), 2.0);
+        assert!(points.iter().any(|p| warped.sample(p) != perlin.sample(p)));
+        assert_eq!(DomainWarp::new(perlin, Simplex::new(7), 0.0).sample(&points[3]), perlin.sample(&points[3]));
+    }
+}
@@ -0,0 +1,278 @@
+// Easing curves (the Penner set), tweening, and spring smoothing
+//      Guidance: https://easings.net/ (the formulas below follow its listings)
+//      Robert Penner, "Motion, Tweening, and Easing": http://robertpenner.com/easing/penner_chapter7_tweening.pdf
+use super::*;
+use super::super::linalg::{ Vector2, Vector3, Vector4, Complex, Dual, Quaternion, Transform };
+use std::ops::{ Add, Sub, Mul };
+
+/*
+    Easing conventions:
+    - An easing function maps progress t in [0, 1] to eased progress, with f(0) = 0 and f(1) = 1.
+      In starts slow, out ends slow, in-out does both (and is symmetric around t = 0.5).
+      Back and elastic overshoot below 0 and above 1 on the way, so the eased progress has to be allowed to extrapolate.
+    - The free functions take t as is. Easing::apply() clamps t to [0, 1] first.
+    - Anything with a lerp can be tweened (Lerp below). Quaternions slerp and transforms slerp their rotation,
+      so rotations stay rotations.
+    - The springs and smooth_damp() need the vector space operations too (VectorSpace below: every lerp-able type
+      except Transform).
+      They are frame-rate independent: any dt gives the same motion, up to rounding (smooth_damp up to its approximation).
+*/
+
+// Struct Definitions
+pub trait Lerp<T: Scalar>: Copy { fn lerp(a: &Self, b: &Self, t: T) -> Self; }
+//      Values that add and scale (what the springs need beyond lerp)
+pub trait VectorSpace<T: Scalar>: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<T, Output = Self> {}
+impl<T: Scalar, V: Copy + Add<Output = V> + Sub<Output = V> + Mul<T, Output = V>> VectorSpace<T> for V {}
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub enum Easing {
+    Linear,
+    QuadIn,     QuadOut,    QuadInOut,
+    CubicIn,    CubicOut,   CubicInOut,
+    QuartIn,    QuartOut,   QuartInOut,
+    QuintIn,    QuintOut,   QuintInOut,
+    SineIn,     SineOut,    SineInOut,
+    ExpoIn,     ExpoOut,    ExpoInOut,
+    CircIn,     CircOut,    CircInOut,
+    BackIn,     BackOut,    BackInOut,
+    ElasticIn,  ElasticOut, ElasticInOut,
+    BounceIn,   BounceOut,  BounceInOut,
+}
+//      A running interpolation from one value to another over a duration
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Tween<V, T: Scalar = f32> { start: V, end: V, duration: T, elapsed: T, easing: Easing }
+//      A value pulled towards a target by a critically damped spring (the fastest approach without overshoot)
+#[derive(Debug, Copy, Clone, PartialEq)] pub struct Spring<V, T: Scalar = f32> { value: V, velocity: V, frequency: T }
+
+// Lerp implementations
+impl Lerp<f32> for f32 { fn lerp(a: &Self, b: &Self, t: f32) -> Self { return a + (b - a) * t; } }
+impl Lerp<f64> for f64 { fn lerp(a: &Self, b: &Self, t: f64) -> Self { return a + (b - a) * t; } }
+impl<T: Scalar> Lerp<T> for Rad<T> { fn lerp(a: &Self, b: &Self, t: T) -> Self { return Rad(a.0 + (b.0 - a.0) * t); } }
+impl<T: Scalar> Lerp<T> for Deg<T> { fn lerp(a: &Self, b: &Self, t: T) -> Self { return Deg(a.0 + (b.0 - a.0) * t); } }
+macro_rules! impl_lerp { ($($V:ident: $f:ident),*) => { $(
+    impl<T: Scalar> Lerp<T> for $V<T> { fn lerp(a: &Self, b: &Self, t: T) -> Self { return $V::$f(a, b, t); } }
+)* } }
+impl_lerp!(Vector2: lerp, Vector3: lerp, Vector4: lerp, Complex: lerp, Dual: lerp, Quaternion: slerp, Transform: lerp);
+
+// Easing functions
+fn pi<T: Scalar>() -> T { return T::from_f64(std::f64::consts::PI); }
+fn powi<T: Scalar>(x: T, n: usize) -> T { return (0..n).fold(T::ONE, |p, _| p * x); }
+fn exp2<T: Scalar>(x: T) -> T { return T::exp(x * T::from_f64(std::f64::consts::LN_2)); }
+//      Polynomials: in is t^n, out mirrors it, in-out joins scaled halves
+fn power_in<T: Scalar>(t: T, n: usize)      -> T { return powi(t, n); }
+fn power_out<T: Scalar>(t: T, n: usize)     -> T { return T::ONE - powi(T::ONE - t, n); }
+fn power_in_out<T: Scalar>(t: T, n: usize)  -> T {
+    return if t < T::HALF { powi(T::TWO, n - 1) * powi(t, n) } else { T::ONE - powi(T::TWO - T::TWO * t, n) * T::HALF }; }
+pub fn quad_in<T: Scalar>(t: T)             -> T { return power_in(t, 2); }
+pub fn quad_out<T: Scalar>(t: T)            -> T { return power_out(t, 2); }
+pub fn quad_in_out<T: Scalar>(t: T)         -> T { return power_in_out(t, 2); }
+pub fn cubic_in<T: Scalar>(t: T)            -> T { return power_in(t, 3); }
+pub fn cubic_out<T: Scalar>(t: T)           -> T { return power_out(t, 3); }
+pub fn cubic_in_out<T: Scalar>(t: T)        -> T { return power_in_out(t, 3); }
+pub fn quart_in<T: Scalar>(t: T)            -> T { return power_in(t, 4); }
+pub fn quart_out<T: Scalar>(t: T)           -> T { return power_out(t, 4); }
+pub fn quart_in_out<T: Scalar>(t: T)        -> T { return power_in_out(t, 4); }
+pub fn quint_in<T: Scalar>(t: T)            -> T { return power_in(t, 5); }
+pub fn quint_out<T: Scalar>(t: T)           -> T { return power_out(t, 5); }
+pub fn quint_in_out<T: Scalar>(t: T)        -> T { return power_in_out(t, 5); }
+//      Quarter (and half) sine waves
+pub fn sine_in<T: Scalar>(t: T)             -> T { return T::ONE - T::cos(t * pi::<T>() * T::HALF); }
+pub fn sine_out<T: Scalar>(t: T)            -> T { return T::sin(t * pi::<T>() * T::HALF); }
+pub fn sine_in_out<T: Scalar>(t: T)         -> T { return (T::ONE - T::cos(t * pi::<T>())) * T::HALF; }
+//      2^(10 (t - 1)), pinned to exactly 0 and 1 at the ends
+pub fn expo_in<T: Scalar>(t: T)             -> T {
+    return if t <= T::ZERO { T::ZERO } else { exp2(T::from_f32(10.0) * t - T::from_f32(10.0)) }; }
+pub fn expo_out<T: Scalar>(t: T)            -> T {
+    return if t >= T::ONE { T::ONE } else { T::ONE - exp2(T::from_f32(-10.0) * t) }; }
+pub fn expo_in_out<T: Scalar>(t: T)         -> T {
+    if t <= T::ZERO { return T::ZERO; }
+    if t >= T::ONE { return T::ONE; }
+    return if t < T::HALF { exp2(T::from_f32(20.0) * t - T::from_f32(10.0)) * T::HALF } else { (T::TWO - exp2(T::from_f32(10.0) - T::from_f32(20.0) * t)) * T::HALF }; }
+//      Quarter circles
+pub fn circ_in<T: Scalar>(t: T)             -> T { return T::ONE - T::sqrt(T::max(T::ONE - t * t, T::ZERO)); }
+pub fn circ_out<T: Scalar>(t: T)            -> T { return T::sqrt(T::max(T::ONE - (t - T::ONE) * (t - T::ONE), T::ZERO)); }
+pub fn circ_in_out<T: Scalar>(t: T)         -> T {
+    return if t < T::HALF { circ_in(T::TWO * t) * T::HALF } else { (circ_out(T::TWO * t - T::ONE) + T::ONE) * T::HALF }; }
+//      Backs up first (by about 10 % with Penner's 1.70158) before heading for the target
+pub fn back_in<T: Scalar>(t: T)             -> T {
+    let c = T::from_f64(1.70158);
+    return (c + T::ONE) * t * t * t - c * t * t; }
+pub fn back_out<T: Scalar>(t: T)            -> T { return T::ONE - back_in(T::ONE - t); }
+pub fn back_in_out<T: Scalar>(t: T)         -> T {
+    let c = T::from_f64(1.70158 * 1.525);
+    let u = T::TWO * t;
+    return if t < T::HALF { u * u * ((c + T::ONE) * u - c) * T::HALF }
+        else { ((u - T::TWO) * (u - T::TWO) * ((c + T::ONE) * (u - T::TWO) + c) + T::TWO) * T::HALF }; }
+//      Decaying sine waves (a plucked string)
+pub fn elastic_in<T: Scalar>(t: T)          -> T {
+    if t <= T::ZERO { return T::ZERO; }
+    if t >= T::ONE { return T::ONE; }
+    let c = T::TWO * pi::<T>() / T::from_f32(3.0);
+    return -exp2(T::from_f32(10.0) * t - T::from_f32(10.0)) * T::sin((T::from_f32(10.0) * t - T::from_f32(10.75)) * c); }
+pub fn elastic_out<T: Scalar>(t: T)         -> T { return T::ONE - elastic_in(T::ONE - t); }
+pub fn elastic_in_out<T: Scalar>(t: T)      -> T {
+    if t <= T::ZERO { return T::ZERO; }
+    if t >= T::ONE { return T::ONE; }
+    let c = T::TWO * pi::<T>() / T::from_f32(4.5);
+    let s = T::sin((T::from_f32(20.0) * t - T::from_f32(11.125)) * c);
+    return if t < T::HALF { -exp2(T::from_f32(20.0) * t - T::from_f32(10.0)) * s * T::HALF }
+        else { exp2(T::from_f32(10.0) - T::from_f32(20.0) * t) * s * T::HALF + T::ONE }; }
+//      A ball dropped on the target, bouncing three times (four parabolas, each a quarter as high as the last)
+pub fn bounce_out<T: Scalar>(t: T)          -> T {
+    let (n, d) = (T::from_f64(7.5625), T::from_f64(2.75));
+    let (offset, floor) =
+        if t < T::ONE / d                 { (T::ZERO, T::ZERO) }
+        else if t < T::TWO / d            { (T::from_f64(1.5), T::from_f64(0.75)) }
+        else if t < T::from_f64(2.5) / d  { (T::from_f64(2.25), T::from_f64(0.9375)) }
+        else                              { (T::from_f64(2.625), T::from_f64(0.984375)) };
+    let u = t - offset / d;
+    return n * u * u + floor; }
+pub fn bounce_in<T: Scalar>(t: T)           -> T { return T::ONE - bounce_out(T::ONE - t); }
+pub fn bounce_in_out<T: Scalar>(t: T)       -> T {
+    return if t < T::HALF { (T::ONE - bounce_out(T::ONE - T::TWO * t)) * T::HALF } else { (T::ONE + bounce_out(T::TWO * t - T::ONE)) * T::HALF }; }
+
+// Easing selection (for storing an easing in a component, or picking one in an editor)
+impl Easing { pub fn apply<T: Scalar>(&self, t: T) -> T {
+    use self::Easing::*;
+    let t = T::min(T::max(t, T::ZERO), T::ONE);
+    return match self {
+        Linear => t,
+        QuadIn => quad_in(t),           QuadOut => quad_out(t),         QuadInOut => quad_in_out(t),
+        CubicIn => cubic_in(t),         CubicOut => cubic_out(t),       CubicInOut => cubic_in_out(t),
+        QuartIn => quart_in(t),         QuartOut => quart_out(t),       QuartInOut => quart_in_out(t),
+        QuintIn => quint_in(t),         QuintOut => quint_out(t),       QuintInOut => quint_in_out(t),
+        SineIn => sine_in(t),           SineOut => sine_out(t),         SineInOut => sine_in_out(t),
+        ExpoIn => expo_in(t),           ExpoOut => expo_out(t),         ExpoInOut => expo_in_out(t),
+        CircIn => circ_in(t),           CircOut => circ_out(t),         CircInOut => circ_in_out(t),
+        BackIn => back_in(t),           BackOut => back_out(t),         BackInOut => back_in_out(t),
+        ElasticIn => elastic_in(t),     ElasticOut => elastic_out(t),   ElasticInOut => elastic_in_out(t),
+        BounceIn => bounce_in(t),       BounceOut => bounce_out(t),     BounceInOut => bounce_in_out(t),
+    }; } }
+impl Easing { pub fn all()                          -> [Easing; 31] {
+    use self::Easing::*;
+    return [
+        Linear,
+        QuadIn, QuadOut, QuadInOut,         CubicIn, CubicOut, CubicInOut,      QuartIn, QuartOut, QuartInOut,
+        QuintIn, QuintOut, QuintInOut,      SineIn, SineOut, SineInOut,         ExpoIn, ExpoOut, ExpoInOut,
+        CircIn, CircOut, CircInOut,         BackIn, BackOut, BackInOut,         ElasticIn, ElasticOut, ElasticInOut,
+        BounceIn, BounceOut, BounceInOut]; } }
+impl Easing { pub fn to_string(&self)               -> String { return format!("{:?}", self); } }
+//      Whether the eased progress leaves [0, 1] on the way
+impl Easing { pub fn overshoots(&self)              -> bool {
+    use self::Easing::*;
+    return matches!(self, BackIn | BackOut | BackInOut | ElasticIn | ElasticOut | ElasticInOut); } }
+
+// Tweening
+pub fn tween<T: Scalar, V: Lerp<T>>(a: &V, b: &V, t: T, easing: Easing) -> V { return V::lerp(a, b, easing.apply(t)); }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn new(start: V, end: V, duration: T, easing: Easing) -> Self {
+    return Self { start, end, duration, elapsed: T::ZERO, easing }; } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn start(&self)        -> V { return self.start; } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn end(&self)          -> V { return self.end; } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn duration(&self)     -> T { return self.duration; } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn elapsed(&self)      -> T { return self.elapsed; } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn easing(&self)       -> Easing { return self.easing; } }
+//      Linear progress in [0, 1] (a zero duration is done at once)
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn progress(&self)     -> T {
+    return if self.duration > T::ZERO { T::min(self.elapsed / self.duration, T::ONE) } else { T::ONE }; } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn is_finished(&self)  -> bool { return self.progress() >= T::ONE; } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn value(&self)        -> V { return tween(&self.start, &self.end, self.progress(), self.easing); } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn advance(&mut self, dt: T) -> V {
+    self.elapsed = T::min(self.elapsed + dt, T::max(self.duration, T::ZERO));
+    return self.value(); } }
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn restart(&mut self)  { self.elapsed = T::ZERO; } }
+//      The same tween played backwards, starting where this one is now
+impl<T: Scalar, V: Lerp<T>> Tween<V, T> { pub fn reversed(&self)     -> Self {
+    return Self { start: self.end, end: self.start, duration: self.duration, elapsed: self.duration - self.elapsed, easing: self.easing }; } }
+
+// Smoothing
+//      Exponential decay towards the target, the frame-rate independent form of a = lerp(a, b, k) every frame.
+//      rate is in 1/seconds: after 1/rate seconds, about 63 % of the distance is covered.
+pub fn damp<T: Scalar, V: Lerp<T>>(current: &V, target: &V, rate: T, dt: T) -> V { return V::lerp(current, target, T::ONE - T::exp(-rate * dt)); }
+//      Rate for damp() that halves the distance every half_life seconds
+pub fn damp_rate_from_half_life<T: Scalar>(half_life: T) -> T { return T::from_f64(std::f64::consts::LN_2) / half_life; }
+//      Critically damped spring towards the target, taking about smooth_time seconds (Unity's SmoothDamp).
+//      The velocity is state owned by the caller, to carry over to the next frame.
+//      Game Programming Gems 4, 1.10 "Critically Damped Ease-In/Ease-Out Smoothing" (the exponential is a Pade approximant)
+pub fn smooth_damp<T: Scalar, V: VectorSpace<T>>(current: &V, target: &V, velocity: &mut V, smooth_time: T, dt: T) -> V {
+    let omega = T::TWO / T::max(smooth_time, T::EPSILON);
+    let x = omega * dt;
+    let decay = T::ONE / (T::ONE + x + T::from_f64(0.48) * x * x + T::from_f64(0.235) * x * x * x);
+    let change = *current - *target;
+    let temp = (*velocity + change * omega) * dt;
+    *velocity = (*velocity - temp * omega) * decay;
+    return *target + (change + temp) * decay; }
+//      The exact critically damped spring: x(t) = (x0 + (v0 + w x0) t) e^(-w t) around the target, with w the frequency
+//      (in radians per second). https://theorangeduck.com/page/spring-roll-call
+pub fn spring_critical<T: Scalar, V: VectorSpace<T>>(current: &V, target: &V, velocity: &mut V, frequency: T, dt: T) -> V {
+    let decay = T::exp(-frequency * dt);
+    let j0 = *current - *target;
+    let j1 = *velocity + j0 * frequency;
+    *velocity = (*velocity - j1 * (frequency * dt)) * decay;
+    return *target + (j0 + j1 * dt) * decay; }
+//      Frequency at which the spring closes half the distance in half_life seconds (starting at rest)
+//          Solves (1 + w h) e^(-w h) = 1/2, so w h is a constant.
+pub fn spring_frequency_from_half_life<T: Scalar>(half_life: T) -> T { return T::from_f64(1.678346990016661) / half_life; }
+
+// Spring
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn new(value: V, frequency: T)            -> Self { return Self { value, velocity: value * T::ZERO, frequency }; } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn from_half_life(value: V, half_life: T) -> Self { return Self::new(value, spring_frequency_from_half_life(half_life)); } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn value(&self)                           -> V { return self.value; } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn velocity(&self)                        -> V { return self.velocity; } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn frequency(&self)                       -> T { return self.frequency; } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn set_value(&mut self, value: V)         { self.value = value; } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn set_velocity(&mut self, velocity: V)   { self.velocity = velocity; } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn set_frequency(&mut self, frequency: T) { self.frequency = frequency; } }
+impl<T: Scalar, V: VectorSpace<T>> Spring<V, T> { pub fn update(&mut self, target: &V, dt: T)   -> V {
+    self.value = spring_critical(&self.value, target, &mut self.velocity, self.frequency, dt);
+    return self.value; } }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easings_run_from_zero_to_one() {
+        for e in Easing::all().iter() {
+            assert!(e.apply(0.0f64).abs() < 1e-12 && (e.apply(1.0f64) - 1.0).abs() < 1e-12, "{}", e.to_string());
+            assert_eq!(e.apply(-1.0f64), e.apply(0.0));
+            let range = (0..=100).map(|i| e.apply(i as f64 / 100.0)).fold((0.0f64, 1.0f64), |(lo, hi), v| (lo.min(v), hi.max(v)));
+            assert_eq!(range.0 < 0.0 || range.1 > 1.0, e.overshoots(), "{}", e.to_string());
+        }
+    }
+
+    #[test]
+    fn penner_values() {
+        assert!((quad_in(0.5) - 0.25f64).abs() < 1e-12 && (cubic_out(0.5) - 0.875f64).abs() < 1e-12);
+        assert!((quint_in_out(0.25) - 0.015625f64).abs() < 1e-12 && (sine_in_out(0.5) - 0.5f64).abs() < 1e-12);
+        assert!((expo_out(0.1) - 0.5f64).abs() < 1e-12 && (bounce_out(1.0 / 2.75) - 1.0f64).abs() < 1e-12);
+        assert!((back_in(0.5) + 0.0876975f64).abs() < 1e-6 && (elastic_out(0.1) - 1.25f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn tweening_anything_with_a_lerp() {
+        let v = tween(&Vector3::zero(), &Vector3::new(2.0, 4.0, 0.0), 0.5, Easing::QuadIn);
+        assert!(v.approx_eq(&Vector3::new(0.5, 1.0, 0.0)));
+        let q = tween(&Quaternion::identity(), &Quaternion::rotation(Deg(90.0), Vector3::up()), 0.5, Easing::Linear);
+        assert!((q * Vector3::right()).approx_eq(&Vector3::new(1.0, 1.0, 0.0).normalization()));
+        let mut t = Tween::new(Deg(0.0), Deg(90.0), 2.0, Easing::CubicInOut);
+        assert_eq!(t.advance(1.0), Deg(45.0));
+        assert!(!t.is_finished() && t.advance(5.0) == Deg(90.0) && t.is_finished() && t.reversed().value() == Deg(90.0));
+    }
+
+    #[test]
+    fn springs_settle_whatever_the_frame_rate() {
+        let settle = |steps: usize| {
+            let (mut x, mut v) = (Vector2::new(5.0, -2.0), Vector2::zero());
+            for _ in 0..steps { x = spring_critical(&x, &Vector2::one(), &mut v, 4.0, 1.0 / steps as f64); }
+            return (x, v); };
+        let (coarse, fine) = (settle(1), settle(100));
+        assert!(coarse.0.approx_eq(&fine.0) && coarse.1.approx_eq(&fine.1));
+        let mut spring = Spring::from_half_life(0.0, 0.5);
+        assert!((spring.update(&10.0, 0.5) - 5.0f64).abs() < 1e-9 && spring.velocity() > 0.0);
+    }
+
+    #[test]
+    fn damping() {
+        assert!((damp(&0.0, &10.0, damp_rate_from_half_life(0.5), 1.0) - 7.5f64).abs() < 1e-9);
+        let (mut x, mut v) = (0.0f64, 0.0);
+        for _ in 0..120 { x = smooth_damp(&x, &1.0, &mut v, 0.25, 1.0 / 60.0); }
+        assert!((x - 1.0).abs() < 1e-3 && x <= 1.0);
+    }
+}
@@ -0,0 +1,339 @@
+// Random sampling: a seedable generator, warps from the unit square onto shapes, low-discrepancy sequences and
+// Poisson-disk sampling
+//      Guidance: Pharr, Jakob & Humphreys, "Physically Based Rendering", https://pbr-book.org/3ed-2018/Monte_Carlo_Integration
+//      O'Neill, PCG random number generators: https://www.pcg-random.org/
+//      Sobol direction numbers from Joe & Kuo: https://web.maths.unsw.edu.au/~fkuo/sobol/
+//      Bridson, "Fast Poisson Disk Sampling in Arbitrary Dimensions": https://www.cs.ubc.ca/~rbridson/docs/bridson-siggraph07-poissondisk.pdf
+use super::linalg::*;
+use super::geometry::{ Aabb, Triangle };
+
+/*
+    Sampling conventions:
+    - Random is PCG32. The same seed (and stream) always gives the same numbers, on every platform.
+      Floats are drawn in [0, 1) from 24 random bits, so f32 and f64 see the same values.
+    - Warps take a point u in the unit square [0, 1)^2 and map it onto a shape, keeping it uniform (or cosine-weighted).
+      Feed them Random, stratified or low-discrepancy points alike.
+    - Hemispheres are around +z (the linalg up), unless a normal is given to turn them to.
+    - Halton and Sobol points are in [0, 1)^n. Seed 0 gives the plain sequences; other seeds shift (Halton) or
+      scramble (Sobol) them, keeping their low discrepancy.
+    - Poisson-disk points are at least the radius apart, and cover the box without gaps much wider than the radius
+      (Bridson's method stops after a number of misses, so a few spots could still take another point).
+*/
+
+// Struct Definitions
+//      PCG32 (XSH RR): 64 bits of state, one of 2^63 streams
+#[derive(Debug, Clone, PartialEq, Eq)] pub struct Random { state: u64, increment: u64 }
+//      Halton sequence in bases 2, 3 and 5
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub struct Halton { seed: u32 }
+//      Sobol sequence in its first three dimensions
+#[derive(Debug, Copy, Clone, PartialEq, Eq)] pub struct Sobol  { seed: u32 }
+
+// Constants
+//      Candidates tried around each active point before it is retired, as in Bridson's paper
+pub const POISSON_DISK_ATTEMPTS: usize = 30;
+const PCG_MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+const PCG_STREAM: u64 = 1_442_695_040_888_963_407;
+
+// Random numbers
+impl Random { pub fn new(seed: u64) -> Self { return Self::with_stream(seed, PCG_STREAM >> 1); } }
+impl Random { pub fn with_stream(seed: u64, stream: u64) -> Self {
+    let mut r = Self { state: 0, increment: (stream << 1) | 1 };
+    r.next_u32();
+    r.state = r.state.wrapping_add(seed);
+    r.next_u32();
+    return r; } }
+impl Random { pub fn next_u32(&mut self) -> u32 {
+    let old = self.state;
+    self.state = old.wrapping_mul(PCG_MULTIPLIER).wrapping_add(self.increment);
+    let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+    return xorshifted.rotate_right((old >> 59) as u32); } }
+impl Random { pub fn next_u64(&mut self) -> u64 { return ((self.next_u32() as u64) << 32) | self.next_u32() as u64; } }
+//      Uniform in [0, n), without modulo bias (n > 0)
+impl Random { pub fn below(&mut self, n: u32) -> u32 {
+    let threshold = n.wrapping_neg() % n;
+    loop { let r = self.next_u32(); if r >= threshold { return r % n; } } } }
+impl Random { pub fn unit<T: Scalar>(&mut self) -> T { return T::from_f64((self.next_u32() >> 8) as f64 / (1u32 << 24) as f64); } }
+impl Random { pub fn range<T: Scalar>(&mut self, min: T, max: T) -> T { return min + (max - min) * self.unit::<T>(); } }
+impl Random { pub fn chance<T: Scalar>(&mut self, p: T) -> bool { return self.unit::<T>() < p; } }
+impl Random { pub fn unit_square<T: Scalar>(&mut self) -> Vector2<T> { let x = self.unit(); return Vector2::new(x, self.unit()); } }
+impl Random { pub fn unit_cube<T: Scalar>(&mut self) -> Vector3<T> { let (x, y) = (self.unit(), self.unit()); return Vector3::new(x, y, self.unit()); } }
+//      Fisher-Yates
+impl Random { pub fn shuffle<E>(&mut self, items: &mut [E]) {
+    for i in (1..items.len()).rev() { items.swap(i, self.below(i as u32 + 1) as usize); } } }
+
+// Shapes
+impl Random { pub fn on_circle<T: Scalar>(&mut self, radius: T) -> Vector2<T> { return Vector2::from_polar(Rad(pi::<T>() * T::TWO * self.unit::<T>()), radius); } }
+impl Random { pub fn in_disk<T: Scalar>(&mut self, radius: T) -> Vector2<T> { return square_to_disk(&self.unit_square()) * radius; } }
+impl Random { pub fn on_sphere<T: Scalar>(&mut self, radius: T) -> Vector3<T> { return square_to_sphere(&self.unit_square()) * radius; } }
+impl Random { pub fn in_sphere<T: Scalar>(&mut self, radius: T) -> Vector3<T> {
+    let r = radius * T::powf(self.unit::<T>(), T::ONE / T::from_f32(3.0));
+    return self.on_sphere(r); } }
+impl Random { pub fn on_hemisphere<T: Scalar>(&mut self, normal: &Vector3<T>) -> Vector3<T> { return align_to_normal(&square_to_hemisphere(&self.unit_square()), normal); } }
+impl Random { pub fn cosine_hemisphere<T: Scalar>(&mut self, normal: &Vector3<T>) -> Vector3<T> { return align_to_normal(&square_to_cosine_hemisphere(&self.unit_square()), normal); } }
+impl Random { pub fn in_triangle<T: Scalar>(&mut self, triangle: &Triangle<T>) -> Vector3<T> {
+    let b = square_to_triangle(&self.unit_square());
+    return triangle.a() * (T::ONE - b.x() - b.y()) + triangle.b() * b.x() + triangle.c() * b.y(); } }
+impl Random { pub fn in_aabb<T: Scalar>(&mut self, aabb: &Aabb<T>) -> Vector3<T> {
+    let (u, lo, size) = (self.unit_cube::<T>(), aabb.min(), aabb.size());
+    return Vector3::new(lo.x() + u.x() * size.x(), lo.y() + u.y() * size.y(), lo.z() + u.z() * size.z()); } }
+//      One jittered point in each cell of an nx by ny grid over the unit square, row by row
+impl Random { pub fn stratified<T: Scalar>(&mut self, nx: usize, ny: usize) -> Vec<Vector2<T>> {
+    let mut points = Vec::with_capacity(nx * ny);
+    for j in 0..ny { for i in 0..nx {
+        let u = self.unit_square::<T>();
+        points.push(Vector2::new((T::from_f64(i as f64) + u.x()) / T::from_f64(nx as f64), (T::from_f64(j as f64) + u.y()) / T::from_f64(ny as f64)));
+    } }
+    return points; } }
+
+// Warps from the unit square
+fn pi<T: Scalar>() -> T { return T::from_f64(std::f64::consts::PI); }
+//      Shirley and Chiu's concentric map onto the unit disk (keeps strata compact, unlike the polar map)
+pub fn square_to_disk<T: Scalar>(u: &Vector2<T>) -> Vector2<T> {
+    let (a, b) = (u.x() * T::TWO - T::ONE, u.y() * T::TWO - T::ONE);
+    if a == T::ZERO && b == T::ZERO { return Vector2::zero(); }
+    let quarter = pi::<T>() * T::HALF * T::HALF;
+    if a.abs() > b.abs() { return Vector2::from_polar(Rad(quarter * (b / a)), a); }
+    return Vector2::from_polar(Rad(pi::<T>() * T::HALF - quarter * (a / b)), b); }
+pub fn square_to_sphere<T: Scalar>(u: &Vector2<T>) -> Vector3<T> {
+    let z = T::ONE - u.x() * T::TWO;
+    let r = T::sqrt(T::max(T::ZERO, T::ONE - z * z));
+    let d = Vector2::from_polar(Rad(pi::<T>() * T::TWO * u.y()), r);
+    return Vector3::new(d.x(), d.y(), z); }
+pub fn square_to_hemisphere<T: Scalar>(u: &Vector2<T>) -> Vector3<T> {
+    let r = T::sqrt(T::max(T::ZERO, T::ONE - u.x() * u.x()));
+    let d = Vector2::from_polar(Rad(pi::<T>() * T::TWO * u.y()), r);
+    return Vector3::new(d.x(), d.y(), u.x()); }
+//      Malley's method: lift a uniform disk point onto the hemisphere
+pub fn square_to_cosine_hemisphere<T: Scalar>(u: &Vector2<T>) -> Vector3<T> {
+    let d = square_to_disk(u);
+    return Vector3::new(d.x(), d.y(), T::sqrt(T::max(T::ZERO, T::ONE - d.magnitude_sqr()))); }
+//      Barycentric weights of b and c (a gets the rest)
+pub fn square_to_triangle<T: Scalar>(u: &Vector2<T>) -> Vector2<T> {
+    let s = u.x().sqrt();
+    return Vector2::new(T::ONE - s, u.y() * s); }
+//      Densities per unit solid angle, for weighting Monte Carlo estimates
+pub fn sphere_pdf<T: Scalar>() -> T { return T::ONE / (pi::<T>() * T::from_f32(4.0)); }
+pub fn hemisphere_pdf<T: Scalar>() -> T { return T::ONE / (pi::<T>() * T::TWO); }
+pub fn cosine_hemisphere_pdf<T: Scalar>(cos_theta: T) -> T { return T::max(T::ZERO, cos_theta) / pi::<T>(); }
+//      Turns a +z-up direction to be around the (unit) normal instead
+//      Duff et al., "Building an Orthonormal Basis, Revisited": https://jcgt.org/published/0006/01/01/
+pub fn align_to_normal<T: Scalar>(v: &Vector3<T>, normal: &Vector3<T>) -> Vector3<T> {
+    let n = normal;
+    let sign = if n.z() < T::ZERO { -T::ONE } else { T::ONE };
+    let a = -T::ONE / (sign + n.z());
+    let b = n.x() * n.y() * a;
+    let tangent = Vector3::new(T::ONE + sign * n.x() * n.x() * a, sign * b, -sign * n.x());
+    let bitangent = Vector3::new(b, sign + n.y() * n.y() * a, -n.y());
+    return tangent * v.x() + bitangent * v.y() + (*n) * v.z(); }
+
+// Low-discrepancy sequences
+//      The index's digits in base, mirrored about the radix point (van der Corput)
+pub fn radical_inverse<T: Scalar>(base: u32, mut index: u32) -> T {
+    let (mut digits, mut scale) = (0.0f64, 1.0f64);
+    while index > 0 {
+        scale /= base as f64;
+        digits += (index % base) as f64 * scale;
+        index /= base;
+    }
+    return T::from_f64(digits); }
+impl Halton { pub fn new(seed: u32) -> Self { return Self { seed }; } }
+impl Sobol  { pub fn new(seed: u32) -> Self { return Self { seed }; } }
+impl Halton { pub fn seed(&self) -> u32 { return self.seed; } }
+impl Sobol  { pub fn seed(&self) -> u32 { return self.seed; } }
+impl Halton { pub fn point2<T: Scalar>(&self, index: u32) -> Vector2<T> { return Vector2::new(self.component(index, 0), self.component(index, 1)); } }
+impl Halton { pub fn point3<T: Scalar>(&self, index: u32) -> Vector3<T> { return Vector3::new(self.component(index, 0), self.component(index, 1), self.component(index, 2)); } }
+impl Sobol  { pub fn point2<T: Scalar>(&self, index: u32) -> Vector2<T> { return Vector2::new(self.component(index, 0), self.component(index, 1)); } }
+impl Sobol  { pub fn point3<T: Scalar>(&self, index: u32) -> Vector3<T> { return Vector3::new(self.component(index, 0), self.component(index, 1), self.component(index, 2)); } }
+//      Per-dimension randomization drawn from the seed (none for seed 0)
+fn randomization(seed: u32, dimension: usize) -> u32 {
+    if seed == 0 { return 0; }
+    let mut r = Random::with_stream(seed as u64, dimension as u64);
+    return r.next_u32(); }
+//      Cranley-Patterson rotation: a toroidal shift of the whole sequence
+impl Halton { fn component<T: Scalar>(&self, index: u32, dimension: usize) -> T {
+    let shift = randomization(self.seed, dimension) as f64 / 4_294_967_296.0;
+    let x = radical_inverse::<f64>([2, 3, 5][dimension], index) + shift;
+    return T::from_f64(if x >= 1.0 { x - 1.0 } else { x }); } }
+//      Direction numbers for the first three dimensions (the second and third from primitive polynomials x + 1 and
+//      x^2 + x + 1), XOR-ed together for each set bit of the index, then XOR-scrambled
+impl Sobol  { fn component<T: Scalar>(&self, index: u32, dimension: usize) -> T {
+    let (s, a, m): (usize, u32, &[u32]) = match dimension { 0 => (0, 0, &[]), 1 => (1, 0, &[1]), _ => (2, 1, &[1, 3]) };
+    let mut v = [0u32; 32];
+    for k in 0..32 {
+        v[k] = if s == 0 { 1 << (31 - k) }
+        else if k < s { m[k] << (31 - k) }
+        else { (1..s).fold(v[k - s] ^ (v[k - s] >> s), |x, i| if (a >> (s - 1 - i)) & 1 == 1 { x ^ v[k - i] } else { x }) };
+    }
+    let bits = (0..32).filter(|k| index & (1 << k) != 0).fold(0u32, |x, k| x ^ v[k]);
+    let x = ((bits ^ randomization(self.seed, dimension)) >> 8) as f64 / (1u32 << 24) as f64;
+    return T::from_f64(x); } }
+
+// Poisson-disk sampling
+pub fn poisson_disk_2d<T: Scalar>(random: &mut Random, min: &Vector2<T>, max: &Vector2<T>, radius: T) -> Vec<Vector2<T>> {
+    let points = bridson(random, &[min.x(), min.y()], &[max.x(), max.y()], radius);
+    return points.iter().map(|p| Vector2::new(p[0], p[1])).collect(); }
+pub fn poisson_disk_3d<T: Scalar>(random: &mut Random, bounds: &Aabb<T>, radius: T) -> Vec<Vector3<T>> {
+    let (lo, hi) = (bounds.min(), bounds.max());
+    let points = bridson(random, &[lo.x(), lo.y(), lo.z()], &[hi.x(), hi.y(), hi.z()], radius);
+    return points.iter().map(|p| Vector3::new(p[0], p[1], p[2])).collect(); }
+//      A background grid with cells small enough to hold one point each (radius / sqrt(n) across), and a list of
+//      active points to grow from. Candidates are uniform in the shell between radius and twice the radius.
+fn bridson<T: Scalar>(random: &mut Random, lo: &[T], hi: &[T], radius: T) -> Vec<[T; 3]> {
+    let n = lo.len();
+    if radius <= T::ZERO || (0..n).any(|k| hi[k] <= lo[k]) { return Vec::new(); }
+    let cell = radius / T::from_f64(n as f64).sqrt();
+    let counts: Vec<usize> = (0..n).map(|k| ((hi[k] - lo[k]) / cell).floor().to_f64() as usize + 1).collect();
+    let cell_of = |p: &[T; 3]| -> Vec<usize> { return (0..n).map(|k| usize::min(((p[k] - lo[k]) / cell).floor().to_f64() as usize, counts[k] - 1)).collect(); };
+    let slot = |c: &[usize]| -> usize { return (0..n).rev().fold(0, |s, k| s * counts[k] + c[k]); };
+    let mut grid = vec![usize::MAX; counts.iter().product()];
+    let (mut points, mut active): (Vec<[T; 3]>, Vec<usize>) = (Vec::new(), Vec::new());
+    let mut first = [T::ZERO; 3];
+    for k in 0..n { first[k] = random.range(lo[k], hi[k]); }
+    grid[slot(&cell_of(&first))] = 0;
+    points.push(first);
+    active.push(0);
+    while !active.is_empty() {
+        let a = random.below(active.len() as u32) as usize;
+        let center = points[active[a]];
+        let mut found = false;
+        for _ in 0..POISSON_DISK_ATTEMPTS {
+            let mut candidate = [T::ZERO; 3];
+            loop {
+                for c in candidate.iter_mut().take(n) { *c = random.range(-radius, radius) * T::TWO; }
+                let d2 = (0..n).fold(T::ZERO, |s, k| s + candidate[k] * candidate[k]);
+                if d2 >= radius * radius && d2 <= radius * radius * T::from_f32(4.0) { break; }
+            }
+            for k in 0..n { candidate[k] += center[k]; }
+            if (0..n).any(|k| candidate[k] < lo[k] || candidate[k] >= hi[k]) { continue; }
+            // Any point closer than the radius is within two cells on every axis
+            let c = cell_of(&candidate);
+            let mut neighbour = vec![0usize; n];
+            let mut clear = true;
+            for offset in 0..5usize.pow(n as u32) {
+                let mut o = offset;
+                let mut inside = true;
+                for k in 0..n {
+                    let i = c[k] as i64 + (o % 5) as i64 - 2;
+                    o /= 5;
+                    if i < 0 || i >= counts[k] as i64 { inside = false; break; }
+                    neighbour[k] = i as usize;
+                }
+                if !inside { continue; }
+                let other = grid[slot(&neighbour)];
+                if other != usize::MAX && (0..n).fold(T::ZERO, |s, k| s + (points[other][k] - candidate[k]) * (points[other][k] - candidate[k])) < radius * radius {
+                    clear = false;
+                    break;
+                }
+            }
+            if clear {
+                grid[slot(&c)] = points.len();
+                active.push(points.len());
+                points.push(candidate);
+                found = true;
+                break;
+            }
+        }
+        if !found { active.swap_remove(a); }
+    }
+    return points; }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::geometry::Plane;
+
+    #[test]
+    fn generator_is_seeded_pcg32() {
+        let (mut a, mut b) = (Random::new(11), Random::new(11));
+        assert!((0..100).all(|_| a.next_u32() == b.next_u32()) && Random::new(12).next_u64() != a.next_u64());
+        // The reference output of pcg32 seeded with (42, 54)
+        let mut r = Random::with_stream(42, 54);
+        assert_eq!((r.next_u32(), r.next_u32(), r.next_u32()), (0xa15c_02b7, 0x7b47_f409, 0xba1d_3330));
+        let mut items = [1, 2, 3, 4, 5, 6];
+        r.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+        assert!((0..1000).all(|_| r.below(7) < 7 && (0.0..1.0).contains(&r.unit::<f32>())));
+    }
+
+    #[test]
+    fn disks_and_spheres() {
+        let mut r = Random::new(5);
+        for _ in 0..10000 {
+            assert!(r.in_disk(2.0f64).magnitude() <= 2.0 && (r.on_circle(2.0f64).magnitude() - 2.0).abs() < 1e-12);
+            assert!((r.on_sphere(3.0f64).magnitude() - 3.0).abs() < 1e-9 && r.in_sphere(1.0f64).magnitude() <= 1.0);
+        }
+        // Uniform in a ball puts half the points beyond 0.5^(1/3) of the radius
+        let outer = (0..20000).filter(|_| r.in_sphere(1.0f64).magnitude() > 0.5f64.powf(1.0 / 3.0)).count();
+        assert!((outer as f64 / 20000.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn hemispheres_weigh_cosines_right() {
+        // E[cos] is 1/2 over the uniform hemisphere and 2/3 over the cosine-weighted one
+        let mut r = Random::new(9);
+        let normal = Vector3::new(1.0, 2.0, -3.0).normalization();
+        let (mut uniform, mut cosine) = (0.0f64, 0.0f64);
+        for _ in 0..20000 {
+            let (h, c) = (r.on_hemisphere(&normal), r.cosine_hemisphere(&normal));
+            assert!((h.magnitude() - 1.0).abs() < 1e-9 && (c.magnitude() - 1.0).abs() < 1e-9 && h * normal >= 0.0 && c * normal >= 0.0);
+            uniform += h * normal / 20000.0;
+            cosine += c * normal / 20000.0;
+        }
+        assert!((uniform - 0.5).abs() < 0.01 && (cosine - 2.0 / 3.0).abs() < 0.01);
+        assert!((cosine_hemisphere_pdf(1.0f64) - 1.0 / std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn triangles_and_boxes() {
+        let mut r = Random::new(3);
+        let triangle = Triangle::new(Vector3::new(1.0, 0.0, 2.0), Vector3::new(3.0, 1.0, 0.0), Vector3::new(0.0, 4.0, 1.0));
+        let plane = Plane::from_points(triangle.a(), triangle.b(), triangle.c());
+        let mut mean = Vector3::zero();
+        for _ in 0..20000 {
+            let p = r.in_triangle(&triangle);
+            let uvw = triangle.barycentric(&p);
+            assert!(plane.contains_point(&p) && uvw.x() >= -1e-12 && uvw.y() >= -1e-12 && uvw.z() >= -1e-12);
+            mean += p / 20000.0;
+        }
+        let centroid = (triangle.a() + triangle.b() + triangle.c()) / 3.0;
+        assert!(mean.approx_eq_eps(&centroid, 0.02, TestMode::AbsoluteCoordinates));
+
+        let aabb = Aabb::new(Vector3::new(-1.0, 0.0, 2.0), Vector3::new(1.0, 4.0, 3.0));
+        assert!((0..10000).all(|_| aabb.contains_point(&r.in_aabb(&aabb))));
+    }
+
+    #[test]
+    fn stratified_points_fill_their_cells() {
+        let strata = Random::new(1).stratified::<f64>(4, 2);
+        assert_eq!(strata.len(), 8);
+        assert!(strata.iter().enumerate().all(|(i, p)| (p.x() * 4.0).floor() as usize == i % 4 && (p.y() * 2.0).floor() as usize == i / 4));
+    }
+
+    #[test]
+    fn low_discrepancy_sequences() {
+        assert_eq!(radical_inverse::<f64>(2, 6), 0.375);
+        assert_eq!(Halton::new(0).point3::<f64>(1), Vector3::new(0.5, 1.0 / 3.0, 0.2));
+        let sobol: Vec<Vector2<f64>> = (0..4).map(|i| Sobol::new(0).point2(i)).collect();
+        assert_eq!(sobol, vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, 0.5), Vector2::new(0.25, 0.75), Vector2::new(0.75, 0.25)]);
+        // Scrambling keeps one point in each of the 8 x 8 cells of the first 64
+        let scrambled: Vec<Vector2<f64>> = (0..64).map(|i| Sobol::new(9).point2(i)).collect();
+        for i in 0..8 { for j in 0..8 {
+            assert_eq!(scrambled.iter().filter(|p| (p.x() * 8.0).floor() as usize == i && (p.y() * 8.0).floor() as usize == j).count(), 1);
+        } }
+        assert!((0..100).all(|i| { let p = Halton::new(4).point3::<f64>(i); return (0..3).all(|k| (0.0..1.0).contains(&p[k])); }));
+    }
+
+    #[test]
+    fn poisson_disks_keep_their_distance() {
+        let mut r = Random::new(2);
+        let points = poisson_disk_2d(&mut r, &Vector2::zero(), &Vector2::new(5.0, 5.0), 0.5);
+        assert!(points.len() > 40 && points.iter().all(|p| p.x() >= 0.0 && p.x() < 5.0 && p.y() >= 0.0 && p.y() < 5.0));
+        assert!(points.iter().enumerate().all(|(i, p)| points[..i].iter().all(|q| (*p - *q).magnitude() >= 0.5)));
+        let points = poisson_disk_3d(&mut Random::new(3), &Aabb::unit(), 0.25);
+        assert!(points.len() > 20 && points.iter().enumerate().all(|(i, p)| points[..i].iter().all(|q| (*p - *q).magnitude() >= 0.25)));
+        // Every spot in the box is within twice the radius of a point
+        assert!((0..1000).all(|_| { let q = r.in_aabb(&Aabb::unit()); return points.iter().any(|p| (*p - q).magnitude() < 0.5); }));
+        assert!(poisson_disk_2d(&mut r, &Vector2::zero(), &Vector2::new(5.0, 5.0), 0.0).is_empty());
+    }
+}